@@ -0,0 +1,55 @@
+// 텍스트(JSON Canvas)와 바이너리 포맷의 쓰기/읽기 시간 비교
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use wgpu_perf_test::document::Document;
+use wgpu_perf_test::formats::{binary, json_canvas};
+
+// 기본 그리드를 이어 붙여 만든 큰 보드
+fn board(copies: usize) -> Document {
+    let mut doc = Document::default();
+    for _ in 0..copies {
+        doc.append(Document::default_grid());
+    }
+    doc
+}
+
+fn round_trip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("round_trip");
+    // 기본 그리드 하나가 노드 100개 → 100개, 1천 개, 10만 개
+    for copies in [1, 10, 1000] {
+        let doc = board(copies);
+        let nodes = doc.len();
+        // 10만 노드는 한 번에 수백 ms라 표본 수를 줄임
+        group.sample_size(if copies >= 1000 { 10 } else { 100 });
+
+        group.bench_with_input(
+            BenchmarkId::new("json_canvas_write", nodes),
+            &doc,
+            |b, doc| b.iter(|| json_canvas::to_string(black_box(doc))),
+        );
+        let text = json_canvas::to_string(&doc).expect("JSON Canvas 쓰기");
+        group.bench_with_input(
+            BenchmarkId::new("json_canvas_read", nodes),
+            &text,
+            |b, text| b.iter(|| json_canvas::parse(black_box(text))),
+        );
+
+        group.bench_with_input(BenchmarkId::new("binary_write", nodes), &doc, |b, doc| {
+            b.iter(|| {
+                let mut bytes = Vec::new();
+                binary::write_to(black_box(doc), &mut bytes).map(|()| bytes)
+            })
+        });
+        let mut bytes = Vec::new();
+        binary::write_to(&doc, &mut bytes).expect("바이너리 쓰기");
+        group.bench_with_input(
+            BenchmarkId::new("binary_read", nodes),
+            &bytes,
+            |b, bytes| b.iter(|| binary::read_from(black_box(bytes.as_slice()))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, round_trip);
+criterion_main!(benches);
@@ -0,0 +1,137 @@
+use crate::document::Document;
+use crate::journal::{Journal, Operation};
+use crate::state::AppState;
+use crate::tabs::BoardSession;
+use crate::ui::recovery_dialog::RecoveryChoice;
+
+impl AppState {
+    /// 아직 자동 저장하지 않는 보드마다 현재 문서를 첫 스냅샷으로 시작.
+    /// 복구 안내가 떠 있는 동안은 이전 파일을 건드리지 않도록 미룸
+    pub fn start_autosave(&mut self) {
+        if self.recovery_prompt.is_some() {
+            return;
+        }
+        let active = self.top_bar_state.active_tab;
+        if self.journal.is_none() {
+            self.journal = self.start_journal(active, self.board_id, &self.document);
+        }
+        for i in 0..self.boards.len() {
+            if i == active || self.boards[i].journal.is_some() {
+                continue;
+            }
+            let board = &self.boards[i];
+            let journal = self.start_journal(i, board.id, &board.document);
+            self.boards[i].journal = journal;
+        }
+    }
+
+    fn start_journal(&self, tab: usize, board_id: u64, doc: &Document) -> Option<Journal> {
+        let dir = self
+            .autosave_dir
+            .as_ref()?
+            .join(format!("{:016x}", board_id));
+        let name = self.top_bar_state.tabs.get(tab).map_or("", String::as_str);
+        match Journal::start(dir, name, doc) {
+            Ok(journal) => Some(journal),
+            Err(err) => {
+                eprintln!("자동 저장을 시작하지 못했습니다: {}", err);
+                None
+            }
+        }
+    }
+
+    /// 문서 변경을 저널에 기록 (변경이 이미 적용된 뒤 호출)
+    pub fn record_operation(&mut self, op: Operation) {
+        self.unsaved_changes = true;
+        if let Some(journal) = &mut self.journal {
+            journal.record(&op, &self.document);
+        }
+    }
+
+    /// 모든 보드의 남은 기록을 마치고 자동 저장 파일 삭제
+    pub fn finish_autosave(&mut self) {
+        let parked = self.boards.iter_mut().filter_map(|b| b.journal.take());
+        for journal in self.journal.take().into_iter().chain(parked) {
+            journal.finish();
+        }
+    }
+
+    /// 앱 종료: 저장한 보드의 자동 저장 파일은 삭제하고, 저장하지 않은 변경이 있는
+    /// 보드는 discard_unsaved(사용자가 버리기로 확인)가 아니면 다음 실행에서 복구하도록 남김
+    pub fn end_autosave(&mut self, discard_unsaved: bool) {
+        let active = self
+            .journal
+            .take()
+            .map(|journal| (journal, self.unsaved_changes));
+        let parked = self
+            .boards
+            .iter_mut()
+            .filter_map(|b| Some((b.journal.take()?, b.unsaved_changes)));
+        for (journal, unsaved) in active.into_iter().chain(parked) {
+            if unsaved && !discard_unsaved {
+                journal.close();
+            } else {
+                journal.finish();
+            }
+        }
+    }
+
+    /// 복구하면 보드마다 같은 이름의 탭에(없으면 새 탭에) 되살림
+    pub fn apply_recovery_choice(&mut self, choice: RecoveryChoice) {
+        let Some(prompt) = &mut self.recovery_prompt else {
+            return;
+        };
+        let mut restored = Vec::new();
+        if choice == RecoveryChoice::Restore {
+            for recovery in &prompt.recoveries {
+                match recovery.load() {
+                    Ok((document, _)) => restored.push((recovery.name.clone(), document)),
+                    Err(err) => {
+                        // 실패하면 파일을 덮어쓰지 않도록 안내를 유지
+                        prompt.error = Some(format!("복구 실패 — {}", err));
+                        return;
+                    }
+                }
+            }
+        }
+        let Some(prompt) = self.recovery_prompt.take() else {
+            return;
+        };
+
+        let mut used = vec![false; self.boards.len()];
+        for (name, document) in restored {
+            let existing = name.as_ref().and_then(|name| {
+                (0..self.top_bar_state.tabs.len())
+                    .find(|&i| !used[i] && self.top_bar_state.tabs[i] == *name)
+            });
+            match existing {
+                Some(i) if i == self.top_bar_state.active_tab => {
+                    used[i] = true;
+                    self.document = document;
+                    self.selected_indices.clear();
+                    self.unsaved_changes = true;
+                }
+                Some(i) => {
+                    used[i] = true;
+                    let board = &mut self.boards[i];
+                    board.document = document;
+                    board.selected_indices.clear();
+                    board.unsaved_changes = true;
+                }
+                None => {
+                    let name = name.unwrap_or_else(|| "복구된 보드".to_string());
+                    let mut session = BoardSession::with_document(document);
+                    session.unsaved_changes = true;
+                    self.open_tab(name, session);
+                    used.push(true);
+                }
+            }
+        }
+        for recovery in &prompt.recoveries {
+            recovery.discard();
+        }
+        self.mark_positions_dirty();
+        self.start_autosave();
+        self.window.request_redraw();
+    }
+}
@@ -1,10 +1,18 @@
 use crate::types::CameraUniform;
 
+#[derive(Copy, Clone, Debug)]
 pub struct Camera {
     pub position: [f32; 2],
     pub zoom: f32,
 }
 
+// 기본 그리드 전체가 보이는 시작 위치
+impl Default for Camera {
+    fn default() -> Self {
+        Self::new([3.15, 2.25], 0.3)
+    }
+}
+
 impl Camera {
     pub fn new(position: [f32; 2], zoom: f32) -> Self {
         Self { position, zoom }
@@ -40,3 +48,214 @@ impl Camera {
         self.position[1] += world_before[1] - world_after[1];
     }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Easing {
+    // 천천히 출발해 천천히 멈춤 (먼 거리 이동)
+    EaseInOutCubic,
+    // 빠르게 반응하고 부드럽게 멈춤 (휠 확대처럼 짧고 잦은 입력)
+    EaseOutCubic,
+}
+
+impl Easing {
+    /// 0~1 진행률 → 0~1 보간 계수
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) * 0.5
+                }
+            }
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+        }
+    }
+}
+
+/// 두 카메라 사이의 시간 기반 전환. 경과 시간은 호출자가 넘겨 주므로 결과가 결정적
+#[derive(Copy, Clone, Debug)]
+pub struct CameraAnimation {
+    pub from: Camera,
+    pub to: Camera,
+    pub duration: f32,
+    pub elapsed: f32,
+    pub easing: Easing,
+}
+
+impl CameraAnimation {
+    pub fn new(from: Camera, to: Camera, duration: f32, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            easing,
+        }
+    }
+
+    /// 진행률 t(0~1)의 카메라. 배율은 로그 공간에서 보간해 확대/축소 속도가 일정하게 보임
+    pub fn sample(&self, t: f32) -> Camera {
+        let k = self.easing.apply(t);
+        let lerp = |a: f32, b: f32| a + (b - a) * k;
+        let zoom = lerp(self.from.zoom.ln(), self.to.zoom.ln()).exp();
+        Camera::new(
+            [
+                lerp(self.from.position[0], self.to.position[0]),
+                lerp(self.from.position[1], self.to.position[1]),
+            ],
+            zoom,
+        )
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).min(1.0)
+        }
+    }
+
+    /// dt초 진행한 뒤의 카메라. 끝나면 정확히 목표 카메라를 반환
+    pub fn advance(&mut self, dt: f32) -> Camera {
+        self.elapsed += dt.max(0.0);
+        if self.is_finished() {
+            self.to
+        } else {
+            self.sample(self.progress())
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+}
+
+/// 끌어 이동하다 놓은 뒤 이어지는 관성 이동. 속도(월드 단위/초)가 마찰에 따라 지수적으로 줄어듦
+#[derive(Copy, Clone, Debug)]
+pub struct PanGlide {
+    pub velocity: [f32; 2],
+    // 초당 감쇠율. 클수록 빨리 멈춤
+    pub friction: f32,
+}
+
+impl PanGlide {
+    pub fn new(velocity: [f32; 2], friction: f32) -> Self {
+        Self { velocity, friction }
+    }
+
+    /// dt초 동안 이동한 거리. 감쇠를 적분해 프레임 간격과 관계없이 같은 궤적을 그림
+    pub fn advance(&mut self, dt: f32) -> [f32; 2] {
+        let dt = dt.max(0.0);
+        let decay = (-self.friction * dt).exp();
+        let travel = if self.friction > 0.0 {
+            (1.0 - decay) / self.friction
+        } else {
+            dt
+        };
+        let moved = [self.velocity[0] * travel, self.velocity[1] * travel];
+        self.velocity = [self.velocity[0] * decay, self.velocity[1] * decay];
+        moved
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.velocity[0].hypot(self.velocity[1])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() <= 1e-4 * a.abs().max(b.abs()).max(1.0)
+    }
+
+    fn animation(duration: f32) -> CameraAnimation {
+        CameraAnimation::new(
+            Camera::new([0.0, 0.0], 0.5),
+            Camera::new([10.0, -4.0], 8.0),
+            duration,
+            Easing::EaseInOutCubic,
+        )
+    }
+
+    #[test]
+    fn sample_hits_both_endpoints() {
+        let anim = animation(1.0);
+        for easing in [Easing::EaseInOutCubic, Easing::EaseOutCubic] {
+            let anim = CameraAnimation { easing, ..anim };
+            let start = anim.sample(0.0);
+            let end = anim.sample(1.0);
+            assert_eq!(start.position, anim.from.position);
+            assert!(close(start.zoom, anim.from.zoom));
+            assert_eq!(end.position, anim.to.position);
+            assert!(close(end.zoom, anim.to.zoom));
+        }
+    }
+
+    #[test]
+    fn zoom_midpoint_is_geometric_mean() {
+        let anim = animation(1.0);
+        let mid = anim.sample(0.5);
+        assert!(close(mid.zoom, (0.5f32 * 8.0).sqrt()));
+        assert!(close(mid.position[0], 5.0));
+        assert!(close(mid.position[1], -2.0));
+    }
+
+    #[test]
+    fn advance_lands_exactly_on_target() {
+        let mut anim = animation(0.45);
+        let mut camera = anim.from;
+        for _ in 0..10 {
+            camera = anim.advance(1.0 / 60.0);
+        }
+        assert!(!anim.is_finished());
+        assert_ne!(camera.position, anim.to.position);
+        // 마지막 프레임이 끝을 넘어가도 목표에서 멈춤
+        let last = anim.advance(1.0);
+        assert!(anim.is_finished());
+        assert_eq!(last.position, anim.to.position);
+        assert_eq!(last.zoom, anim.to.zoom);
+    }
+
+    #[test]
+    fn zero_duration_finishes_immediately() {
+        for duration in [0.0, -1.0] {
+            let mut anim = animation(duration);
+            assert_eq!(anim.progress(), 1.0);
+            assert!(anim.is_finished());
+            let camera = anim.advance(0.0);
+            assert_eq!(camera.position, anim.to.position);
+            assert_eq!(camera.zoom, anim.to.zoom);
+        }
+    }
+
+    #[test]
+    fn glide_is_frame_rate_independent() {
+        let run = |fps: u32| {
+            let mut glide = PanGlide::new([3.0, -2.0], 4.0);
+            let mut moved = [0.0f32, 0.0];
+            for _ in 0..fps {
+                let step = glide.advance(1.0 / fps as f32);
+                moved = [moved[0] + step[0], moved[1] + step[1]];
+            }
+            (moved, glide.velocity)
+        };
+        let (slow, slow_velocity) = run(30);
+        let (fast, fast_velocity) = run(144);
+        let (once, once_velocity) = {
+            let mut glide = PanGlide::new([3.0, -2.0], 4.0);
+            (glide.advance(1.0), glide.velocity)
+        };
+        for (a, b) in [
+            (slow, fast),
+            (slow, once),
+            (slow_velocity, fast_velocity),
+            (slow_velocity, once_velocity),
+        ] {
+            assert!(close(a[0], b[0]) && close(a[1], b[1]), "{:?} != {:?}", a, b);
+        }
+    }
+}
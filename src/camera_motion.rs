@@ -0,0 +1,258 @@
+use std::time::Instant;
+
+use crate::camera::{Camera, CameraAnimation, Easing, PanGlide};
+use crate::formats::PIXELS_PER_UNIT;
+use crate::state::AppState;
+use crate::types::*;
+
+// 전환 시간 (초)
+pub const CAMERA_JUMP_DURATION: f32 = 0.45;
+pub const CAMERA_ZOOM_DURATION: f32 = 0.12;
+const CAMERA_REVEAL_DURATION: f32 = 0.2;
+
+// 화면에 맞출 때 카드 묶음 둘레에 남기는 여백 (px)
+const FIT_PADDING: f32 = 48.0;
+// 카드 한두 장에 맞출 때 지나치게 커지지 않도록 100%의 배수로 제한
+const FIT_MAX_SCALE: f32 = 2.0;
+// 키보드로 옮긴 초점 카드를 화면 가장자리에서 띄우는 여백 (px)
+const REVEAL_PADDING: f32 = 32.0;
+// 툴바 위쪽으로 더 띄우는 간격 (px)
+const TOOLBAR_CLEARANCE: f32 = 20.0;
+
+// 놓기 직전 이 시간(초) 동안의 움직임으로 관성 속도를 구함
+const PAN_VELOCITY_WINDOW: f32 = 0.1;
+// 놓기 전 이만큼(초) 멈춰 있었으면 관성 없이 그 자리에 멈춤
+const PAN_RELEASE_HOLD: f32 = 0.05;
+// 화면에서 이보다 느리면(px/초) 관성 이동을 끝냄
+const GLIDE_MIN_SPEED: f32 = 20.0;
+
+// 창이 멈췄다 돌아온 경우 한 프레임에 너무 많이 건너뛰지 않도록 제한
+const MAX_FRAME_DT: f32 = 0.1;
+
+impl AppState {
+    /// 현재 카메라에서 target으로 부드럽게 이동
+    pub fn animate_camera_to(&mut self, target: Camera, duration: f32, easing: Easing) {
+        self.camera_glide = None;
+        self.camera_animation = Some(CameraAnimation::new(self.camera, target, duration, easing));
+        self.camera_animation_tick = Instant::now();
+        self.window.request_redraw();
+    }
+
+    /// 진행 중인 전환의 목표 (없으면 현재 카메라). 연속 입력을 목표 기준으로 누적할 때 사용
+    pub fn camera_target(&self) -> Camera {
+        self.camera_animation.map_or(self.camera, |anim| anim.to)
+    }
+
+    /// 사용자가 직접 카메라를 움직이면 전환과 관성 이동을 멈춤 (현재 위치에서 정지)
+    pub fn stop_camera_animation(&mut self) {
+        self.camera_animation = None;
+        self.camera_glide = None;
+    }
+
+    /// 끌어 이동 중 카메라 위치를 기록. 속도 계산에 필요한 최근 기록만 남김
+    pub fn record_pan_sample(&mut self) {
+        let now = Instant::now();
+        self.pan_samples
+            .retain(|(at, _)| now.duration_since(*at).as_secs_f32() <= PAN_VELOCITY_WINDOW);
+        self.pan_samples.push((now, self.camera.position));
+    }
+
+    /// 끌어 이동을 놓을 때 최근 속도로 관성 이동을 시작 (설정에서 끄면 그대로 멈춤)
+    pub fn release_pan(&mut self) {
+        let samples = std::mem::take(&mut self.pan_samples);
+        if !self.settings.pan_inertia {
+            return;
+        }
+        let (Some(&(first_at, first)), Some(&(last_at, last))) = (samples.first(), samples.last())
+        else {
+            return;
+        };
+        let now = Instant::now();
+        let span = last_at.duration_since(first_at).as_secs_f32();
+        if span <= 0.0 || now.duration_since(last_at).as_secs_f32() > PAN_RELEASE_HOLD {
+            return;
+        }
+        let velocity = [(last[0] - first[0]) / span, (last[1] - first[1]) / span];
+        let glide = PanGlide::new(velocity, self.settings.pan_friction);
+        if glide.speed() < self.min_glide_speed() {
+            return;
+        }
+        self.camera_animation = None;
+        self.camera_glide = Some(glide);
+        self.camera_animation_tick = now;
+        self.window.request_redraw();
+    }
+
+    /// 관성 이동을 끝낼 월드 속도 (화면 px/초 기준을 현재 배율로 환산)
+    fn min_glide_speed(&self) -> f32 {
+        let scale = self.camera.zoom * self.egui.canvas_rect.height().max(1.0) * 0.5;
+        GLIDE_MIN_SPEED / scale
+    }
+
+    /// 100% 배율: 카드가 외부 포맷의 픽셀 크기(PIXELS_PER_UNIT)로 보이는 배율
+    fn actual_size_zoom(&self) -> f32 {
+        2.0 * PIXELS_PER_UNIT / self.egui.canvas_rect.height().max(1.0)
+    }
+
+    /// 캔버스에서 툴바 같은 오버레이에 가리지 않는 영역 (egui 좌표)
+    fn unobstructed_canvas(&self) -> egui::Rect {
+        let mut rect = self.egui.canvas_rect;
+        if self.settings.show_toolbar {
+            rect.max.y -= TOOLBAR_BOTTOM_OFFSET + TOOLBAR_CLEARANCE;
+        }
+        if rect.height() <= 0.0 {
+            return self.egui.canvas_rect;
+        }
+        rect
+    }
+
+    /// 월드 경계 상자(카드 중심 기준)가 가리지 않는 영역 가운데 여백을 두고 들어오는 카메라
+    fn framing_camera(&self, min: [f32; 2], max: [f32; 2]) -> Option<Camera> {
+        let canvas = self.egui.canvas_rect;
+        let view = self.unobstructed_canvas();
+        if canvas.width() <= 0.0 || canvas.height() <= 0.0 {
+            return None;
+        }
+        // 카드 크기만큼 넓혀 가장자리 카드도 온전히 보이게
+        let size = [
+            max[0] - min[0] + CARD_HALF_W * 2.0,
+            max[1] - min[1] + CARD_HALF_H * 2.0,
+        ];
+        let room = [
+            (view.width() - FIT_PADDING * 2.0).max(1.0),
+            (view.height() - FIT_PADDING * 2.0).max(1.0),
+        ];
+        // 월드 1단위당 화면 px (가로세로 같음: zoom * 캔버스 높이 / 2)
+        let max_scale = self.actual_size_zoom() * FIT_MAX_SCALE * canvas.height() * 0.5;
+        let scale = (room[0] / size[0]).min(room[1] / size[1]).min(max_scale);
+        let zoom = (2.0 * scale / canvas.height()).clamp(0.001, 100.0);
+        let scale = zoom * canvas.height() * 0.5;
+
+        // 상자 중심이 보이는 영역의 중심에 오도록 캔버스 중심과의 차이만큼 보정
+        let offset = view.center() - canvas.center();
+        let center = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5];
+        Some(Camera::new(
+            [center[0] - offset.x / scale, center[1] + offset.y / scale],
+            zoom,
+        ))
+    }
+
+    /// idx 카드가 가리지 않는 영역 밖(여백 포함)에 있으면 최소한만 움직여 보이게 함
+    pub fn reveal_card(&mut self, idx: usize) {
+        let Some(center) = self.document.block_positions.get(idx).map(|inst| inst.position)
+        else {
+            return;
+        };
+        let canvas = self.egui.canvas_rect;
+        if canvas.width() <= 0.0 || canvas.height() <= 0.0 {
+            return;
+        }
+        let mut target = self.camera_target();
+        let scale = target.zoom * canvas.height() * 0.5;
+        let view = self.unobstructed_canvas().shrink(REVEAL_PADDING);
+        // 보이는 영역의 월드 범위 (y는 화면과 반대)
+        let min = [
+            target.position[0] + (view.min.x - canvas.center().x) / scale,
+            target.position[1] - (view.max.y - canvas.center().y) / scale,
+        ];
+        let max = [
+            target.position[0] + (view.max.x - canvas.center().x) / scale,
+            target.position[1] - (view.min.y - canvas.center().y) / scale,
+        ];
+        let half = [CARD_HALF_W, CARD_HALF_H];
+        let mut shift = [0.0; 2];
+        for axis in 0..2 {
+            let (lo, hi) = (center[axis] - half[axis], center[axis] + half[axis]);
+            // 영역보다 큰 카드는 가운데를 맞춤
+            shift[axis] = if hi - lo > max[axis] - min[axis] {
+                center[axis] - (min[axis] + max[axis]) * 0.5
+            } else if lo < min[axis] {
+                lo - min[axis]
+            } else if hi > max[axis] {
+                hi - max[axis]
+            } else {
+                0.0
+            };
+        }
+        if shift == [0.0, 0.0] {
+            return;
+        }
+        target.position[0] += shift[0];
+        target.position[1] += shift[1];
+        self.animate_camera_to(target, CAMERA_REVEAL_DURATION, Easing::EaseInOutCubic);
+    }
+
+    fn animate_framing(&mut self, bounds: Option<([f32; 2], [f32; 2])>) {
+        let Some(target) = bounds.and_then(|(min, max)| self.framing_camera(min, max)) else {
+            return;
+        };
+        self.animate_camera_to(target, CAMERA_JUMP_DURATION, Easing::EaseInOutCubic);
+    }
+
+    /// 모든 카드가 보이도록
+    pub fn zoom_to_fit(&mut self) {
+        self.animate_framing(self.document.bounds());
+    }
+
+    /// 선택한 카드가 보이도록 (선택이 없으면 아무것도 안 함)
+    pub fn zoom_to_selection(&mut self) {
+        let mut positions = self
+            .selected_indices
+            .iter()
+            .filter_map(|&i| self.document.block_positions.get(i))
+            .map(|inst| inst.position);
+        let bounds = positions.next().map(|first| {
+            positions.fold((first, first), |(min, max), p| {
+                (
+                    [min[0].min(p[0]), min[1].min(p[1])],
+                    [max[0].max(p[0]), max[1].max(p[1])],
+                )
+            })
+        });
+        self.animate_framing(bounds);
+    }
+
+    /// 지금 보이는 영역의 중심을 유지한 채 100%로
+    pub fn zoom_to_actual_size(&mut self) {
+        let mut target = self.camera_target();
+        let factor = self.actual_size_zoom() / target.zoom;
+        let view = self.unobstructed_canvas();
+        let canvas = self.egui.canvas_rect;
+        let center_ndc = [
+            (view.center().x - canvas.min.x) / canvas.width().max(1.0) * 2.0 - 1.0,
+            (view.center().y - canvas.min.y) / canvas.height().max(1.0) * -2.0 + 1.0,
+        ];
+        target.zoom_at(center_ndc, factor, self.canvas_aspect());
+        self.animate_camera_to(target, CAMERA_JUMP_DURATION, Easing::EaseInOutCubic);
+    }
+
+    /// 프레임 시간만큼 전환/관성 이동을 진행. 아직 움직이는 중이면 true
+    pub fn tick_camera_animation(&mut self) -> bool {
+        if self.camera_animation.is_none() && self.camera_glide.is_none() {
+            return false;
+        }
+        let now = Instant::now();
+        let dt = now
+            .duration_since(self.camera_animation_tick)
+            .as_secs_f32()
+            .min(MAX_FRAME_DT);
+        self.camera_animation_tick = now;
+
+        if let Some(anim) = &mut self.camera_animation {
+            self.camera = anim.advance(dt);
+            if anim.is_finished() {
+                self.camera_animation = None;
+            }
+        }
+        let min_speed = self.min_glide_speed();
+        if let Some(glide) = &mut self.camera_glide {
+            let [dx, dy] = glide.advance(dt);
+            self.camera.position[0] += dx;
+            self.camera.position[1] += dy;
+            if glide.speed() < min_speed {
+                self.camera_glide = None;
+            }
+        }
+        self.camera_animation.is_some() || self.camera_glide.is_some()
+    }
+}
@@ -0,0 +1,207 @@
+// 선택한 카드에 대한 편집 (명령으로 실행)
+use crate::document::CardKind;
+use crate::formats;
+use crate::journal::Operation;
+use crate::navigation::Direction;
+use crate::state::AppState;
+
+// 복제한 카드를 원본에서 비켜 놓는 거리 (월드 단위)
+const DUPLICATE_OFFSET: [f32; 2] = [0.1, -0.1];
+// Shift+방향키는 보통 이동의 이 배수만큼
+const NUDGE_FAR_FACTOR: f32 = 10.0;
+// 이만큼(격자 한 칸 대비) 가까우면 이미 격자선 위로 봄 (부동소수 오차)
+const ON_GRID_EPSILON: f32 = 1e-3;
+
+/// 방향키로 옮길 때 기준 카드가 갈 위치. 격자가 있으면 진행 방향으로 steps칸 떨어진
+/// 격자선에, 다른 축은 가장 가까운 격자선에 맞춤 (격자 밖의 카드는 첫 이동에서 격자에 올라감)
+fn nudge_target(anchor: [f32; 2], direction: Direction, steps: f32, grid: Option<f32>) -> [f32; 2] {
+    let vector = direction.vector();
+    let mut target = anchor;
+    for axis in 0..2 {
+        let v = vector[axis];
+        target[axis] = match grid {
+            None => anchor[axis] + v * steps / formats::PIXELS_PER_UNIT,
+            Some(grid) => {
+                let cells = anchor[axis] / grid;
+                let cell = if v > 0.0 {
+                    (cells + ON_GRID_EPSILON).floor() + steps
+                } else if v < 0.0 {
+                    (cells - ON_GRID_EPSILON).ceil() - steps
+                } else {
+                    cells.round()
+                };
+                cell * grid
+            }
+        };
+    }
+    target
+}
+
+impl AppState {
+    fn finish_card_edit(&mut self, op: Operation) {
+        self.record_operation(op);
+        self.mark_positions_dirty();
+        self.window.request_redraw();
+    }
+
+    /// 빈 텍스트 카드를 추가하고 선택
+    pub fn add_card_at(&mut self, position: [f32; 2]) {
+        let edge_start = self.document.edges.len();
+        let color = self.settings.card_color(self.document.len());
+        let idx = self.document.push_card(position, color, CardKind::Text, "");
+        self.selected_indices = vec![idx];
+        self.finish_card_edit(Operation::appended(&self.document, idx, edge_start));
+    }
+
+    /// 개요(Markdown/OPML)면 카드 묶음으로, 아니면 텍스트 카드 한 장으로 붙여넣기
+    pub fn paste_at(&mut self, text: &str, position: [f32; 2]) {
+        if let Some(parsed) = formats::outline::parse_pasted(text) {
+            self.add_pasted_outline(parsed, position);
+            return;
+        }
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let edge_start = self.document.edges.len();
+        let color = self.settings.card_color(self.document.len());
+        let idx = self
+            .document
+            .push_card(position, color, CardKind::Text, text);
+        self.selected_indices = vec![idx];
+        self.finish_card_edit(Operation::appended(&self.document, idx, edge_start));
+    }
+
+    pub fn color_selection(&mut self, color: [f32; 3]) {
+        for &idx in &self.selected_indices {
+            if let Some(instance) = self.document.block_positions.get_mut(idx) {
+                instance.color = color;
+            }
+        }
+        let op = Operation::colors(&self.document, &self.selected_indices);
+        self.finish_card_edit(op);
+    }
+
+    /// 선택한 카드와 그 사이의 연결을 옆에 복제하고, 복제본을 선택
+    pub fn duplicate_selection(&mut self) {
+        if self.selected_indices.is_empty() {
+            return;
+        }
+        let mut copy = self.document.extract(&self.selected_indices);
+        copy.translate(DUPLICATE_OFFSET);
+        let edge_start = self.document.edges.len();
+        let range = self.document.append(copy);
+        let op = Operation::appended(&self.document, range.start, edge_start);
+        self.selected_indices = range.collect();
+        self.finish_card_edit(op);
+    }
+
+    /// 선택한 다른 카드들에서 target으로 연결
+    pub fn connect_selection_to(&mut self, target: usize) {
+        let (card_start, edge_start) = (self.document.len(), self.document.edges.len());
+        for &idx in &self.selected_indices {
+            self.document.connect(idx, target);
+        }
+        if self.document.edges.len() > edge_start {
+            self.finish_card_edit(Operation::appended(&self.document, card_start, edge_start));
+        }
+    }
+
+    /// 선택한 카드를 다른 카드 위에 그려지도록 맨 뒤 순서로 옮김
+    pub fn bring_selection_to_front(&mut self) {
+        let mut indices: Vec<usize> = self
+            .selected_indices
+            .iter()
+            .copied()
+            .filter(|&i| i < self.document.len())
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        if indices.is_empty() {
+            return;
+        }
+        self.document.raise(&indices);
+        let start = self.document.len() - indices.len();
+        self.selected_indices = (start..self.document.len()).collect();
+        self.finish_card_edit(Operation::Raise(indices));
+    }
+
+    pub fn delete_selection(&mut self) {
+        if self.selected_indices.is_empty() {
+            return;
+        }
+        let indices = std::mem::take(&mut self.selected_indices);
+        self.document.remove_cards(&indices);
+        self.finish_card_edit(Operation::Remove(indices));
+    }
+
+    /// 방향키로 선택을 옮김. 격자 맞춤을 켜면 격자 한 칸, 아니면 100%에서 1px 단위.
+    /// 격자에 맞출 때는 처음 선택한 카드를 기준으로 선택 전체를 같은 만큼 옮김
+    pub fn nudge_selection(&mut self, direction: Direction, far: bool) {
+        let Some(anchor) = self
+            .selected_indices
+            .first()
+            .and_then(|&idx| self.document.block_positions.get(idx))
+            .map(|instance| instance.position)
+        else {
+            return;
+        };
+        let steps = if far { NUDGE_FAR_FACTOR } else { 1.0 };
+        let grid = self
+            .settings
+            .snap_to_grid
+            .then_some(self.settings.grid_size);
+        let target = nudge_target(anchor, direction, steps, grid);
+        let (dx, dy) = (target[0] - anchor[0], target[1] - anchor[1]);
+        for &idx in &self.selected_indices {
+            if let Some(instance) = self.document.block_positions.get_mut(idx) {
+                instance.position[0] += dx;
+                instance.position[1] += dy;
+            }
+        }
+        let op = Operation::positions(&self.document, &self.selected_indices);
+        self.finish_card_edit(op);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: [f32; 2], b: [f32; 2]) -> bool {
+        (a[0] - b[0]).abs() < 1e-5 && (a[1] - b[1]).abs() < 1e-5
+    }
+
+    #[test]
+    fn nudge_moves_one_pixel_or_ten_without_the_grid() {
+        let px = 1.0 / formats::PIXELS_PER_UNIT;
+        let target = nudge_target([0.013, 0.2], Direction::Right, 1.0, None);
+        assert!(close(target, [0.013 + px, 0.2]), "{:?}", target);
+        let target = nudge_target([0.013, 0.2], Direction::Down, NUDGE_FAR_FACTOR, None);
+        assert!(close(target, [0.013, 0.2 - 10.0 * px]), "{:?}", target);
+    }
+
+    #[test]
+    fn nudge_steps_along_grid_lines() {
+        let grid = Some(0.1);
+        let target = nudge_target([0.2, 0.3], Direction::Right, 1.0, grid);
+        assert!(close(target, [0.3, 0.3]), "{:?}", target);
+        let target = nudge_target([0.2, 0.3], Direction::Up, NUDGE_FAR_FACTOR, grid);
+        assert!(close(target, [0.2, 1.3]), "{:?}", target);
+    }
+
+    #[test]
+    fn first_grid_nudge_puts_an_off_grid_card_on_the_grid() {
+        let grid = Some(0.1);
+        // 진행 방향으로는 다음 격자선, 다른 축은 가장 가까운 격자선
+        let target = nudge_target([0.23, 0.48], Direction::Right, 1.0, grid);
+        assert!(close(target, [0.3, 0.5]), "{:?}", target);
+        let target = nudge_target([0.23, 0.48], Direction::Left, 1.0, grid);
+        assert!(close(target, [0.2, 0.5]), "{:?}", target);
+        let target = nudge_target([-0.23, 0.48], Direction::Down, 1.0, grid);
+        assert!(close(target, [-0.2, 0.4]), "{:?}", target);
+        // 그다음부터는 한 칸씩
+        let target = nudge_target(target, Direction::Down, 1.0, grid);
+        assert!(close(target, [-0.2, 0.3]), "{:?}", target);
+    }
+}
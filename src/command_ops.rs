@@ -0,0 +1,185 @@
+use winit::keyboard::KeyCode;
+
+use crate::camera::{Camera, Easing};
+use crate::camera_motion::CAMERA_JUMP_DURATION;
+use crate::commands::Command;
+use crate::document::{CardKind, Document};
+use crate::journal::Operation;
+use crate::keymap::{KeyChord, Keymap};
+use crate::navigation::{self, Direction};
+use crate::state::{AppState, InteractionMode};
+use crate::types::*;
+use crate::ui;
+use crate::ui::command_palette::PaletteEntry;
+use crate::ui::file_dialog::FileDialogMode;
+use crate::ui::left_tab::LeftSection;
+
+impl AppState {
+    /// 키맵에 등록된 단축키면 명령을 실행하고 true.
+    /// 끌기/선택 중에는 선택이나 문서가 바뀌지 않도록 무시
+    pub fn handle_shortcut(&mut self, key: KeyCode) -> bool {
+        if !matches!(self.interaction, InteractionMode::Idle) {
+            return false;
+        }
+        let chord = KeyChord::new(key, self.modifiers);
+        let Some(command) = self.keymap.lookup(chord) else {
+            return false;
+        };
+        self.run_command(command);
+        true
+    }
+
+    /// 위치가 필요한 명령(추가, 붙여넣기, 연결)은 커서가 캔버스 위면 커서, 아니면 화면 중심 기준
+    pub fn run_command(&mut self, command: Command) {
+        let anchor = if self.is_pointer_in_canvas() {
+            self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect())
+        } else {
+            self.camera.position
+        };
+        self.run_command_at(command, anchor);
+    }
+
+    /// anchor: 명령의 기준 위치 (월드 좌표)
+    pub fn run_command_at(&mut self, command: Command, anchor: [f32; 2]) {
+        match command {
+            Command::OpenPalette => self.command_palette_state.open(),
+            Command::AddCard => self.add_card_at(anchor),
+            Command::AddCards(count) => self.add_card_batch(count, anchor),
+            Command::Paste => {
+                if let Some(text) = self.egui.winit_state.clipboard_text() {
+                    self.paste_at(&text, anchor);
+                }
+            }
+            Command::SelectAll => {
+                self.selected_indices = (0..self.document.len()).collect();
+            }
+            Command::ClearSelection => self.selected_indices.clear(),
+            Command::Duplicate => self.duplicate_selection(),
+            Command::Delete => self.delete_selection(),
+            Command::BringToFront => self.bring_selection_to_front(),
+            Command::ConnectToCard => {
+                if let Some(target) = self.card_at(anchor) {
+                    self.connect_selection_to(target);
+                }
+            }
+            Command::CardColor(n) => {
+                if let Some(&color) = CARD_COLORS.get(n) {
+                    self.color_selection(color);
+                }
+            }
+            Command::Nudge(direction) if self.navigation_mode => self.move_focus(direction),
+            Command::Nudge(direction) => self.nudge_selection(direction, false),
+            Command::NudgeFar(direction) => self.nudge_selection(direction, true),
+            Command::ToggleNavigation => self.navigation_mode = !self.navigation_mode,
+            Command::ZoomToFit => self.zoom_to_fit(),
+            Command::ZoomToSelection => self.zoom_to_selection(),
+            Command::ZoomToActualSize => self.zoom_to_actual_size(),
+            Command::ResetBoard => self.reset_board(),
+            Command::Import => self.file_dialog_state.open(FileDialogMode::Import),
+            Command::Export => self.file_dialog_state.open(FileDialogMode::Export),
+            Command::NewTab => self.new_tab(),
+            Command::CloseTab => self.request_close_tab(self.top_bar_state.active_tab),
+            Command::CloseTabAt(idx) => self.request_close_tab(idx),
+            Command::ToggleInspector => self.inspector_state.open = !self.inspector_state.open,
+            Command::ShowProjects => self.left_tab_state.toggle(LeftSection::Projects),
+            Command::ShowDashboard => self.left_tab_state.toggle(LeftSection::Dashboard),
+            Command::ShowSettings => self.left_tab_state.toggle(LeftSection::Settings),
+        }
+        self.window.request_redraw();
+    }
+
+    /// 팔레트 목록 (현재 키맵의 단축키 표시)
+    pub fn palette_entries(&self) -> Vec<PaletteEntry> {
+        Command::all()
+            .into_iter()
+            .filter(|&command| command != Command::OpenPalette)
+            .map(|command| PaletteEntry {
+                command,
+                title: command.title(),
+                shortcut: self.keymap.label(command),
+            })
+            .collect()
+    }
+
+    /// 키맵 파일을 다시 읽음 (설정 패널에서 요청)
+    pub fn reload_keymap(&mut self) {
+        let (keymap, warnings) = match &self.keymap_path {
+            Some(path) => Keymap::load(path),
+            None => (Keymap::default(), Vec::new()),
+        };
+        self.keymap = keymap;
+        self.settings_panel_state.keymap_warnings = warnings;
+    }
+
+    /// anchor를 시작으로 격자 모양으로 추가하고 순서대로 연결
+    fn add_card_batch(&mut self, count: usize, anchor: [f32; 2]) {
+        let (start, edge_start) = (self.document.len(), self.document.edges.len());
+        let cols = (count as f32).sqrt().ceil() as usize;
+        for i in 0..count {
+            let col = (i % cols) as f32;
+            let row = (i / cols) as f32;
+            let color = self.settings.card_color(start + i);
+            let position = [
+                anchor[0] + col * GRID_SPACING_X,
+                anchor[1] + row * GRID_SPACING_Y,
+            ];
+            let idx = self
+                .document
+                .push_card(position, color, CardKind::Text, "");
+            if idx > 0 {
+                self.document.connect(idx - 1, idx);
+            }
+        }
+        self.mark_positions_dirty();
+        if self.document.len() > start {
+            self.record_operation(Operation::appended(&self.document, start, edge_start));
+        }
+    }
+
+    /// 선택한 카드(여럿이면 마지막)에서 direction 쪽 가장 가까운 카드를 선택하고 화면에 보이게 함.
+    /// 선택이 없으면 화면 중심에 가장 가까운 카드에서 시작
+    fn move_focus(&mut self, direction: Direction) {
+        let next = match self.selected_indices.last() {
+            Some(&from) => navigation::nearest_toward(&self.document, from, direction),
+            None => navigation::nearest_to(&self.document, self.camera_target().position),
+        };
+        let Some(next) = next else {
+            return;
+        };
+        self.selected_indices = vec![next];
+        self.reveal_card(next);
+    }
+
+    fn reset_board(&mut self) {
+        self.document = Document::default_grid();
+        self.selected_indices.clear();
+        self.mark_positions_dirty();
+        self.animate_camera_to(
+            Camera::default(),
+            CAMERA_JUMP_DURATION,
+            Easing::EaseInOutCubic,
+        );
+        self.record_operation(Operation::Clear);
+        self.record_operation(Operation::appended(&self.document, 0, 0));
+    }
+
+    /// 툴바/팔레트/왼쪽 탭/우클릭 메뉴에서 고른 명령 실행
+    pub fn apply_ui_commands(
+        &mut self,
+        toolbar: Option<Command>,
+        palette: Option<Command>,
+        left_tab: Option<Command>,
+        context_menu: &ui::context_menu::ContextMenuAction,
+    ) {
+        if let Some(command) = toolbar {
+            // 툴바 위에 커서가 있으므로 화면 중심 기준
+            self.run_command_at(command, self.camera.position);
+        }
+        for command in palette.into_iter().chain(left_tab) {
+            self.run_command(command);
+        }
+        if let Some(command) = context_menu.command {
+            self.run_command_at(command, context_menu.anchor);
+        }
+    }
+}
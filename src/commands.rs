@@ -0,0 +1,212 @@
+// 명령 목록. 툴바, 우클릭 메뉴, 단축키, 명령 팔레트가 모두 이 명령으로 동작을 요청한다
+use crate::navigation::Direction;
+use crate::types::CARD_COLORS;
+
+// 툴바의 대량 생성 개수
+pub const BATCH_SIZES: [usize; 3] = [100, 1000, 10000];
+
+const COLOR_NAMES: [&str; CARD_COLORS.len()] = ["분홍", "보라", "노랑", "시안", "주황", "다크"];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Command {
+    OpenPalette,
+    AddCard,
+    // 개수만큼 격자로 추가 (BATCH_SIZES 중 하나)
+    AddCards(usize),
+    Paste,
+    SelectAll,
+    ClearSelection,
+    Duplicate,
+    Delete,
+    BringToFront,
+    // 선택한 다른 카드들에서 기준 위치의 카드로 연결
+    ConnectToCard,
+    // 선택한 카드를 CARD_COLORS[n]으로
+    CardColor(usize),
+    // 선택을 조금 옮김. 탐색 모드에서는 그 방향의 가장 가까운 카드로 초점 이동
+    Nudge(Direction),
+    // 선택을 크게 옮김
+    NudgeFar(Direction),
+    ToggleNavigation,
+    ZoomToFit,
+    ZoomToSelection,
+    ZoomToActualSize,
+    ResetBoard,
+    Import,
+    Export,
+    NewTab,
+    CloseTab,
+    // 상단 탭 메뉴에서 고른 탭 닫기 (팔레트/키맵에는 없음)
+    CloseTabAt(usize),
+    ToggleInspector,
+    ShowProjects,
+    ShowDashboard,
+    ShowSettings,
+}
+
+impl Command {
+    /// 팔레트/키맵에 나오는 순서
+    pub fn all() -> Vec<Command> {
+        let mut all = vec![Command::OpenPalette, Command::AddCard];
+        all.extend(BATCH_SIZES.iter().map(|&n| Command::AddCards(n)));
+        all.extend([
+            Command::Paste,
+            Command::SelectAll,
+            Command::ClearSelection,
+            Command::Duplicate,
+            Command::Delete,
+            Command::BringToFront,
+            Command::ConnectToCard,
+        ]);
+        all.extend((0..CARD_COLORS.len()).map(Command::CardColor));
+        all.extend(Direction::ALL.map(Command::Nudge));
+        all.extend(Direction::ALL.map(Command::NudgeFar));
+        all.extend([
+            Command::ToggleNavigation,
+            Command::ZoomToFit,
+            Command::ZoomToSelection,
+            Command::ZoomToActualSize,
+            Command::ResetBoard,
+            Command::Import,
+            Command::Export,
+            Command::NewTab,
+            Command::CloseTab,
+            Command::ToggleInspector,
+            Command::ShowProjects,
+            Command::ShowDashboard,
+            Command::ShowSettings,
+        ]);
+        all
+    }
+
+    /// 키맵 파일에 쓰는 이름
+    pub fn id(self) -> String {
+        match self {
+            Command::OpenPalette => "command_palette".into(),
+            Command::AddCard => "add_card".into(),
+            Command::AddCards(n) => format!("add_cards_{}", n),
+            Command::Paste => "paste".into(),
+            Command::SelectAll => "select_all".into(),
+            Command::ClearSelection => "clear_selection".into(),
+            Command::Duplicate => "duplicate".into(),
+            Command::Delete => "delete".into(),
+            Command::BringToFront => "bring_to_front".into(),
+            Command::ConnectToCard => "connect_to_card".into(),
+            Command::CardColor(n) => format!("card_color_{}", n + 1),
+            Command::Nudge(direction) => format!("nudge_{}", direction.id()),
+            Command::NudgeFar(direction) => format!("nudge_far_{}", direction.id()),
+            Command::ToggleNavigation => "toggle_navigation".into(),
+            Command::ZoomToFit => "zoom_to_fit".into(),
+            Command::ZoomToSelection => "zoom_to_selection".into(),
+            Command::ZoomToActualSize => "zoom_to_actual_size".into(),
+            Command::ResetBoard => "reset_board".into(),
+            Command::Import => "import".into(),
+            Command::Export => "export".into(),
+            Command::NewTab => "new_tab".into(),
+            Command::CloseTab => "close_tab".into(),
+            Command::CloseTabAt(idx) => format!("close_tab_{}", idx + 1),
+            Command::ToggleInspector => "toggle_inspector".into(),
+            Command::ShowProjects => "show_projects".into(),
+            Command::ShowDashboard => "show_dashboard".into(),
+            Command::ShowSettings => "show_settings".into(),
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Command> {
+        Command::all().into_iter().find(|c| c.id() == id)
+    }
+
+    /// 팔레트/메뉴에 보이는 이름
+    pub fn title(self) -> String {
+        match self {
+            Command::OpenPalette => "명령 팔레트".into(),
+            Command::AddCard => "카드 추가".into(),
+            Command::AddCards(n) => format!("카드 {}개 추가", n),
+            Command::Paste => "붙여넣기".into(),
+            Command::SelectAll => "모두 선택".into(),
+            Command::ClearSelection => "선택 해제".into(),
+            Command::Duplicate => "복제".into(),
+            Command::Delete => "삭제".into(),
+            Command::BringToFront => "맨 앞으로".into(),
+            Command::ConnectToCard => "선택한 카드에서 연결".into(),
+            Command::CardColor(n) => format!("카드 색: {}", COLOR_NAMES.get(n).unwrap_or(&"")),
+            Command::Nudge(direction) => format!("선택 옮기기: {}", direction.name()),
+            Command::NudgeFar(direction) => format!("선택 크게 옮기기: {}", direction.name()),
+            Command::ToggleNavigation => "키보드 탐색 모드".into(),
+            Command::ZoomToFit => "전체 보기".into(),
+            Command::ZoomToSelection => "선택한 카드 보기".into(),
+            Command::ZoomToActualSize => "실제 크기 (100%)".into(),
+            Command::ResetBoard => "보드 초기화".into(),
+            Command::Import => "가져오기".into(),
+            Command::Export => "내보내기".into(),
+            Command::NewTab => "새 탭".into(),
+            Command::CloseTab | Command::CloseTabAt(_) => "탭 닫기".into(),
+            Command::ToggleInspector => "인스펙터 열기/닫기".into(),
+            Command::ShowProjects => "프로젝트 패널".into(),
+            Command::ShowDashboard => "대시보드 패널".into(),
+            Command::ShowSettings => "설정 패널".into(),
+        }
+    }
+}
+
+/// query의 글자가 text에 순서대로 모두 나오면 점수(클수록 잘 맞음), 아니면 None.
+/// 대소문자와 query의 공백은 무시하고, 연속으로 맞거나 단어 첫 글자에 맞으면 가산
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut pos = 0;
+    let mut last_match: Option<usize> = None;
+    for q in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let found = pos + text[pos..].iter().position(|&c| c == q)?;
+        score += 1;
+        if last_match.is_some_and(|last| last + 1 == found) {
+            score += 3;
+        }
+        if found == 0 || matches!(text[found - 1], ' ' | '_' | ':' | '(') {
+            score += 2;
+        }
+        last_match = Some(found);
+        pos = found + 1;
+    }
+    // 같은 점수면 짧은 이름이 위로
+    Some(score * 100 - text.len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_round_trip_and_tab_buttons_stay_out_of_the_keymap() {
+        for command in Command::all() {
+            assert_eq!(Command::from_id(&command.id()), Some(command));
+        }
+        assert!(!Command::all().contains(&Command::CloseTabAt(0)));
+        assert_eq!(Command::from_id(&Command::CloseTabAt(0).id()), None);
+    }
+
+    #[test]
+    fn fuzzy_score_needs_every_letter_in_order() {
+        assert!(fuzzy_score("ztf", "zoom to fit").is_some());
+        assert_eq!(fuzzy_score("fz", "zoom to fit"), None);
+        assert_eq!(fuzzy_score("zoomx", "zoom to fit"), None);
+        // 대소문자와 검색어의 공백은 무시, 한글도 같은 규칙
+        assert_eq!(
+            fuzzy_score("Z T F", "zoom to fit"),
+            fuzzy_score("ztf", "zoom to fit")
+        );
+        assert!(fuzzy_score("보기", "전체 보기").is_some());
+    }
+
+    #[test]
+    fn fuzzy_score_ranks_runs_and_word_starts_first() {
+        let score = |query, text| fuzzy_score(query, text).expect("matches");
+        // 연속으로 맞는 글자가 띄엄띄엄 맞는 글자보다 위
+        assert!(score("ab", "abxx") > score("ab", "axbx"));
+        // 단어 첫 글자에 맞으면 가산
+        assert!(score("tab", "new tab") > score("tab", "toggle navigation bar"));
+        assert!(score("nt", "new tab") > score("nt", "anything"));
+        // 같은 점수면 짧은 이름이 위
+        assert!(score("del", "delete") > score("del", "delete all"));
+    }
+}
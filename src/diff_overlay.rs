@@ -0,0 +1,89 @@
+use crate::history::DocumentDiff;
+use crate::state::AppState;
+use crate::types::*;
+use crate::ui::inspector::InspectorAction;
+
+fn grow_buffer(device: &wgpu::Device, label: &str, capacity: usize, stride: usize) -> wgpu::Buffer {
+    device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some(label),
+        size: (capacity * stride) as u64,
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+impl AppState {
+    pub fn apply_inspector_action(&mut self, action: &InspectorAction) {
+        if let Some(name) = &action.take_snapshot {
+            self.history.take(name, &self.document);
+        }
+        if let Some(idx) = action.delete_snapshot {
+            if idx < self.history.snapshots.len() {
+                self.history.snapshots.remove(idx);
+                self.inspector_state.history.forget_snapshot(idx);
+            }
+        }
+    }
+
+    /// 비교 대상이 바뀌었거나 현재 문서와 비교 중에 문서가 바뀌면 차이를 다시 계산
+    pub fn refresh_diff(&mut self) {
+        let panel = &self.inspector_state.history;
+        let compare = panel.base.map(|base| (base, panel.target));
+        let against_current = matches!(compare, Some((_, None)));
+        if compare == self.diff_compare && !(against_current && self.positions_dirty) {
+            return;
+        }
+        self.diff_compare = compare;
+
+        self.diff = compare.and_then(|(base, target)| {
+            let old = &self.history.snapshots.get(base)?.document;
+            let new = match target {
+                Some(target) => &self.history.snapshots.get(target)?.document,
+                None => &self.document,
+            };
+            Some(DocumentDiff::between(old, new))
+        });
+        self.upload_diff_overlay();
+        self.window.request_redraw();
+    }
+
+    fn upload_diff_overlay(&mut self) {
+        let (instances, lines) = match &self.diff {
+            Some(diff) => (diff.overlay_instances(), diff.overlay_lines()),
+            None => (Vec::new(), Vec::new()),
+        };
+
+        if instances.len() > self.overlay_instance_capacity {
+            self.overlay_instance_capacity = instances.len() * 2;
+            self.overlay_instance_buffer = grow_buffer(
+                &self.device,
+                "Overlay Instance Buffer",
+                self.overlay_instance_capacity,
+                std::mem::size_of::<InstanceRaw>(),
+            );
+        }
+        if lines.len() > self.overlay_line_capacity {
+            self.overlay_line_capacity = lines.len() * 2;
+            self.overlay_line_buffer = grow_buffer(
+                &self.device,
+                "Overlay Line Buffer",
+                self.overlay_line_capacity,
+                std::mem::size_of::<Vertex>(),
+            );
+        }
+
+        if !instances.is_empty() {
+            self.queue.write_buffer(
+                &self.overlay_instance_buffer,
+                0,
+                bytemuck::cast_slice(&instances),
+            );
+        }
+        if !lines.is_empty() {
+            self.queue
+                .write_buffer(&self.overlay_line_buffer, 0, bytemuck::cast_slice(&lines));
+        }
+        self.overlay_instance_count = instances.len() as u32;
+        self.overlay_line_count = lines.len() as u32;
+    }
+}
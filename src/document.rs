@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::types::*;
+
+// 카드 종류 (JSON Canvas 노드 타입과 대응)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CardKind {
+    #[default]
+    Text,
+    File,
+    Link,
+    Group,
+}
+
+// GPU로 올라가지 않는 카드 부가 정보 (block_positions와 같은 인덱스)
+#[derive(Clone, Debug, Default)]
+pub struct CardMeta {
+    pub id: u64,
+    pub kind: CardKind,
+    pub text: String,
+    // 그룹 이름 (CSV/Mermaid 등 그룹 카드가 없는 포맷의 분류)
+    pub group: String,
+    // 이 카드를 감싸는 그룹 카드의 ID
+    pub parent: Option<u64>,
+}
+
+// 카드 간 연결 (block_positions 인덱스 기준)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Clone, Default)]
+pub struct Document {
+    pub block_positions: Vec<InstanceRaw>,
+    pub cards: Vec<CardMeta>,
+    pub edges: Vec<Edge>,
+}
+
+/// 새 카드 ID. 순번이면 브랜치마다 따로 만든 카드끼리 겹치므로 무작위 값을 씀
+pub fn random_id() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    RandomState::new().hash_one(COUNTER.fetch_add(1, Ordering::Relaxed))
+}
+
+impl Document {
+    /// 기본 그리드 + 순서대로 이어진 연결선
+    pub fn default_grid() -> Self {
+        let mut doc = Self::default();
+        for instance in create_default_grid() {
+            let idx = doc.push_card(instance.position, instance.color, CardKind::Text, "");
+            if idx > 0 {
+                doc.connect(idx - 1, idx);
+            }
+        }
+        doc
+    }
+
+    pub fn len(&self) -> usize {
+        self.block_positions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.block_positions.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.block_positions.clear();
+        self.cards.clear();
+        self.edges.clear();
+    }
+
+    pub fn push_card(
+        &mut self,
+        position: [f32; 2],
+        color: [f32; 3],
+        kind: CardKind,
+        text: &str,
+    ) -> usize {
+        let id = random_id();
+        self.block_positions.push(InstanceRaw { position, color });
+        self.cards.push(CardMeta {
+            id,
+            kind,
+            text: text.to_string(),
+            group: String::new(),
+            parent: None,
+        });
+        self.block_positions.len() - 1
+    }
+
+    pub fn connect(&mut self, from: usize, to: usize) {
+        if from < self.len() && to < self.len() && from != to {
+            self.edges.push(Edge { from, to });
+        }
+    }
+
+    /// 다른 문서를 뒤에 이어 붙임 (이미 있는 ID나 먼저 붙인 카드와 겹치는 카드만 새 ID로,
+    /// 그룹 참조도 함께). 추가된 카드의 인덱스 범위 반환
+    pub fn append(&mut self, other: Document) -> std::ops::Range<usize> {
+        let base = self.len();
+        let mut used: HashSet<u64> = self.cards.iter().map(|c| c.id).collect();
+        // 카드마다 붙일 ID, 그리고 그룹 참조용으로 원래 ID를 처음 쓴 카드의 ID
+        let mut ids = Vec::with_capacity(other.len());
+        let mut first_ids = HashMap::new();
+        for meta in &other.cards {
+            let id = if used.insert(meta.id) {
+                meta.id
+            } else {
+                let id = std::iter::repeat_with(random_id)
+                    .find(|id| !used.contains(id))
+                    .unwrap_or_default();
+                used.insert(id);
+                id
+            };
+            first_ids.entry(meta.id).or_insert(id);
+            ids.push(id);
+        }
+        let cards = other.block_positions.into_iter().zip(other.cards);
+        for ((instance, mut meta), id) in cards.zip(ids) {
+            meta.id = id;
+            // 함께 붙이는 그룹만 새 ID로 바꾸고, 기존 보드의 그룹 참조는 그대로
+            meta.parent = meta.parent.map(|p| first_ids.get(&p).copied().unwrap_or(p));
+            self.block_positions.push(instance);
+            self.cards.push(meta);
+        }
+        self.edges.extend(other.edges.into_iter().map(|e| Edge {
+            from: e.from + base,
+            to: e.to + base,
+        }));
+        base..self.len()
+    }
+
+    /// 기존 인덱스를 order 순서대로 재배치. order에 없는 카드와 그 카드의 연결은 삭제
+    fn reorder(&mut self, order: &[usize]) {
+        let mut new_index = vec![None; self.len()];
+        for (new, &old) in order.iter().enumerate() {
+            new_index[old] = Some(new);
+        }
+        self.block_positions = order.iter().map(|&i| self.block_positions[i]).collect();
+        self.cards = order.iter().map(|&i| self.cards[i].clone()).collect();
+        self.edges = self
+            .edges
+            .iter()
+            .filter_map(|e| {
+                Some(Edge {
+                    from: new_index[e.from]?,
+                    to: new_index[e.to]?,
+                })
+            })
+            .collect();
+    }
+
+    /// 카드와 연결된 엣지를 삭제. 범위를 벗어나는 인덱스는 무시
+    pub fn remove_cards(&mut self, indices: &[usize]) {
+        let mut removed = vec![false; self.len()];
+        for &i in indices {
+            if let Some(flag) = removed.get_mut(i) {
+                *flag = true;
+            }
+        }
+        let order: Vec<usize> = (0..self.len()).filter(|&i| !removed[i]).collect();
+        self.reorder(&order);
+    }
+
+    /// 카드를 맨 앞(마지막 그리기 순서)으로. 옮긴 카드끼리의 순서는 유지
+    pub fn raise(&mut self, indices: &[usize]) {
+        let mut raised = vec![false; self.len()];
+        for &i in indices {
+            if let Some(flag) = raised.get_mut(i) {
+                *flag = true;
+            }
+        }
+        let order: Vec<usize> = (0..self.len())
+            .filter(|&i| !raised[i])
+            .chain((0..self.len()).filter(|&i| raised[i]))
+            .collect();
+        self.reorder(&order);
+    }
+
+    /// 카드와 그 사이의 연결만 떼어 낸 문서 (복제용)
+    pub fn extract(&self, indices: &[usize]) -> Document {
+        let indices: Vec<usize> = indices.iter().copied().filter(|&i| i < self.len()).collect();
+        let mut out = Document::default();
+        // 원래 인덱스 → 떼어 낸 문서의 인덱스 (같은 카드가 여러 번 오면 처음 자리)
+        let mut new_index = vec![None; self.len()];
+        for (n, &i) in indices.iter().enumerate() {
+            out.block_positions.push(self.block_positions[i]);
+            out.cards.push(self.cards[i].clone());
+            new_index[i].get_or_insert(n);
+        }
+        for edge in &self.edges {
+            let from = new_index.get(edge.from).copied().flatten();
+            let to = new_index.get(edge.to).copied().flatten();
+            if let (Some(from), Some(to)) = (from, to) {
+                out.edges.push(Edge { from, to });
+            }
+        }
+        out
+    }
+
+    /// 카드 중심들의 경계 상자 (min, max)
+    pub fn bounds(&self) -> Option<([f32; 2], [f32; 2])> {
+        let first = self.block_positions.first()?.position;
+        let mut min = first;
+        let mut max = first;
+        for pos in &self.block_positions {
+            min[0] = min[0].min(pos.position[0]);
+            min[1] = min[1].min(pos.position[1]);
+            max[0] = max[0].max(pos.position[0]);
+            max[1] = max[1].max(pos.position[1]);
+        }
+        Some((min, max))
+    }
+
+    /// 모든 카드를 평행이동
+    pub fn translate(&mut self, offset: [f32; 2]) {
+        for pos in &mut self.block_positions {
+            pos.position[0] += offset[0];
+            pos.position[1] += offset[1];
+        }
+    }
+
+    /// 경계 상자 중심을 원점으로 이동
+    pub fn center_at_origin(&mut self) {
+        if let Some((min, max)) = self.bounds() {
+            self.translate([-(min[0] + max[0]) * 0.5, -(min[1] + max[1]) * 0.5]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(ids: &[u64]) -> Document {
+        let mut doc = Document::default();
+        for &id in ids {
+            let idx = doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, "");
+            doc.cards[idx].id = id;
+        }
+        doc
+    }
+
+    #[test]
+    fn new_ids_are_not_sequential() {
+        let mut doc = Document::default();
+        let a = doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, "");
+        let b = doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, "");
+        assert_ne!(doc.cards[a].id, doc.cards[b].id);
+        assert_ne!(doc.cards[a].id.wrapping_add(1), doc.cards[b].id);
+    }
+
+    #[test]
+    fn append_keeps_ids_into_an_empty_board() {
+        let mut doc = Document::default();
+        doc.append(board(&[7, 3, 42]));
+        let ids: Vec<u64> = doc.cards.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [7, 3, 42]);
+    }
+
+    #[test]
+    fn append_renumbers_only_colliding_ids() {
+        let mut doc = board(&[1, 2]);
+        let mut other = board(&[2, 5, 9]);
+        // 겹치는 2번이 그룹이면 그 구성원의 참조도 따라가야 함
+        other.cards[0].kind = CardKind::Group;
+        other.cards[1].parent = Some(2);
+        // 기존 보드의 그룹을 가리키는 참조는 그대로
+        other.cards[2].parent = Some(1);
+        let range = doc.append(other);
+
+        let renamed = doc.cards[range.start].id;
+        assert!(renamed != 1 && renamed != 2);
+        assert_eq!(doc.cards[range.start + 1].id, 5);
+        assert_eq!(doc.cards[range.start + 1].parent, Some(renamed));
+        assert_eq!(doc.cards[range.start + 2].id, 9);
+        assert_eq!(doc.cards[range.start + 2].parent, Some(1));
+    }
+
+    #[test]
+    fn append_gives_each_duplicate_id_its_own_new_id() {
+        let mut doc = board(&[1]);
+        doc.append(board(&[4, 4, 1, 1]));
+        let ids: Vec<u64> = doc.cards.iter().map(|c| c.id).collect();
+        assert_eq!(ids[..2], [1, 4]);
+        let unique: HashSet<u64> = ids.iter().copied().collect();
+        assert_eq!(unique.len(), ids.len(), "{:?}", ids);
+    }
+
+    #[test]
+    fn extract_keeps_edges_between_selected_cards() {
+        let mut doc = board(&[10, 11, 12, 13]);
+        doc.edges = vec![
+            Edge { from: 0, to: 2 },
+            Edge { from: 2, to: 3 },
+            Edge { from: 1, to: 3 },
+            Edge { from: 3, to: 0 },
+        ];
+        // 범위 밖 인덱스는 무시하고 선택 순서대로 옮김
+        let out = doc.extract(&[3, 0, 9, 2]);
+        let ids: Vec<u64> = out.cards.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [13, 10, 12]);
+        assert_eq!(
+            out.edges,
+            [
+                Edge { from: 1, to: 2 },
+                Edge { from: 2, to: 0 },
+                Edge { from: 0, to: 1 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, TryRecvError};
+use std::sync::Arc;
+
+use crate::document::Document;
+use crate::formats::{self, ExportOptions, Format, FormatError};
+use crate::journal::Operation;
+use crate::session_layout;
+use crate::state::AppState;
+use crate::tabs::BoardSession;
+use crate::transcript::SAMPLE_TRANSCRIPT;
+use crate::ui::file_dialog::{FileDialogMode, FileDialogRequest};
+use crate::ui::project_browser::ProjectBrowserAction;
+use crate::workspace;
+
+impl AppState {
+    pub fn apply_file_request(&mut self, request: &FileDialogRequest) {
+        if request.benchmark {
+            self.start_benchmark();
+            return;
+        }
+
+        let path = Path::new(&request.path);
+        let result = match request.mode {
+            FileDialogMode::Import => self.import_document(path, request.replace),
+            FileDialogMode::Export => {
+                let options = ExportOptions {
+                    title: self.active_tab_title(),
+                    transcript: request.include_transcript.then_some(&SAMPLE_TRANSCRIPT[..]),
+                };
+                let result = formats::export_file(&self.document, path, &options);
+                // 보드를 그대로 되살릴 수 있는 포맷으로 내보냈을 때만 저장한 것으로 봄
+                if result.is_ok() && Format::from_path(path).is_some_and(Format::is_lossless) {
+                    self.unsaved_changes = false;
+                }
+                result
+            }
+        };
+
+        match result {
+            Ok(()) => self.file_dialog_state.mode = None,
+            Err(err) => self.file_dialog_state.error = Some(err.to_string()),
+        }
+        self.window.request_redraw();
+    }
+
+    /// 큰 보드에서도 화면이 멈추지 않도록 문서 사본으로 작업 스레드에서 측정
+    fn start_benchmark(&mut self) {
+        if self.benchmark_job.is_some() {
+            return;
+        }
+        let doc = self.document.clone();
+        let window = Arc::clone(&self.window);
+        let (sender, receiver) = mpsc::channel();
+        let spawned = std::thread::Builder::new()
+            .name("benchmark".into())
+            .spawn(move || {
+                let report = match formats::binary::benchmark(&doc) {
+                    Ok(report) => report.to_string(),
+                    Err(err) => err.to_string(),
+                };
+                let _ = sender.send(report);
+                window.request_redraw();
+            });
+        self.file_dialog_state.benchmark = Some(match spawned {
+            Ok(_) => {
+                self.benchmark_job = Some(receiver);
+                "측정 중…".to_string()
+            }
+            Err(err) => format!("측정을 시작하지 못했습니다: {}", err),
+        });
+        self.window.request_redraw();
+    }
+
+    /// 측정이 끝났으면 결과를 대화상자에 반영 (매 프레임 호출)
+    pub fn poll_benchmark(&mut self) {
+        let Some(receiver) = &self.benchmark_job else {
+            return;
+        };
+        match receiver.try_recv() {
+            Ok(report) => {
+                self.file_dialog_state.benchmark = Some(report);
+                self.benchmark_job = None;
+            }
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => {
+                self.file_dialog_state.benchmark = Some("측정이 중단되었습니다".to_string());
+                self.benchmark_job = None;
+            }
+        }
+    }
+
+    fn active_tab_title(&self) -> &str {
+        self.top_bar_state
+            .tabs
+            .get(self.top_bar_state.active_tab)
+            .map_or("보드", String::as_str)
+    }
+
+    pub fn apply_project_action(&mut self, action: &ProjectBrowserAction) {
+        let dir = PathBuf::from(self.project_browser_state.workspace.trim());
+        let result = if action.save_current || action.save_as_new || action.overwrite.is_some() {
+            let path = match &action.overwrite {
+                Some(path) => path.clone(),
+                None => {
+                    let name =
+                        workspace::file_name_for(self.active_tab_title(), Format::JsonCanvas);
+                    let path = dir.join(name);
+                    if action.save_as_new {
+                        workspace::unique_path(&path)
+                    } else if path.exists() {
+                        // 같은 이름의 다른 보드를 말없이 덮어쓰지 않도록 먼저 확인
+                        self.project_browser_state.confirm_overwrite = Some(path);
+                        self.window.request_redraw();
+                        return;
+                    } else {
+                        path
+                    }
+                }
+            };
+            self.project_browser_state.confirm_overwrite = None;
+            self.save_to_workspace(&dir, &path)
+        } else if let Some(path) = &action.open {
+            self.open_project(path)
+        } else if let Some(path) = &action.duplicate {
+            workspace::duplicate(path).map(|_| ()).map_err(FormatError::from)
+        } else if let Some(path) = &action.delete {
+            // 지운 파일에서 연 보드는 파일 없이 남음 (활성 탭과 다른 탭 모두)
+            let active = (&mut self.board_source, &mut self.unsaved_changes);
+            let parked = self
+                .boards
+                .iter_mut()
+                .map(|board| (&mut board.source, &mut board.unsaved_changes));
+            workspace::delete(path, std::iter::once(active).chain(parked))
+                .map_err(FormatError::from)
+        } else if action.refresh {
+            Ok(())
+        } else {
+            return;
+        };
+
+        let browser = &mut self.project_browser_state;
+        browser.error = result.err().map(|err| err.to_string());
+        // 파일을 건드린 뒤에는 목록을 다시 읽음
+        let previous = std::mem::take(&mut browser.entries);
+        let pending = match workspace::scan(&dir, previous) {
+            Ok(listing) => {
+                if !listing.skipped.is_empty() && browser.error.is_none() {
+                    browser.error = Some(format!(
+                        "항목 {}개를 읽지 못해 건너뛰었습니다: {}",
+                        listing.skipped.len(),
+                        listing.skipped.join(", ")
+                    ));
+                }
+                let pending = listing.pending();
+                browser.entries = listing.entries;
+                pending
+            }
+            Err(err) => {
+                browser.error = Some(format!("작업 공간을 읽지 못했습니다: {}", err));
+                Vec::new()
+            }
+        };
+        browser.scanned = true;
+        self.start_preview_job(pending);
+        self.window.request_redraw();
+    }
+
+    fn save_to_workspace(&mut self, dir: &Path, path: &Path) -> Result<(), FormatError> {
+        let options = ExportOptions {
+            title: self.active_tab_title(),
+            transcript: None,
+        };
+        std::fs::create_dir_all(dir)?;
+        formats::export_file(&self.document, path, &options)?;
+        self.unsaved_changes = false;
+        self.board_source = Some(path.to_path_buf());
+        Ok(())
+    }
+
+    /// 목록에 새로 나온 파일의 썸네일을 작업 스레드에서 읽음 (이전 작업은 버림)
+    fn start_preview_job(&mut self, pending: Vec<PathBuf>) {
+        self.preview_job = None;
+        if pending.is_empty() {
+            return;
+        }
+        let window = Arc::clone(&self.window);
+        match workspace::load_previews(pending, move || window.request_redraw()) {
+            Ok(receiver) => self.preview_job = Some(receiver),
+            Err(err) => {
+                self.project_browser_state.error =
+                    Some(format!("썸네일을 읽기 시작하지 못했습니다: {}", err));
+            }
+        }
+    }
+
+    /// 읽은 썸네일을 목록에 반영 (매 프레임 호출)
+    pub fn poll_previews(&mut self) {
+        let Some(receiver) = &self.preview_job else {
+            return;
+        };
+        loop {
+            match receiver.try_recv() {
+                Ok((path, preview)) => {
+                    let entries = &mut self.project_browser_state.entries;
+                    if let Some(entry) = entries.iter_mut().find(|e| e.path == path) {
+                        entry.preview = preview;
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.preview_job = None;
+                    break;
+                }
+            }
+        }
+    }
+
+    /// 작업 공간의 보드를 새 탭으로 열고 지난번 카메라(없으면 카드 중심)로 이동
+    fn open_project(&mut self, path: &Path) -> Result<(), FormatError> {
+        let document = formats::import_file(path)?;
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let mut session = BoardSession::with_document(document);
+        session.source = Some(path.to_path_buf());
+        let key = session_layout::camera_key(session.id, Some(path));
+        if let Some(camera) = self.session_layout.camera_for(&key) {
+            session.camera = camera;
+        } else if let Some((min, max)) = session.document.bounds() {
+            session.camera.position = [(min[0] + max[0]) * 0.5, (min[1] + max[1]) * 0.5];
+        }
+        self.open_tab(name, session);
+        Ok(())
+    }
+
+    /// 파일을 읽어 현재 보드에 추가(또는 대체)하고 가져온 카드를 선택
+    pub fn import_document(&mut self, path: &Path, replace: bool) -> Result<(), FormatError> {
+        let mut imported = formats::import_file(path)?;
+        if Format::from_path(path).is_some_and(Format::is_auto_layout) {
+            imported.translate(self.camera.position);
+        }
+        if replace {
+            self.document.clear();
+            self.record_operation(Operation::Clear);
+        }
+        let edge_start = self.document.edges.len();
+        // 빈 문서에 붙이면 파일의 카드 ID가 그대로 남고, 추가할 때는 겹치는 ID만 새로 발급
+        let range = self.document.append(imported);
+        self.record_operation(Operation::appended(&self.document, range.start, edge_start));
+        self.selected_indices = range.collect();
+        self.mark_positions_dirty();
+        Ok(())
+    }
+
+    /// 붙여넣은 목록 텍스트를 카메라 위치 중심의 마인드맵으로 추가
+    pub fn paste_outline(&mut self, text: &str) {
+        self.paste_outline_at(text, self.camera.position);
+    }
+
+    /// 붙여넣은 개요를 position(월드 좌표)을 중심으로 배치
+    pub fn paste_outline_at(&mut self, text: &str, position: [f32; 2]) {
+        // 개요가 아닌 텍스트는 무시
+        if let Some(parsed) = formats::outline::parse_pasted(text) {
+            self.add_pasted_outline(parsed, position);
+        }
+    }
+
+    /// 이미 해석한 개요를 position 중심으로 추가. 해석 오류는 알림으로 표시
+    pub fn add_pasted_outline(
+        &mut self,
+        parsed: Result<Document, FormatError>,
+        position: [f32; 2],
+    ) {
+        let mut imported = match parsed {
+            Ok(imported) => imported,
+            Err(err) => {
+                self.paste_error = Some(err.to_string());
+                self.window.request_redraw();
+                return;
+            }
+        };
+        imported.translate(position);
+        let edge_start = self.document.edges.len();
+        let range = self.document.append(imported);
+        self.record_operation(Operation::appended(&self.document, range.start, edge_start));
+        self.selected_indices = range.collect();
+        self.mark_positions_dirty();
+        self.window.request_redraw();
+    }
+}
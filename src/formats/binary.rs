@@ -0,0 +1,431 @@
+// 대용량 보드용 바이너리 컨테이너 (.wpb)
+//
+// [헤더 24B] [InstanceRaw × 노드 수] [CardRecord × 노드 수] [[u32; 2] × 엣지 수] [문자열 테이블]
+// 모든 구간은 4바이트 정렬이고 리틀 엔디언. 노드 배열은 InstanceRaw 그대로라
+// 읽은 바이트를 bytemuck으로 캐스팅해 GPU 버퍼에 바로 올릴 수 있다.
+use std::fmt;
+use std::io::{Read, Write};
+use std::time::{Duration, Instant};
+
+use bytemuck::{Pod, Zeroable};
+
+use super::*;
+use crate::document::{CardKind, CardMeta, Document, Edge};
+use crate::types::InstanceRaw;
+
+const MAGIC: [u8; 4] = *b"WPTB";
+const VERSION: u32 = 1;
+// 손상된 헤더로 거대한 할당을 하지 않도록 나눠 읽는 단위 (요소 수)
+const READ_CHUNK: usize = 64 * 1024;
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct Header {
+    magic: [u8; 4],
+    version: u32,
+    node_count: u32,
+    edge_count: u32,
+    string_bytes: u32,
+    reserved: u32,
+}
+
+// 카드 부가 정보. 문자열은 테이블의 바이트 범위로 참조
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct CardRecord {
+    id: u64,
+    kind: u32,
+    text_start: u32,
+    text_len: u32,
+    group_start: u32,
+    group_len: u32,
+    // parent가 유효하면 1
+    has_parent: u32,
+    parent: u64,
+}
+
+fn kind_to_u32(kind: CardKind) -> u32 {
+    match kind {
+        CardKind::Text => 0,
+        CardKind::File => 1,
+        CardKind::Link => 2,
+        CardKind::Group => 3,
+    }
+}
+
+fn kind_from_u32(value: u32) -> Option<CardKind> {
+    match value {
+        0 => Some(CardKind::Text),
+        1 => Some(CardKind::File),
+        2 => Some(CardKind::Link),
+        3 => Some(CardKind::Group),
+        _ => None,
+    }
+}
+
+fn to_u32(value: usize, what: &str) -> Result<u32, FormatError> {
+    u32::try_from(value).map_err(|_| FormatError::Unsupported(format!("{} 40억 개 초과", what)))
+}
+
+fn check_endian() -> Result<(), FormatError> {
+    if cfg!(target_endian = "big") {
+        return Err(FormatError::Unsupported(
+            "빅 엔디언 환경의 바이너리 보드".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Pod 배열을 청크 단위로 읽음. 파일이 헤더보다 짧으면 UnexpectedEof
+fn read_pod_vec<T: Pod, R: Read>(reader: &mut R, count: usize) -> Result<Vec<T>, FormatError> {
+    let mut out: Vec<T> = Vec::with_capacity(count.min(READ_CHUNK));
+    while out.len() < count {
+        let start = out.len();
+        let end = count.min(start + READ_CHUNK);
+        out.resize(end, T::zeroed());
+        reader.read_exact(bytemuck::cast_slice_mut(&mut out[start..end]))?;
+    }
+    Ok(out)
+}
+
+pub fn write_to<W: Write>(doc: &Document, mut writer: W) -> Result<(), FormatError> {
+    check_endian()?;
+    let mut strings = String::new();
+    let mut push_str = |s: &str| -> Result<(u32, u32), FormatError> {
+        let start = to_u32(strings.len(), "문자열 바이트")?;
+        strings.push_str(s);
+        Ok((start, to_u32(s.len(), "문자열 바이트")?))
+    };
+
+    let mut records = Vec::with_capacity(doc.cards.len());
+    for meta in &doc.cards {
+        let (text_start, text_len) = push_str(&meta.text)?;
+        let (group_start, group_len) = push_str(&meta.group)?;
+        records.push(CardRecord {
+            id: meta.id,
+            kind: kind_to_u32(meta.kind),
+            text_start,
+            text_len,
+            group_start,
+            group_len,
+            has_parent: meta.parent.is_some() as u32,
+            parent: meta.parent.unwrap_or(0),
+        });
+    }
+    let edges = doc
+        .edges
+        .iter()
+        .map(|e| Ok([to_u32(e.from, "엣지 인덱스")?, to_u32(e.to, "엣지 인덱스")?]))
+        .collect::<Result<Vec<[u32; 2]>, FormatError>>()?;
+
+    // 문자열 테이블 끝을 4바이트 경계로 채움
+    let padding = (4 - strings.len() % 4) % 4;
+    let header = Header {
+        magic: MAGIC,
+        version: VERSION,
+        node_count: to_u32(doc.len(), "노드")?,
+        edge_count: to_u32(edges.len(), "엣지")?,
+        string_bytes: to_u32(strings.len(), "문자열 바이트")?,
+        reserved: 0,
+    };
+
+    writer.write_all(bytemuck::bytes_of(&header))?;
+    writer.write_all(bytemuck::cast_slice(&doc.block_positions))?;
+    writer.write_all(bytemuck::cast_slice(&records))?;
+    writer.write_all(bytemuck::cast_slice(&edges))?;
+    writer.write_all(strings.as_bytes())?;
+    writer.write_all(&[0; 3][..padding])?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// 헤더부터 순서대로 스트리밍 읽기 (파일 전체를 메모리에 올리지 않음)
+pub fn read_from<R: Read>(mut reader: R) -> Result<Document, FormatError> {
+    check_endian()?;
+    let mut header = Header::zeroed();
+    reader.read_exact(bytemuck::bytes_of_mut(&mut header))?;
+    if header.magic != MAGIC {
+        return Err(FormatError::Parse("바이너리 보드 파일이 아닙니다".into()));
+    }
+    if header.version != VERSION {
+        return Err(FormatError::Unsupported(format!(
+            "바이너리 보드 버전 {}",
+            header.version
+        )));
+    }
+
+    let node_count = header.node_count as usize;
+    let block_positions: Vec<InstanceRaw> = read_pod_vec(&mut reader, node_count)?;
+    let records: Vec<CardRecord> = read_pod_vec(&mut reader, node_count)?;
+    let raw_edges: Vec<[u32; 2]> = read_pod_vec(&mut reader, header.edge_count as usize)?;
+    let string_bytes: Vec<u8> = read_pod_vec(&mut reader, header.string_bytes as usize)?;
+    // 정렬 패딩까지 소비해 뒤따르는 데이터(저널 레코드 등)를 이어 읽을 수 있게 함
+    let padding = (4 - string_bytes.len() % 4) % 4;
+    reader.read_exact(&mut [0; 3][..padding])?;
+    let strings = String::from_utf8(string_bytes)
+        .map_err(|_| FormatError::Parse("문자열 테이블이 UTF-8이 아닙니다".into()))?;
+
+    let slice = |start: u32, len: u32| {
+        let start = start as usize;
+        strings
+            .get(start..start + len as usize)
+            .ok_or_else(|| FormatError::Parse("문자열 범위가 테이블을 벗어납니다".into()))
+    };
+    let mut cards = Vec::with_capacity(node_count);
+    for record in &records {
+        let kind = kind_from_u32(record.kind)
+            .ok_or_else(|| FormatError::Parse(format!("알 수 없는 카드 종류 {}", record.kind)))?;
+        cards.push(CardMeta {
+            id: record.id,
+            kind,
+            text: slice(record.text_start, record.text_len)?.to_string(),
+            group: slice(record.group_start, record.group_len)?.to_string(),
+            parent: (record.has_parent != 0).then_some(record.parent),
+        });
+    }
+
+    let mut edges = Vec::with_capacity(raw_edges.len());
+    for [from, to] in raw_edges {
+        let (from, to) = (from as usize, to as usize);
+        if from >= node_count || to >= node_count {
+            return Err(FormatError::Parse(format!(
+                "엣지 {} → {}가 노드 범위를 벗어납니다",
+                from, to
+            )));
+        }
+        edges.push(Edge { from, to });
+    }
+
+    Ok(Document {
+        block_positions,
+        cards,
+        edges,
+    })
+}
+
+/// 같은 문서를 JSON Canvas와 바이너리로 메모리에서 왕복시킨 시간 비교
+pub struct BenchmarkReport {
+    pub nodes: usize,
+    pub edges: usize,
+    pub text_bytes: usize,
+    pub binary_bytes: usize,
+    pub text_write: Duration,
+    pub text_read: Duration,
+    pub binary_write: Duration,
+    pub binary_read: Duration,
+}
+
+pub fn benchmark(doc: &Document) -> Result<BenchmarkReport, FormatError> {
+    let start = Instant::now();
+    let text = json_canvas::to_string(doc)?;
+    let text_write = start.elapsed();
+
+    let start = Instant::now();
+    json_canvas::parse(&text)?;
+    let text_read = start.elapsed();
+
+    let start = Instant::now();
+    let mut bytes = Vec::new();
+    write_to(doc, &mut bytes)?;
+    let binary_write = start.elapsed();
+
+    let start = Instant::now();
+    read_from(bytes.as_slice())?;
+    let binary_read = start.elapsed();
+
+    Ok(BenchmarkReport {
+        nodes: doc.len(),
+        edges: doc.edges.len(),
+        text_bytes: text.len(),
+        binary_bytes: bytes.len(),
+        text_write,
+        text_read,
+        binary_write,
+        binary_read,
+    })
+}
+
+impl fmt::Display for BenchmarkReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        let kb = |b: usize| b as f64 / 1024.0;
+        writeln!(f, "노드 {}개, 엣지 {}개", self.nodes, self.edges)?;
+        writeln!(
+            f,
+            "JSON Canvas: 쓰기 {:.1}ms, 읽기 {:.1}ms, {:.0}KB",
+            ms(self.text_write),
+            ms(self.text_read),
+            kb(self.text_bytes)
+        )?;
+        write!(
+            f,
+            "바이너리: 쓰기 {:.1}ms, 읽기 {:.1}ms, {:.0}KB",
+            ms(self.binary_write),
+            ms(self.binary_read),
+            kb(self.binary_bytes)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use super::*;
+
+    // 카드 ID/종류/글/그룹/그룹 카드, 위치, 색
+    type CardState = (
+        u64,
+        CardKind,
+        String,
+        String,
+        Option<u64>,
+        [f32; 2],
+        [f32; 3],
+    );
+
+    fn cards(doc: &Document) -> Vec<CardState> {
+        doc.cards
+            .iter()
+            .zip(&doc.block_positions)
+            .map(|(meta, inst)| {
+                let (text, group) = (meta.text.clone(), meta.group.clone());
+                (
+                    meta.id,
+                    meta.kind,
+                    text,
+                    group,
+                    meta.parent,
+                    inst.position,
+                    inst.color,
+                )
+            })
+            .collect()
+    }
+
+    /// 모든 카드 종류, 그룹/그룹 카드, 엣지를 담은 보드
+    fn sample() -> Document {
+        let mut doc = Document::default();
+        let kinds = [
+            CardKind::Group,
+            CardKind::Text,
+            CardKind::File,
+            CardKind::Link,
+        ];
+        let texts = ["묶음", "첫 카드", "notes.md", "https://example.com"];
+        for (i, (kind, text)) in kinds.into_iter().zip(texts).enumerate() {
+            doc.push_card([i as f32, -0.5], [0.1 * i as f32, 0.5, 1.0], kind, text);
+        }
+        let group_id = doc.cards[0].id;
+        for meta in &mut doc.cards[1..3] {
+            meta.group = "기획".into();
+            meta.parent = Some(group_id);
+        }
+        doc.connect(1, 2);
+        doc.connect(3, 1);
+        doc
+    }
+
+    fn bytes_of(doc: &Document) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_to(doc, &mut bytes).expect("writes");
+        bytes
+    }
+
+    /// offset 위치의 값을 바꿈 (헤더/레코드를 일부러 망가뜨리는 용도)
+    fn patch<T: Pod>(bytes: &mut [u8], offset: usize, value: T) {
+        bytes[offset..offset + size_of::<T>()].copy_from_slice(bytemuck::bytes_of(&value));
+    }
+
+    #[test]
+    fn round_trip_keeps_every_field() {
+        let doc = sample();
+        let parsed = read_from(bytes_of(&doc).as_slice()).expect("reads");
+        assert_eq!(cards(&parsed), cards(&doc));
+        assert_eq!(parsed.edges, doc.edges);
+    }
+
+    #[test]
+    fn keeps_ids_across_the_whole_u64_range() {
+        let mut doc = Document::default();
+        let idx = doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, "끝");
+        doc.cards[idx].id = u64::MAX;
+        let mut bytes = Vec::new();
+        write_to(&doc, &mut bytes).expect("writes");
+        let parsed = read_from(bytes.as_slice()).expect("reads");
+        assert_eq!(parsed.cards[0].id, u64::MAX);
+    }
+
+    #[test]
+    fn truncated_input_is_unexpected_eof() {
+        let bytes = bytes_of(&sample());
+        for cut in 0..bytes.len() {
+            match read_from(&bytes[..cut]) {
+                Err(FormatError::Io(err)) => {
+                    assert_eq!(
+                        err.kind(),
+                        std::io::ErrorKind::UnexpectedEof,
+                        "{}바이트",
+                        cut
+                    )
+                }
+                other => panic!("{}바이트에서 잘림: {:?}", cut, other.err()),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_bad_magic_and_unknown_version() {
+        let mut bytes = bytes_of(&sample());
+        bytes[0] = b'X';
+        assert!(matches!(
+            read_from(bytes.as_slice()),
+            Err(FormatError::Parse(_))
+        ));
+
+        let mut bytes = bytes_of(&sample());
+        patch(&mut bytes, 4, VERSION + 1);
+        assert!(matches!(
+            read_from(bytes.as_slice()),
+            Err(FormatError::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_out_of_range_strings_and_edges() {
+        let doc = sample();
+        let records = size_of::<Header>() + doc.len() * size_of::<InstanceRaw>();
+        let edges = records + doc.len() * size_of::<CardRecord>();
+
+        // 첫 카드의 text_len이 문자열 테이블을 넘어감
+        let mut bytes = bytes_of(&doc);
+        patch(&mut bytes, records + 16, u32::MAX / 2);
+        assert!(matches!(
+            read_from(bytes.as_slice()),
+            Err(FormatError::Parse(_))
+        ));
+
+        // 첫 엣지의 출발 인덱스가 노드 수와 같음
+        let mut bytes = bytes_of(&doc);
+        patch(&mut bytes, edges, doc.len() as u32);
+        assert!(matches!(
+            read_from(bytes.as_slice()),
+            Err(FormatError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn padding_is_consumed_before_trailing_data() {
+        let mut doc = Document::default();
+        // 3바이트 문자열이라 1바이트 패딩이 붙음
+        doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, "가");
+        let mut bytes = bytes_of(&doc);
+        assert_eq!(bytes.len() % 4, 0);
+        bytes.extend_from_slice(b"TAIL");
+
+        let mut reader = bytes.as_slice();
+        let parsed = read_from(&mut reader).expect("reads");
+        assert_eq!(parsed.cards[0].text, "가");
+        assert_eq!(reader, b"TAIL");
+    }
+}
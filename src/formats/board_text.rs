@@ -0,0 +1,397 @@
+// git 친화 텍스트 포맷 (.board): 카드/엣지 한 줄씩, ID 순 정렬, 좌표 반올림
+//
+//   weaving-board 3
+//
+//   [cards]
+//   <id> <z> <x> <y> <#색상> <종류> <그룹 카드 id 또는 -> "<그룹>" "<텍스트>"
+//
+//   [edges]
+//   <출발 id> -> <도착 id>
+//
+// 카드 하나를 옮기면 한 줄만 바뀌고, 개수 같은 요약 값을 두지 않아
+// 서로 다른 브랜치에서 추가한 카드는 ID에 따라 서로 다른 줄에 들어간다.
+// 그리는 순서(뒤에 올수록 위)는 줄 순서 대신 z 칸에 두고, z가 같으면 ID 순으로 그린다.
+//
+// 이전 버전도 읽고, 저장하면 버전 3으로 바뀐다.
+//   버전 2: z 칸 없이 줄 순서가 그리는 순서
+//   버전 1: 버전 2에서 그룹 카드 id 칸도 없음
+use std::collections::HashMap;
+
+use super::*;
+use crate::document::{CardKind, Document};
+
+const HEADER: &str = "weaving-board 3";
+const HEADER_V2: &str = "weaving-board 2";
+const HEADER_V1: &str = "weaving-board 1";
+
+fn kind_name(kind: CardKind) -> &'static str {
+    match kind {
+        CardKind::Text => "text",
+        CardKind::File => "file",
+        CardKind::Link => "link",
+        CardKind::Group => "group",
+    }
+}
+
+fn parse_kind(name: &str) -> Option<CardKind> {
+    match name {
+        "text" => Some(CardKind::Text),
+        "file" => Some(CardKind::File),
+        "link" => Some(CardKind::Link),
+        "group" => Some(CardKind::Group),
+        _ => None,
+    }
+}
+
+fn quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            _ => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// 따옴표 문자열 하나를 읽고 나머지 입력 반환
+fn unquote(input: &str) -> Result<(String, &str), String> {
+    let rest = input
+        .trim_start()
+        .strip_prefix('"')
+        .ok_or("따옴표 문자열이 필요합니다")?;
+    let mut out = String::new();
+    let mut chars = rest.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((out, &rest[i + 1..])),
+            '\\' => match chars.next().map(|(_, c)| c) {
+                Some('n') => out.push('\n'),
+                Some('r') => out.push('\r'),
+                Some('t') => out.push('\t'),
+                Some(c @ ('"' | '\\')) => out.push(c),
+                other => return Err(format!("잘못된 이스케이프 '\\{}'", other.unwrap_or(' '))),
+            },
+            _ => out.push(c),
+        }
+    }
+    Err("닫히지 않은 따옴표".into())
+}
+
+// -0.000 대신 0.000으로 써서 의미 없는 diff를 막음
+fn coord(v: f32) -> String {
+    let s = format!("{:.3}", v);
+    if s == "-0.000" {
+        "0.000".into()
+    } else {
+        s
+    }
+}
+
+pub fn to_string(doc: &Document) -> String {
+    let mut order: Vec<usize> = (0..doc.len()).collect();
+    order.sort_by_key(|&i| doc.cards[i].id);
+
+    let mut out = format!("{}\n\n[cards]\n", HEADER);
+    for z in order {
+        let (instance, meta) = (&doc.block_positions[z], &doc.cards[z]);
+        out.push_str(&format!(
+            "{} {} {} {} {} {} {} {} {}\n",
+            meta.id,
+            z,
+            coord(instance.position[0]),
+            coord(instance.position[1]),
+            to_hex_color(instance.color),
+            kind_name(meta.kind),
+            meta.parent
+                .map_or_else(|| "-".to_string(), |p| p.to_string()),
+            quote(&meta.group),
+            quote(&meta.text),
+        ));
+    }
+
+    let mut edges: Vec<(u64, u64)> = doc
+        .edges
+        .iter()
+        .map(|e| (doc.cards[e.from].id, doc.cards[e.to].id))
+        .collect();
+    edges.sort_unstable();
+    out.push_str("\n[edges]\n");
+    for (from, to) in edges {
+        out.push_str(&format!("{} -> {}\n", from, to));
+    }
+    out
+}
+
+#[derive(PartialEq)]
+enum Section {
+    None,
+    Cards,
+    Edges,
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    let mut lines = text.lines().enumerate().map(|(i, l)| (i + 1, l.trim()));
+    let version = match lines
+        .by_ref()
+        .find(|(_, l)| !l.is_empty() && !l.starts_with('#'))
+    {
+        Some((_, HEADER)) => 3,
+        Some((_, HEADER_V2)) => 2,
+        Some((_, HEADER_V1)) => 1,
+        Some((_, other)) if other.starts_with("weaving-board ") => {
+            return Err(FormatError::Unsupported(format!(
+                "보드 텍스트 버전 '{}'",
+                other
+            )))
+        }
+        _ => return Err(FormatError::Parse(format!("'{}' 헤더가 없습니다", HEADER))),
+    };
+
+    // (z, 카드) — 버전 1/2는 줄 순서를 z로 씀
+    let mut cards: Vec<(usize, CardLine)> = Vec::new();
+    let mut card_lines = HashMap::new();
+    let mut edge_lines = Vec::new();
+    let mut errors = Vec::new();
+    let mut section = Section::None;
+
+    for (line_no, line) in lines {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line {
+            "[cards]" => section = Section::Cards,
+            "[edges]" => section = Section::Edges,
+            _ if section == Section::Cards => match parse_card(line, version) {
+                Ok((z, card)) => {
+                    if card_lines.insert(card.0, line_no).is_some() {
+                        errors.push(format!("{}행: 중복된 ID {}", line_no, card.0));
+                        continue;
+                    }
+                    cards.push((z.unwrap_or(cards.len()), card));
+                }
+                Err(err) => errors.push(format!("{}행: {}", line_no, err)),
+            },
+            // 엣지는 카드를 모두 읽은 뒤 연결 (섹션 순서와 무관하게)
+            _ if section == Section::Edges => edge_lines.push((line_no, line)),
+            _ => errors.push(format!("{}행: 섹션 밖의 내용", line_no)),
+        }
+    }
+
+    cards.sort_by_key(|(z, card)| (*z, card.0));
+    let mut doc = Document::default();
+    let mut index_by_id = HashMap::new();
+    for (_, (id, position, color, kind, parent, group, text)) in cards {
+        let idx = doc.push_card(position, color, kind, &text);
+        doc.cards[idx].id = id;
+        doc.cards[idx].parent = parent;
+        doc.cards[idx].group = group;
+        index_by_id.insert(id, idx);
+    }
+
+    for (line_no, line) in edge_lines {
+        let ends = line
+            .split_once("->")
+            .and_then(|(a, b)| Some((a.trim().parse().ok()?, b.trim().parse().ok()?)));
+        let Some((from, to)) = ends else {
+            errors.push(format!("{}행: '<id> -> <id>' 형식이 아닙니다", line_no));
+            continue;
+        };
+        match (index_by_id.get(&from), index_by_id.get(&to)) {
+            (Some(&from), Some(&to)) => doc.connect(from, to),
+            _ => errors.push(format!("{}행: 없는 카드를 잇는 엣지", line_no)),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(FormatError::Invalid(errors));
+    }
+    Ok(doc)
+}
+
+type CardLine = (
+    u64,
+    [f32; 2],
+    [f32; 3],
+    CardKind,
+    Option<u64>,
+    String,
+    String,
+);
+
+/// 카드 한 줄과 z (버전 3에만 있음)
+fn parse_card(line: &str, version: u32) -> Result<(Option<usize>, CardLine), String> {
+    let fields_before_group = match version {
+        1 => 5,
+        2 => 6,
+        _ => 7,
+    };
+    let mut fields = line.splitn(fields_before_group + 1, ' ');
+    let mut next = |name: &str| fields.next().ok_or(format!("{} 값이 없습니다", name));
+    let id = next("id")?;
+    let id = id.parse().map_err(|_| format!("잘못된 ID '{}'", id))?;
+    let z = if version >= 3 {
+        let z = next("z")?;
+        Some(z.parse().map_err(|_| format!("잘못된 z '{}'", z))?)
+    } else {
+        None
+    };
+    let mut number = |name: &str| -> Result<f32, String> {
+        let value = next(name)?;
+        value
+            .parse::<f32>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or(format!("{} 값 '{}'가 숫자가 아닙니다", name, value))
+    };
+    let position = [number("x")?, number("y")?];
+    let color = next("색상")?;
+    let color = parse_hex_color(color).ok_or(format!("잘못된 색상 '{}'", color))?;
+    let kind = next("종류")?;
+    let kind = parse_kind(kind).ok_or(format!("알 수 없는 카드 종류 '{}'", kind))?;
+    let parent = if version >= 2 {
+        match next("그룹 카드 id")? {
+            "-" => None,
+            id => Some(
+                id.parse()
+                    .map_err(|_| format!("잘못된 그룹 카드 ID '{}'", id))?,
+            ),
+        }
+    } else {
+        None
+    };
+    let (group, rest) = unquote(next("그룹")?)?;
+    let (text, rest) = unquote(rest)?;
+    if !rest.trim().is_empty() {
+        return Err("줄 끝에 남는 내용이 있습니다".into());
+    }
+    Ok((z, (id, position, color, kind, parent, group, text)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(cards: &[(u64, &str)]) -> Document {
+        let mut doc = Document::default();
+        for (i, &(id, text)) in cards.iter().enumerate() {
+            let idx = doc.push_card([i as f32, 0.0], [1.0; 3], CardKind::Text, text);
+            doc.cards[idx].id = id;
+        }
+        doc
+    }
+
+    fn texts(doc: &Document) -> Vec<&str> {
+        doc.cards.iter().map(|c| c.text.as_str()).collect()
+    }
+
+    #[test]
+    fn keeps_ids_across_the_whole_u64_range() {
+        let text = format!(
+            "{}\n\n[cards]\n0 0 1.000 0.000 #ffffff text - \"\" \"시작\"\n{} 1 0.000 0.000 #ffffff text - \"\" \"끝\"\n\n[edges]\n0 -> {}\n",
+            HEADER,
+            u64::MAX,
+            u64::MAX
+        );
+        let doc = parse(&text).expect("parses");
+        let ids: Vec<u64> = doc.cards.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [0, u64::MAX]);
+        assert_eq!(to_string(&doc), text);
+    }
+
+    #[test]
+    fn cards_are_written_by_id_and_drawn_by_z() {
+        let mut doc = fixtures::grouped_board();
+        // ID와 그리는 순서가 어긋나도 (맨 앞으로 가져온 카드 등) z로 순서를 보존
+        for (meta, id) in doc.cards.iter_mut().zip([50, 10, 40, 20, 30]) {
+            meta.id = id;
+        }
+        doc.cards[1].parent = Some(50);
+        doc.cards[2].parent = Some(50);
+        doc.cards[3].parent = Some(10);
+
+        let text = to_string(&doc);
+        let written: Vec<&str> = text
+            .lines()
+            .skip_while(|l| *l != "[cards]")
+            .skip(1)
+            .take_while(|l| !l.is_empty())
+            .map(|l| l.split(' ').next().unwrap_or(""))
+            .collect();
+        assert_eq!(written, ["10", "20", "30", "40", "50"]);
+
+        let back = parse(&text).expect("parses");
+        let ids: Vec<u64> = back.cards.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [50, 10, 40, 20, 30]);
+        assert_eq!(texts(&back), ["바깥", "안쪽", "A", "B", "C"]);
+        assert_eq!(
+            fixtures::group_summary(&back),
+            fixtures::group_summary(&doc)
+        );
+        let ends = |d: &Document| {
+            let mut ends: Vec<(usize, usize)> = d.edges.iter().map(|e| (e.from, e.to)).collect();
+            ends.sort_unstable();
+            ends
+        };
+        assert_eq!(ends(&back), ends(&doc));
+    }
+
+    #[test]
+    fn cards_added_on_two_branches_land_on_different_lines() {
+        let base = board(&[(10, "처음"), (30, "셋째")]);
+        let mut ours = base.clone();
+        ours.push_card([5.0, 0.0], [1.0; 3], CardKind::Text, "우리");
+        ours.cards[2].id = 20;
+        let mut theirs = base.clone();
+        theirs.push_card([6.0, 0.0], [1.0; 3], CardKind::Text, "그쪽");
+        theirs.cards[2].id = 40;
+
+        // 각 브랜치의 변경은 한 줄 추가뿐: (추가된 줄 위치, 줄)
+        let base = to_string(&base);
+        let base_lines: Vec<&str> = base.lines().collect();
+        let inserted = |doc: &Document| {
+            let text = to_string(doc);
+            let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+            let at = (0..lines.len())
+                .find(|&i| base_lines.get(i) != Some(&lines[i].as_str()))
+                .expect("adds a line");
+            let line = lines.remove(at);
+            assert_eq!(lines, base_lines);
+            (at, line)
+        };
+        let (ours_at, ours_line) = inserted(&ours);
+        let (theirs_at, theirs_line) = inserted(&theirs);
+        assert!(ours_at < theirs_at);
+
+        // 서로 다른 자리에 들어가므로 두 추가를 그대로 합칠 수 있음
+        let mut merged: Vec<&str> = base_lines.clone();
+        merged.insert(theirs_at, &theirs_line);
+        merged.insert(ours_at, &ours_line);
+        let merged = parse(&merged.join("\n")).expect("parses");
+        assert_eq!(texts(&merged), ["처음", "셋째", "우리", "그쪽"]);
+    }
+
+    #[test]
+    fn reads_older_versions_in_line_order() {
+        let v2 = "weaving-board 2\n\n[cards]\n30 0.000 0.000 #ffffff group - \"\" \"묶음\"\n10 1.000 0.000 #ffffff text 30 \"\" \"안\"\n\n[edges]\n30 -> 10\n";
+        let v1 = "weaving-board 1\n\n[cards]\n30 0.000 0.000 #ffffff group \"\" \"묶음\"\n10 1.000 0.000 #ffffff text \"\" \"안\"\n\n[edges]\n30 -> 10\n";
+        for (text, parent) in [(v2, Some(30)), (v1, None)] {
+            let doc = parse(text).expect("parses");
+            assert_eq!(texts(&doc), ["묶음", "안"]);
+            assert_eq!(doc.cards[1].parent, parent);
+            assert_eq!(doc.edges.len(), 1);
+            // 저장하면 현재 버전으로
+            assert!(to_string(&doc).starts_with(HEADER));
+        }
+        assert!(matches!(
+            parse("weaving-board 9\n"),
+            Err(FormatError::Unsupported(_))
+        ));
+    }
+}
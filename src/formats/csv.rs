@@ -0,0 +1,262 @@
+// CSV 노드 목록: 헤더 행 필수, 열 순서 자유 (title 필수, x/y/color/group 선택)
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::layout;
+use crate::types::{nearest_palette_index, CARD_COLORS};
+
+// 팔레트 이름 (CARD_COLORS 순서)
+const COLOR_NAMES: [&str; 6] = ["분홍", "보라", "노랑", "시안", "주황", "다크"];
+
+/// RFC 4180 형식 레코드 분리 (따옴표 안의 쉼표/줄바꿈/"" 지원). 각 레코드의 시작 행 번호 포함
+fn parse_records(text: &str) -> Result<Vec<(usize, Vec<String>)>, FormatError> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push('\n');
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => record.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                record.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut record)));
+                line += 1;
+                record_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(FormatError::Parse(format!(
+            "{}행: 닫히지 않은 따옴표",
+            record_line
+        )));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((record_line, record));
+    }
+
+    // 빈 줄 제거
+    records.retain(|(_, r)| !(r.len() == 1 && r[0].trim().is_empty()));
+    Ok(records)
+}
+
+fn parse_color(value: &str) -> Option<[f32; 3]> {
+    if let Ok(idx) = value.parse::<usize>() {
+        return CARD_COLORS.get(idx).copied();
+    }
+    if let Some(idx) = COLOR_NAMES.iter().position(|n| *n == value) {
+        return Some(CARD_COLORS[idx]);
+    }
+    parse_hex_color(value).map(|c| CARD_COLORS[nearest_palette_index(c)])
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    // 스프레드시트가 붙이는 UTF-8 BOM 제거
+    let text = text.trim_start_matches('\u{feff}');
+    let mut records = parse_records(text)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Err(FormatError::Parse("헤더 행이 없습니다".into()));
+    };
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    };
+    let title_col =
+        column("title").ok_or_else(|| FormatError::Parse("'title' 열이 필요합니다".into()))?;
+    let (x_col, y_col) = (column("x"), column("y"));
+    let (color_col, group_col) = (column("color"), column("group"));
+
+    let mut doc = Document::default();
+    let mut errors = Vec::new();
+    let mut unplaced = Vec::new();
+
+    for (line, record) in records {
+        let cell = |col: Option<usize>| {
+            col.and_then(|c| record.get(c))
+                .map(|v| v.trim())
+                .unwrap_or("")
+        };
+        let mut row_errors = Vec::new();
+
+        let title = cell(Some(title_col));
+        if title.is_empty() {
+            row_errors.push("title이 비어 있습니다".to_string());
+        }
+
+        let mut coord = |name: &str, value: &str| -> Option<f32> {
+            if value.is_empty() {
+                return None;
+            }
+            match value.parse::<f32>() {
+                Ok(v) if v.is_finite() => Some(v),
+                _ => {
+                    row_errors.push(format!("{} 값 '{}'가 숫자가 아닙니다", name, value));
+                    None
+                }
+            }
+        };
+        let (x_raw, y_raw) = (cell(x_col), cell(y_col));
+        let position = match (coord("x", x_raw), coord("y", y_raw)) {
+            (Some(x), Some(y)) => Some([x, y]),
+            _ => {
+                if x_raw.is_empty() != y_raw.is_empty() {
+                    row_errors.push("x와 y는 함께 지정해야 합니다".to_string());
+                }
+                None
+            }
+        };
+
+        let color_value = cell(color_col);
+        let color = if color_value.is_empty() {
+            CARD_COLORS[doc.len() % CARD_COLORS.len()]
+        } else {
+            parse_color(color_value).unwrap_or_else(|| {
+                row_errors.push(format!("알 수 없는 색상 '{}'", color_value));
+                CARD_COLORS[0]
+            })
+        };
+
+        if !row_errors.is_empty() {
+            errors.extend(row_errors.into_iter().map(|e| format!("{}행: {}", line, e)));
+            continue;
+        }
+
+        let idx = doc.push_card(position.unwrap_or([0.0, 0.0]), color, CardKind::Text, title);
+        doc.cards[idx].group = cell(group_col).to_string();
+        if position.is_none() {
+            unplaced.push(idx);
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(FormatError::Invalid(errors));
+    }
+    layout::fill_grid(&mut doc, &unplaced);
+    Ok(doc)
+}
+
+fn escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn to_string(doc: &Document) -> String {
+    let mut out = String::from("id,title,x,y,color,group\n");
+    for (instance, meta) in doc.block_positions.iter().zip(&doc.cards) {
+        let [x, y] = instance.position;
+        out.push_str(&format!(
+            "{},{},{:.3},{:.3},{},{}\n",
+            meta.id,
+            escape(&meta.text),
+            x,
+            y,
+            COLOR_NAMES[nearest_palette_index(instance.color)],
+            escape(&meta.group),
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(doc: &Document) -> Vec<&str> {
+        doc.cards.iter().map(|c| c.text.as_str()).collect()
+    }
+
+    fn errors(result: Result<Document, FormatError>) -> Vec<String> {
+        match result {
+            Err(FormatError::Invalid(errors)) => errors,
+            other => panic!("검증 오류가 아님: {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn quoted_fields_keep_commas_quotes_and_newlines() {
+        let text = "title,group\r\n\"가, 나\",\"say \"\"hi\"\"\"\n\"첫 줄\n둘째 줄\",\n마지막,\n";
+        let records = parse_records(text).expect("parses");
+        let lines: Vec<usize> = records.iter().map(|(line, _)| *line).collect();
+        // 따옴표 안의 줄바꿈 뒤 레코드는 실제 행 번호로
+        assert_eq!(lines, [1, 2, 3, 5]);
+        assert_eq!(records[1].1, ["가, 나", "say \"hi\""]);
+        assert_eq!(records[2].1, ["첫 줄\n둘째 줄", ""]);
+    }
+
+    #[test]
+    fn finds_header_columns_in_any_order_and_case() {
+        let doc =
+            parse("\u{feff}Group, Y ,TITLE,x\n기획,2,첫 카드,1\n\n,,둘째,\n").expect("parses");
+        assert_eq!(texts(&doc), ["첫 카드", "둘째"]);
+        assert_eq!(doc.block_positions[0].position, [1.0, 2.0]);
+        assert_eq!(doc.cards[0].group, "기획");
+        assert_eq!(doc.cards[1].group, "");
+
+        assert!(matches!(
+            parse("name,x,y\n가,0,0\n"),
+            Err(FormatError::Parse(_))
+        ));
+        assert!(matches!(parse(""), Err(FormatError::Parse(_))));
+    }
+
+    #[test]
+    fn round_trip_keeps_text_position_color_and_group() {
+        let mut doc = Document::default();
+        for (i, text) in ["쉼표, 있음", "따옴표 \"인용\"", "여러\n줄"]
+            .iter()
+            .enumerate()
+        {
+            let idx = doc.push_card([i as f32, -0.5], CARD_COLORS[i + 1], CardKind::Text, text);
+            doc.cards[idx].group = format!("묶음 {}", i);
+        }
+        let back = parse(&to_string(&doc)).expect("parses");
+        assert_eq!(texts(&back), texts(&doc));
+        for (a, b) in back.block_positions.iter().zip(&doc.block_positions) {
+            assert_eq!((a.position, a.color), (b.position, b.color));
+        }
+        let groups: Vec<&str> = back.cards.iter().map(|c| c.group.as_str()).collect();
+        assert_eq!(groups, ["묶음 0", "묶음 1", "묶음 2"]);
+    }
+
+    #[test]
+    fn malformed_rows_are_reported_by_line() {
+        assert!(matches!(
+            parse("title\n\"닫히지 않음\n"),
+            Err(FormatError::Parse(msg)) if msg.starts_with("2행")
+        ));
+
+        let errors = errors(parse(
+            "title,x,y,color\n,0,0,\n가,하나,0,\n나,1,,\n다,0,0,무지개\n",
+        ));
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        for (error, line) in errors.iter().zip(["2행", "3행", "4행", "5행"]) {
+            assert!(error.starts_with(line), "{}", error);
+        }
+    }
+}
@@ -0,0 +1,552 @@
+// Graphviz DOT 부분 집합: (di)graph, 노드/엣지 문장, 속성 목록, cluster subgraph, rankdir
+use std::collections::HashMap;
+
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::layout::{self, LayoutDirection};
+use crate::types::{nearest_palette_index, CARD_COLORS};
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Id(String),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Semi,
+    Comma,
+    Eq,
+    Colon,
+    EdgeOp,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, FormatError> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut line_start = true;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+
+        // 전처리 줄(#)과 주석
+        if line_start && c == '#' {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c == '\n' {
+            line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        line_start = false;
+
+        match (c, next) {
+            ('/', Some('/')) => {
+                while i < chars.len() && chars[i] != '\n' {
+                    i += 1;
+                }
+            }
+            ('/', Some('*')) => {
+                i += 2;
+                while i + 1 < chars.len() && !(chars[i] == '*' && chars[i + 1] == '/') {
+                    i += 1;
+                }
+                i += 2;
+            }
+            ('-', Some('>')) | ('-', Some('-')) => {
+                tokens.push(Token::EdgeOp);
+                i += 2;
+            }
+            ('{', _) => {
+                tokens.push(Token::LBrace);
+                i += 1;
+            }
+            ('}', _) => {
+                tokens.push(Token::RBrace);
+                i += 1;
+            }
+            ('[', _) => {
+                tokens.push(Token::LBracket);
+                i += 1;
+            }
+            (']', _) => {
+                tokens.push(Token::RBracket);
+                i += 1;
+            }
+            (';', _) => {
+                tokens.push(Token::Semi);
+                i += 1;
+            }
+            (',', _) => {
+                tokens.push(Token::Comma);
+                i += 1;
+            }
+            ('=', _) => {
+                tokens.push(Token::Eq);
+                i += 1;
+            }
+            (':', _) => {
+                tokens.push(Token::Colon);
+                i += 1;
+            }
+            ('"', _) => {
+                let mut value = String::new();
+                i += 1;
+                while i < chars.len() && chars[i] != '"' {
+                    if chars[i] == '\\' && i + 1 < chars.len() {
+                        match chars[i + 1] {
+                            '"' => value.push('"'),
+                            'n' | 'l' | 'r' => value.push('\n'),
+                            '\n' => {}
+                            other => {
+                                value.push('\\');
+                                value.push(other);
+                            }
+                        }
+                        i += 2;
+                    } else {
+                        value.push(chars[i]);
+                        i += 1;
+                    }
+                }
+                if i >= chars.len() {
+                    return Err(FormatError::Parse("닫히지 않은 문자열".into()));
+                }
+                i += 1;
+                tokens.push(Token::Id(value));
+            }
+            ('<', _) => {
+                // HTML 문자열: 태그를 제거한 텍스트만 사용
+                let mut depth = 0;
+                let mut value = String::new();
+                let mut in_tag = false;
+                while i < chars.len() {
+                    match chars[i] {
+                        '<' => {
+                            depth += 1;
+                            if depth > 1 {
+                                in_tag = true;
+                            }
+                        }
+                        '>' => {
+                            depth -= 1;
+                            in_tag = false;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        ch if !in_tag => value.push(ch),
+                        _ => {}
+                    }
+                    i += 1;
+                }
+                i += 1;
+                tokens.push(Token::Id(value.trim().to_string()));
+            }
+            _ if c.is_alphanumeric() || c == '_' || c == '.' || c == '-' => {
+                let start = i;
+                while i < chars.len()
+                    && (chars[i].is_alphanumeric() || matches!(chars[i], '_' | '.'))
+                {
+                    i += 1;
+                }
+                // 음수 리터럴의 '-'
+                if i == start {
+                    i += 1;
+                    while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                        i += 1;
+                    }
+                }
+                tokens.push(Token::Id(chars[start..i].iter().collect()));
+            }
+            _ => {
+                return Err(FormatError::Parse(format!("예상하지 못한 문자 '{}'", c)));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Scope {
+    node_defaults: HashMap<String, String>,
+    members: Vec<usize>,
+    cluster_label: Option<String>,
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    doc: Document,
+    index_by_id: HashMap<String, usize>,
+    scopes: Vec<Scope>,
+    direction: LayoutDirection,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Id(id)) if id.eq_ignore_ascii_case(keyword))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), FormatError> {
+        match self.next() {
+            Some(token) if token == expected => Ok(()),
+            other => Err(FormatError::Parse(format!(
+                "{:?}가 필요하지만 {:?}를 만났습니다",
+                expected, other
+            ))),
+        }
+    }
+
+    fn expect_id(&mut self) -> Result<String, FormatError> {
+        match self.next() {
+            Some(Token::Id(id)) => Ok(id),
+            other => Err(FormatError::Parse(format!(
+                "ID가 필요하지만 {:?}를 만났습니다",
+                other
+            ))),
+        }
+    }
+
+    fn parse_graph(&mut self) -> Result<(), FormatError> {
+        if self.peek_keyword("strict") {
+            self.pos += 1;
+        }
+        if !self.peek_keyword("digraph") && !self.peek_keyword("graph") {
+            return Err(FormatError::Parse(
+                "'digraph' 또는 'graph'가 필요합니다".into(),
+            ));
+        }
+        self.pos += 1;
+        if let Some(Token::Id(_)) = self.peek() {
+            self.pos += 1;
+        }
+        self.expect(Token::LBrace)?;
+        self.parse_stmt_list()?;
+        self.expect(Token::RBrace)
+    }
+
+    fn parse_stmt_list(&mut self) -> Result<(), FormatError> {
+        while !matches!(self.peek(), Some(Token::RBrace) | None) {
+            self.parse_stmt()?;
+            if let Some(Token::Semi) = self.peek() {
+                self.pos += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_stmt(&mut self) -> Result<(), FormatError> {
+        for keyword in ["graph", "node", "edge"] {
+            if self.peek_keyword(keyword) && self.tokens.get(self.pos + 1) == Some(&Token::LBracket)
+            {
+                self.pos += 1;
+                let attrs = self.parse_attr_lists()?;
+                self.apply_attr_stmt(keyword, attrs);
+                return Ok(());
+            }
+        }
+
+        // ID = ID (그래프 속성)
+        if let (Some(Token::Id(key)), Some(Token::Eq)) =
+            (self.peek().cloned(), self.tokens.get(self.pos + 1))
+        {
+            self.pos += 2;
+            let value = self.expect_id()?;
+            self.apply_attr_stmt("graph", vec![(key, value)]);
+            return Ok(());
+        }
+
+        let mut operands = vec![self.parse_operand()?];
+        while let Some(Token::EdgeOp) = self.peek() {
+            self.pos += 1;
+            operands.push(self.parse_operand()?);
+        }
+        let attrs = self.parse_attr_lists()?;
+
+        if operands.len() == 1 {
+            // 단일 노드 문장이면 속성 적용
+            for &idx in &operands[0] {
+                self.apply_node_attrs(idx, &attrs);
+            }
+        }
+        for pair in operands.windows(2) {
+            for &from in &pair[0] {
+                for &to in &pair[1] {
+                    self.doc.connect(from, to);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 노드 ID 또는 subgraph — 포함된 노드 인덱스 목록 반환
+    fn parse_operand(&mut self) -> Result<Vec<usize>, FormatError> {
+        if self.peek_keyword("subgraph") || self.peek() == Some(&Token::LBrace) {
+            return self.parse_subgraph();
+        }
+        let id = self.expect_id()?;
+        // 포트(:port[:compass]) 무시
+        while let Some(Token::Colon) = self.peek() {
+            self.pos += 1;
+            self.expect_id()?;
+        }
+        Ok(vec![self.node(&id)])
+    }
+
+    fn parse_subgraph(&mut self) -> Result<Vec<usize>, FormatError> {
+        let mut name = None;
+        if self.peek_keyword("subgraph") {
+            self.pos += 1;
+            if let Some(Token::Id(id)) = self.peek().cloned() {
+                self.pos += 1;
+                name = Some(id);
+            }
+        }
+        let is_cluster = name.as_deref().is_some_and(|n| n.starts_with("cluster"));
+        let node_defaults = self
+            .scopes
+            .last()
+            .map(|s| s.node_defaults.clone())
+            .unwrap_or_default();
+        self.scopes.push(Scope {
+            node_defaults,
+            members: Vec::new(),
+            cluster_label: None,
+        });
+
+        self.expect(Token::LBrace)?;
+        self.parse_stmt_list()?;
+        self.expect(Token::RBrace)?;
+
+        let Some(scope) = self.scopes.pop() else {
+            return Ok(Vec::new());
+        };
+        if is_cluster {
+            let label = scope.cluster_label.clone().unwrap_or_else(|| {
+                let name = name.unwrap_or_default();
+                name.trim_start_matches("cluster")
+                    .trim_start_matches('_')
+                    .to_string()
+            });
+            for &idx in &scope.members {
+                if self.doc.cards[idx].group.is_empty() {
+                    self.doc.cards[idx].group = label.clone();
+                }
+            }
+        }
+        if let Some(parent) = self.scopes.last_mut() {
+            parent.members.extend(&scope.members);
+        }
+        Ok(scope.members)
+    }
+
+    fn parse_attr_lists(&mut self) -> Result<Vec<(String, String)>, FormatError> {
+        let mut attrs = Vec::new();
+        while let Some(Token::LBracket) = self.peek() {
+            self.pos += 1;
+            while !matches!(self.peek(), Some(Token::RBracket) | None) {
+                let key = self.expect_id()?;
+                let value = if let Some(Token::Eq) = self.peek() {
+                    self.pos += 1;
+                    self.expect_id()?
+                } else {
+                    "true".to_string()
+                };
+                attrs.push((key, value));
+                if matches!(self.peek(), Some(Token::Comma) | Some(Token::Semi)) {
+                    self.pos += 1;
+                }
+            }
+            self.expect(Token::RBracket)?;
+        }
+        Ok(attrs)
+    }
+
+    fn apply_attr_stmt(&mut self, kind: &str, attrs: Vec<(String, String)>) {
+        let is_root = self.scopes.len() == 1;
+        let Some(scope) = self.scopes.last_mut() else {
+            return;
+        };
+        for (key, value) in attrs {
+            match (kind, key.as_str()) {
+                ("graph", "rankdir") if is_root => {
+                    self.direction = LayoutDirection::parse(&value).unwrap_or_default();
+                }
+                ("graph", "label") => scope.cluster_label = Some(value),
+                ("node", _) => {
+                    scope.node_defaults.insert(key, value);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn node(&mut self, id: &str) -> usize {
+        let idx = match self.index_by_id.get(id) {
+            Some(&idx) => idx,
+            None => {
+                let color = CARD_COLORS[self.doc.len() % CARD_COLORS.len()];
+                let idx = self.doc.push_card([0.0, 0.0], color, CardKind::Text, id);
+                self.index_by_id.insert(id.to_string(), idx);
+                let defaults: Vec<(String, String)> = self
+                    .scopes
+                    .last()
+                    .map(|s| s.node_defaults.clone().into_iter().collect())
+                    .unwrap_or_default();
+                self.apply_node_attrs(idx, &defaults);
+                idx
+            }
+        };
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.members.push(idx);
+        }
+        idx
+    }
+
+    fn apply_node_attrs(&mut self, idx: usize, attrs: &[(String, String)]) {
+        for (key, value) in attrs {
+            match key.as_str() {
+                "label" if !value.is_empty() && value != "\\N" => {
+                    self.doc.cards[idx].text = value.clone();
+                }
+                "color" | "fillcolor" => {
+                    if let Some(color) = parse_dot_color(value) {
+                        self.doc.block_positions[idx].color =
+                            CARD_COLORS[nearest_palette_index(color)];
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// "#rrggbb", X11 색상 이름 일부
+fn parse_dot_color(value: &str) -> Option<[f32; 3]> {
+    if let Some(color) = parse_hex_color(value.get(..7).unwrap_or(value)) {
+        return Some(color);
+    }
+    let rgb = match value.to_ascii_lowercase().as_str() {
+        "red" | "crimson" | "pink" | "hotpink" | "deeppink" => [0.94, 0.33, 0.46],
+        "purple" | "violet" | "magenta" | "orchid" | "blueviolet" => [0.55, 0.48, 0.82],
+        "yellow" | "gold" | "khaki" => [0.95, 0.73, 0.15],
+        "cyan" | "lightblue" | "skyblue" | "turquoise" | "blue" | "green" | "lightgreen" => {
+            [0.30, 0.82, 0.88]
+        }
+        "orange" | "darkorange" | "coral" | "tomato" | "brown" => [0.96, 0.58, 0.22],
+        "black" | "gray" | "grey" | "darkgray" | "darkgrey" | "navy" => [0.25, 0.25, 0.30],
+        _ => return None,
+    };
+    Some(rgb)
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+        doc: Document::default(),
+        index_by_id: HashMap::new(),
+        scopes: vec![Scope {
+            node_defaults: HashMap::new(),
+            members: Vec::new(),
+            cluster_label: None,
+        }],
+        direction: LayoutDirection::TopDown,
+    };
+    parser.parse_graph()?;
+
+    let mut doc = parser.doc;
+    layout::layered(&mut doc, parser.direction);
+    Ok(doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::fixtures::edge_texts;
+
+    fn card<'a>(doc: &'a Document, id: &str) -> (usize, &'a crate::document::CardMeta) {
+        doc.cards
+            .iter()
+            .enumerate()
+            .find(|(_, c)| c.text == id)
+            .expect("card")
+    }
+
+    #[test]
+    fn chains_subgraph_operands_and_clusters() {
+        let doc = parse(
+            r#"digraph G {
+  # 전처리 줄
+  rankdir=LR; // 한 줄 주석
+  node [color=orange];
+  /* 여러 줄
+     주석 */
+  a [label="시작"];
+  a -> b -> c;
+  subgraph cluster_0 { label="묶음"; d; e }
+  {d e} -> f
+  subgraph cluster_api { x }
+  subgraph plain { y }
+}"#,
+        )
+        .expect("parses");
+        let expected = [("b", "c"), ("d", "f"), ("e", "f"), ("시작", "b")];
+        assert_eq!(
+            edge_texts(&doc),
+            expected.map(|(a, b)| (a.to_string(), b.to_string()))
+        );
+
+        assert_eq!(card(&doc, "d").1.group, "묶음");
+        assert_eq!(card(&doc, "e").1.group, "묶음");
+        // 라벨 없는 cluster는 이름에서 접두사를 뗀 값, cluster가 아니면 그룹 없음
+        assert_eq!(card(&doc, "x").1.group, "api");
+        assert_eq!(card(&doc, "y").1.group, "");
+        assert_eq!(card(&doc, "f").1.group, "");
+
+        // 노드 기본 속성이 모든 노드에 적용
+        let orange = CARD_COLORS[nearest_palette_index([0.96, 0.58, 0.22])];
+        assert!(doc.block_positions.iter().all(|p| p.color == orange));
+
+        let x = |id: &str| doc.block_positions[card(&doc, id).0].position[0];
+        assert!(x("시작") < x("b") && x("b") < x("c"));
+    }
+
+    #[test]
+    fn rankdir_only_applies_at_the_top_level() {
+        let doc = parse("digraph { subgraph s { rankdir=LR } a -> b }").expect("parses");
+        let y = |id: &str| doc.block_positions[card(&doc, id).0].position[1];
+        assert!(y("a") > y("b"));
+
+        let doc = parse("strict graph { rankdir=BT; a -- b }").expect("parses");
+        let y = |id: &str| doc.block_positions[card(&doc, id).0].position[1];
+        assert!(y("a") < y("b"));
+    }
+
+    #[test]
+    fn rejects_malformed_graphs() {
+        assert!(parse("").is_err());
+        assert!(parse("a -> b").is_err());
+        assert!(parse("digraph { a -> }").is_err());
+        assert!(parse("digraph { a [label=\"열린 문자열] }").is_err());
+        assert!(parse("digraph { a -> b").is_err());
+    }
+}
@@ -0,0 +1,261 @@
+// draw.io (.drawio) mxGraph XML: vertex → 카드, edge → 엣지, 컨테이너 vertex → 그룹
+use std::collections::{HashMap, HashSet};
+
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::types::{nearest_palette_index, CARD_COLORS};
+
+struct Cell<'a> {
+    id: &'a str,
+    parent: &'a str,
+    value: String,
+    style: HashMap<&'a str, &'a str>,
+    geometry: [f32; 4],
+    source: Option<&'a str>,
+    target: Option<&'a str>,
+    is_vertex: bool,
+    is_edge: bool,
+}
+
+/// "key=value;key2;..." 스타일 문자열 (값 없는 키는 도형 이름)
+fn parse_style(style: &str) -> HashMap<&str, &str> {
+    style
+        .split(';')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.split_once('=').unwrap_or((s, "")))
+        .collect()
+}
+
+/// html=1 라벨에서 태그 제거, <br>은 줄바꿈
+fn strip_html(value: &str) -> String {
+    let mut out = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = rest[start + 1..start + end].trim().to_ascii_lowercase();
+        if tag.starts_with("br") || tag == "/div" || tag == "/p" {
+            out.push('\n');
+        }
+        rest = &rest[start + end + 1..];
+    }
+    out.push_str(rest);
+    out.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+        .trim()
+        .to_string()
+}
+
+fn cell_color(style: &HashMap<&str, &str>) -> Option<[f32; 3]> {
+    ["strokeColor", "fillColor"]
+        .iter()
+        .filter_map(|key| style.get(key))
+        .filter_map(|value| parse_hex_color(value))
+        .find(|c| c.iter().any(|&v| v < 0.95))
+        .map(|c| CARD_COLORS[nearest_palette_index(c)])
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    let xml = roxmltree::Document::parse(text).map_err(|e| FormatError::Parse(e.to_string()))?;
+    let model = xml
+        .descendants()
+        .find(|n| n.has_tag_name("mxGraphModel"))
+        .ok_or_else(|| {
+            let compressed = xml.descendants().any(|n| {
+                n.has_tag_name("diagram") && n.text().is_some_and(|t| !t.trim().is_empty())
+            });
+            if compressed {
+                FormatError::Unsupported(
+                    "압축된 draw.io 다이어그램 (압축 해제 후 저장 필요)".into(),
+                )
+            } else {
+                FormatError::Parse("<mxGraphModel> 요소가 없습니다".into())
+            }
+        })?;
+
+    let cells: Vec<Cell> = model
+        .descendants()
+        .filter(|n| n.has_tag_name("mxCell"))
+        .map(|n| {
+            let geometry = n
+                .children()
+                .find(|g| g.has_tag_name("mxGeometry"))
+                .map(|g| {
+                    let attr = |key| g.attribute(key).and_then(|v| v.parse().ok()).unwrap_or(0.0);
+                    [attr("x"), attr("y"), attr("width"), attr("height")]
+                })
+                .unwrap_or_default();
+            Cell {
+                id: n.attribute("id").unwrap_or(""),
+                parent: n.attribute("parent").unwrap_or(""),
+                value: strip_html(n.attribute("value").unwrap_or("")),
+                style: parse_style(n.attribute("style").unwrap_or("")),
+                geometry,
+                source: n.attribute("source"),
+                target: n.attribute("target"),
+                is_vertex: n.attribute("vertex") == Some("1"),
+                is_edge: n.attribute("edge") == Some("1"),
+            }
+        })
+        .collect();
+
+    let cell_by_id: HashMap<&str, &Cell> = cells.iter().map(|c| (c.id, c)).collect();
+    let is_vertex = |id: &str| cell_by_id.get(id).is_some_and(|c| c.is_vertex);
+    // 부모 vertex를 가진 vertex는 컨테이너(그룹) 멤버
+    let containers: HashSet<&str> = cells
+        .iter()
+        .filter(|c| c.is_vertex && is_vertex(c.parent))
+        .map(|c| c.parent)
+        .collect();
+
+    // 자식 좌표는 부모 기준이므로 조상 좌표를 누적.
+    // 부모 관계가 순환하는 손상된 파일에서 멈추지 않도록 셀 수만큼만 거슬러 올라감
+    let absolute_origin = |cell: &Cell| {
+        let mut origin = [cell.geometry[0], cell.geometry[1]];
+        let mut parent = cell.parent;
+        for _ in 0..cells.len() {
+            let Some(p) = cell_by_id.get(parent).filter(|p| p.is_vertex) else {
+                break;
+            };
+            origin[0] += p.geometry[0];
+            origin[1] += p.geometry[1];
+            parent = p.parent;
+        }
+        origin
+    };
+
+    let mut doc = Document::default();
+    let mut index_by_id = HashMap::new();
+    for cell in cells.iter().filter(|c| c.is_vertex) {
+        // 엣지 라벨 셀은 건너뜀
+        if cell_by_id.get(cell.parent).is_some_and(|p| p.is_edge) {
+            continue;
+        }
+        let [x, y] = absolute_origin(cell);
+        let [_, _, w, h] = cell.geometry;
+        let is_group = containers.contains(cell.id)
+            || cell.style.contains_key("swimlane")
+            || cell.style.get("container") == Some(&"1");
+        let kind = if is_group {
+            CardKind::Group
+        } else {
+            CardKind::Text
+        };
+        let color = cell_color(&cell.style).unwrap_or(CARD_COLORS[doc.len() % CARD_COLORS.len()]);
+        let idx = doc.push_card(rect_px_to_world(x, y, w, h), color, kind, &cell.value);
+        index_by_id.insert(cell.id, idx);
+    }
+
+    for cell in cells.iter().filter(|c| c.is_vertex) {
+        let (Some(&idx), Some(parent)) = (index_by_id.get(cell.id), cell_by_id.get(cell.parent))
+        else {
+            continue;
+        };
+        if let Some(&group) = index_by_id.get(parent.id) {
+            doc.cards[idx].parent = Some(doc.cards[group].id);
+            doc.cards[idx].group = parent.value.clone();
+        }
+    }
+
+    for cell in cells.iter().filter(|c| c.is_edge) {
+        let from = cell.source.and_then(|id| index_by_id.get(id));
+        let to = cell.target.and_then(|id| index_by_id.get(id));
+        if let (Some(&from), Some(&to)) = (from, to) {
+            doc.connect(from, to);
+        }
+    }
+
+    Ok(doc)
+}
+
+pub fn to_string(doc: &Document) -> String {
+    let mut out = String::from(
+        "<mxfile host=\"wgpu_perf_test\">\n  <diagram id=\"board\" name=\"Page-1\">\n    <mxGraphModel>\n      <root>\n        <mxCell id=\"0\" />\n        <mxCell id=\"1\" parent=\"0\" />\n",
+    );
+
+    // 그룹은 container 셀로 쓰고 멤버를 그 자식으로 둠 (자식 좌표는 부모 기준)
+    let parents = group_parents(doc);
+    let rects: Vec<[f32; 4]> = (0..doc.len()).map(|i| card_rect_px(doc, i)).collect();
+    for idx in parents_first(&parents) {
+        let (instance, meta) = (&doc.block_positions[idx], &doc.cards[idx]);
+        let [mut x, mut y, w, h] = rects[idx];
+        let parent = match parents[idx] {
+            Some(p) => {
+                x -= rects[p][0];
+                y -= rects[p][1];
+                format!("card-{}", doc.cards[p].id)
+            }
+            None => "1".to_string(),
+        };
+        let stroke = to_hex_color(instance.color);
+        let dashed = if meta.kind == CardKind::Group {
+            "dashed=1;fillColor=none;verticalAlign=top;container=1;"
+        } else {
+            "fillColor=#ffffff;"
+        };
+        // html=1 라벨: 텍스트를 HTML로 이스케이프한 뒤 줄바꿈을 <br>로, 다시 XML 속성으로 이스케이프
+        let value = xml_escape(&xml_escape(&meta.text).replace("&#xa;", "<br>"));
+        out.push_str(&format!(
+            "        <mxCell id=\"card-{}\" value=\"{}\" style=\"rounded=1;whiteSpace=wrap;html=1;strokeWidth=2;strokeColor={};{}\" vertex=\"1\" parent=\"{}\">\n          <mxGeometry x=\"{:.0}\" y=\"{:.0}\" width=\"{:.0}\" height=\"{:.0}\" as=\"geometry\" />\n        </mxCell>\n",
+            meta.id, value, stroke, dashed, parent, x, y, w, h
+        ));
+    }
+
+    for (i, edge) in doc.edges.iter().enumerate() {
+        let stroke = to_hex_color(doc.block_positions[edge.from].color);
+        out.push_str(&format!(
+            "        <mxCell id=\"edge-{}\" style=\"endArrow=classic;html=1;strokeColor={};\" edge=\"1\" parent=\"1\" source=\"card-{}\" target=\"card-{}\">\n          <mxGeometry relative=\"1\" as=\"geometry\" />\n        </mxCell>\n",
+            i, stroke, doc.cards[edge.from].id, doc.cards[edge.to].id
+        ));
+    }
+
+    out.push_str("      </root>\n    </mxGraphModel>\n  </diagram>\n</mxfile>\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::fixtures::{group_summary, grouped_board};
+
+    fn model(cells: &str) -> String {
+        format!(
+            "<mxfile><diagram><mxGraphModel><root><mxCell id=\"0\" /><mxCell id=\"1\" parent=\"0\" />{}</root></mxGraphModel></diagram></mxfile>",
+            cells
+        )
+    }
+
+    #[test]
+    fn groups_round_trip_as_containers() {
+        let doc = grouped_board();
+        let text = to_string(&doc);
+        assert!(text.contains("container=1"));
+        let parsed = parse(&text).expect("exported draw.io parses");
+        assert_eq!(group_summary(&doc), group_summary(&parsed));
+        assert_eq!(parsed.edges.len(), 2);
+        // 자식 좌표는 부모 기준으로 저장되지만 읽으면 같은 위치
+        let b = parsed.cards.iter().position(|c| c.text == "B").expect("B");
+        let [x, y] = parsed.block_positions[b].position;
+        assert!(
+            (x - 0.5).abs() < 0.01 && (y - 0.1).abs() < 0.01,
+            "{} {}",
+            x,
+            y
+        );
+    }
+
+    #[test]
+    fn cyclic_parents_do_not_hang() {
+        let text = model(
+            r#"<mxCell id="a" value="A" vertex="1" parent="b"><mxGeometry x="10" y="10" width="100" height="60" as="geometry" /></mxCell>
+               <mxCell id="b" value="B" vertex="1" parent="a"><mxGeometry x="20" y="20" width="100" height="60" as="geometry" /></mxCell>"#,
+        );
+        let doc = parse(&text).expect("cyclic file still parses");
+        assert_eq!(doc.len(), 2);
+    }
+}
@@ -0,0 +1,408 @@
+// Excalidraw (.excalidraw) JSON: 사각형/타원/마름모/텍스트 → 카드, 화살표 → 엣지,
+// 프레임과 groupIds 묶음 → 그룹 카드
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::types::{nearest_palette_index, CARD_COLORS};
+
+const SHAPE_TYPES: [&str; 3] = ["rectangle", "ellipse", "diamond"];
+const FRAME_TYPES: [&str; 2] = ["frame", "magicframe"];
+const FONT_SIZE: f32 = 20.0;
+
+fn str_field<'a>(element: &'a Value, key: &str) -> &'a str {
+    element.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+fn num_field(element: &Value, key: &str) -> f32 {
+    element.get(key).and_then(Value::as_f64).unwrap_or(0.0) as f32
+}
+
+fn element_color(element: &Value) -> Option<[f32; 3]> {
+    ["strokeColor", "backgroundColor"]
+        .iter()
+        .filter_map(|key| parse_hex_color(str_field(element, key)))
+        // 기본 검정 테두리는 팔레트 매핑에서 제외
+        .find(|c| c.iter().any(|&v| v > 0.15))
+        .map(|c| CARD_COLORS[nearest_palette_index(c)])
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    let root: Value = serde_json::from_str(text).map_err(|e| FormatError::Parse(e.to_string()))?;
+    if root.get("type").and_then(Value::as_str) != Some("excalidraw") {
+        return Err(FormatError::Parse("Excalidraw 문서가 아닙니다".into()));
+    }
+    let elements: Vec<&Value> = root
+        .get("elements")
+        .and_then(Value::as_array)
+        .ok_or_else(|| FormatError::Parse("'elements' 배열이 없습니다".into()))?
+        .iter()
+        .filter(|e| !e.get("isDeleted").and_then(Value::as_bool).unwrap_or(false))
+        .collect();
+
+    // 도형에 묶인 텍스트 (containerId → 텍스트)
+    let bound_text: HashMap<&str, &str> = elements
+        .iter()
+        .filter(|e| str_field(e, "type") == "text" && !str_field(e, "containerId").is_empty())
+        .map(|e| (str_field(e, "containerId"), str_field(e, "text")))
+        .collect();
+
+    let mut doc = Document::default();
+    let mut index_by_id = HashMap::new();
+    let mut rects = Vec::new();
+    // 카드가 된 요소 (카드 인덱스 순)
+    let mut card_elements = Vec::new();
+
+    for element in &elements {
+        let kind = str_field(element, "type");
+        let is_free_text = kind == "text" && str_field(element, "containerId").is_empty();
+        let is_frame = FRAME_TYPES.contains(&kind);
+        if !SHAPE_TYPES.contains(&kind) && !is_free_text && !is_frame {
+            continue;
+        }
+        let id = str_field(element, "id");
+        let (x, y) = (num_field(element, "x"), num_field(element, "y"));
+        let (w, h) = (num_field(element, "width"), num_field(element, "height"));
+        let label = if is_free_text {
+            str_field(element, "text")
+        } else if is_frame {
+            str_field(element, "name")
+        } else {
+            bound_text.get(id).copied().unwrap_or("")
+        };
+        let card_kind = if is_frame {
+            CardKind::Group
+        } else {
+            CardKind::Text
+        };
+        let color = element_color(element).unwrap_or(CARD_COLORS[doc.len() % CARD_COLORS.len()]);
+        let idx = doc.push_card(rect_px_to_world(x, y, w, h), color, card_kind, label);
+        index_by_id.insert(id, idx);
+        card_elements.push(*element);
+        if !is_frame {
+            rects.push((idx, x, y, w, h));
+        }
+    }
+    assign_groups(&mut doc, &card_elements, &index_by_id);
+
+    // 바인딩이 없는 화살표는 끝점을 포함하는 도형으로 연결
+    let shape_at = |px: f32, py: f32| {
+        rects
+            .iter()
+            .find(|(_, x, y, w, h)| px >= *x && px <= x + w && py >= *y && py <= y + h)
+            .map(|(idx, ..)| *idx)
+    };
+    for element in &elements {
+        if str_field(element, "type") != "arrow" {
+            continue;
+        }
+        let binding = |key: &str| {
+            element
+                .get(key)
+                .and_then(|b| b.get("elementId"))
+                .and_then(Value::as_str)
+                .and_then(|id| index_by_id.get(id).copied())
+        };
+        let points = element.get("points").and_then(Value::as_array);
+        let point = |i: Option<&Value>| -> Option<(f32, f32)> {
+            let p = i?.as_array()?;
+            Some((
+                num_field(element, "x") + p.first()?.as_f64()? as f32,
+                num_field(element, "y") + p.get(1)?.as_f64()? as f32,
+            ))
+        };
+        let from = binding("startBinding").or_else(|| {
+            let (px, py) = point(points.and_then(|p| p.first()))?;
+            shape_at(px, py)
+        });
+        let to = binding("endBinding").or_else(|| {
+            let (px, py) = point(points.and_then(|p| p.last()))?;
+            shape_at(px, py)
+        });
+        if let (Some(from), Some(to)) = (from, to) {
+            doc.connect(from, to);
+        }
+    }
+
+    Ok(doc)
+}
+
+fn group_ids(element: &Value) -> Vec<&str> {
+    element
+        .get("groupIds")
+        .and_then(Value::as_array)
+        .map(|ids| ids.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+// groupIds 묶음 하나: 멤버 카드 중심의 경계 상자(월드 좌표)와 바로 바깥 묶음
+struct GroupBounds<'a> {
+    id: &'a str,
+    min: [f32; 2],
+    max: [f32; 2],
+    outer: Option<&'a str>,
+}
+
+impl GroupBounds<'_> {
+    fn include(&mut self, min: [f32; 2], max: [f32; 2]) {
+        self.min = [self.min[0].min(min[0]), self.min[1].min(min[1])];
+        self.max = [self.max[0].max(max[0]), self.max[1].max(max[1])];
+    }
+}
+
+/// frameId가 가리키는 프레임을 그룹 카드로 삼고, 프레임 밖의 groupIds 묶음은
+/// 멤버를 감싸는 그룹 카드를 새로 만들어 연결 (groupIds는 안쪽 → 바깥쪽 순)
+fn assign_groups(doc: &mut Document, card_elements: &[&Value], index_by_id: &HashMap<&str, usize>) {
+    let mut groups: Vec<GroupBounds> = Vec::new();
+    let mut group_index: HashMap<&str, usize> = HashMap::new();
+
+    for (idx, element) in card_elements.iter().enumerate() {
+        let frame = index_by_id.get(str_field(element, "frameId")).copied();
+        if let Some(frame) = frame.filter(|&f| f != idx) {
+            doc.cards[idx].parent = Some(doc.cards[frame].id);
+            doc.cards[idx].group = doc.cards[frame].text.clone();
+            continue;
+        }
+        let ids = group_ids(element);
+        let center = doc.block_positions[idx].position;
+        for (i, &id) in ids.iter().enumerate() {
+            match group_index.get(id) {
+                Some(&g) => groups[g].include(center, center),
+                None => {
+                    group_index.insert(id, groups.len());
+                    groups.push(GroupBounds {
+                        id,
+                        min: center,
+                        max: center,
+                        outer: ids.get(i + 1).copied(),
+                    });
+                }
+            }
+        }
+    }
+
+    // 안쪽 묶음의 범위도 바깥 묶음에 포함 (순환하는 손상된 파일은 묶음 수만큼만)
+    for i in 0..groups.len() {
+        let (min, max, mut outer) = (groups[i].min, groups[i].max, groups[i].outer);
+        for _ in 0..groups.len() {
+            let Some(&g) = outer.and_then(|o| group_index.get(o)) else {
+                break;
+            };
+            groups[g].include(min, max);
+            outer = groups[g].outer;
+        }
+    }
+
+    let mut group_cards: HashMap<&str, usize> = HashMap::new();
+    for group in &groups {
+        let center = [
+            (group.min[0] + group.max[0]) * 0.5,
+            (group.min[1] + group.max[1]) * 0.5,
+        ];
+        let color = CARD_COLORS[doc.len() % CARD_COLORS.len()];
+        let idx = doc.push_card(center, color, CardKind::Group, "");
+        group_cards.insert(group.id, idx);
+    }
+    for group in &groups {
+        let outer = group.outer.and_then(|o| group_cards.get(o));
+        if let (Some(&idx), Some(&outer)) = (group_cards.get(group.id), outer) {
+            doc.cards[idx].parent = Some(doc.cards[outer].id);
+        }
+    }
+    for (idx, element) in card_elements.iter().enumerate() {
+        if doc.cards[idx].parent.is_some() {
+            continue;
+        }
+        if let Some(&group) = group_ids(element).first().and_then(|g| group_cards.get(g)) {
+            doc.cards[idx].parent = Some(doc.cards[group].id);
+        }
+    }
+}
+
+fn base_element(id: String, kind: &str, rect: [f32; 4], stroke: &str, seed: u64) -> Value {
+    let [x, y, width, height] = rect;
+    // Excalidraw는 seed를 JS 정수로 다루므로 큰 ID도 31비트 안으로 접음
+    let seed = seed % (1 << 31);
+    json!({
+        "id": id,
+        "type": kind,
+        "x": x,
+        "y": y,
+        "width": width,
+        "height": height,
+        "angle": 0,
+        "strokeColor": stroke,
+        "backgroundColor": "transparent",
+        "fillStyle": "solid",
+        "strokeWidth": 2,
+        "strokeStyle": "solid",
+        "roughness": 0,
+        "opacity": 100,
+        "groupIds": [],
+        "frameId": null,
+        "roundness": null,
+        "seed": seed,
+        "version": 1,
+        "versionNonce": seed,
+        "isDeleted": false,
+        "boundElements": [],
+        "updated": 0,
+        "link": null,
+        "locked": false,
+    })
+}
+
+pub fn to_string(doc: &Document) -> Result<String, FormatError> {
+    let shape_id = |idx: usize| format!("card-{}", doc.cards[idx].id);
+    let mut elements = Vec::new();
+    let mut arrows_by_card: HashMap<usize, Vec<Value>> = HashMap::new();
+    for (i, edge) in doc.edges.iter().enumerate() {
+        for idx in [edge.from, edge.to] {
+            arrows_by_card
+                .entry(idx)
+                .or_default()
+                .push(json!({ "id": format!("edge-{}", i), "type": "arrow" }));
+        }
+    }
+
+    // 그룹 카드는 프레임으로, 멤버는 frameId와 바깥 그룹까지의 groupIds로
+    let parents = group_parents(doc);
+    let group_id = |idx: usize| format!("group-{}", doc.cards[idx].id);
+    for idx in parents_first(&parents) {
+        let (instance, meta) = (&doc.block_positions[idx], &doc.cards[idx]);
+        let rect = card_rect_px(doc, idx);
+        let stroke = to_hex_color(instance.color);
+        let id = shape_id(idx);
+        let text_id = format!("text-{}", meta.id);
+        let mut ancestors = Vec::new();
+        let mut current = parents[idx];
+        while let Some(p) = current {
+            ancestors.push(group_id(p));
+            current = parents[p];
+        }
+        let frame_id = parents[idx].map(shape_id);
+
+        if meta.kind == CardKind::Group {
+            let mut frame = base_element(id, "frame", rect, &stroke, meta.id);
+            frame["name"] = json!(meta.text);
+            frame["groupIds"] = json!(ancestors);
+            frame["frameId"] = json!(frame_id);
+            elements.push(frame);
+            continue;
+        }
+
+        let mut shape = base_element(id.clone(), "rectangle", rect, &stroke, meta.id);
+        shape["roundness"] = json!({ "type": 3 });
+        shape["groupIds"] = json!(ancestors);
+        shape["frameId"] = json!(frame_id);
+        let mut bound = arrows_by_card.remove(&idx).unwrap_or_default();
+        if !meta.text.is_empty() {
+            bound.push(json!({ "id": text_id, "type": "text" }));
+        }
+        shape["boundElements"] = Value::Array(bound);
+        elements.push(shape);
+
+        if !meta.text.is_empty() {
+            let mut text = base_element(text_id, "text", rect, "#1e1e1e", meta.id.wrapping_add(1));
+            text["text"] = json!(meta.text);
+            text["originalText"] = json!(meta.text);
+            text["fontSize"] = json!(FONT_SIZE);
+            text["fontFamily"] = json!(1);
+            text["textAlign"] = json!("center");
+            text["verticalAlign"] = json!("middle");
+            text["containerId"] = json!(id);
+            text["lineHeight"] = json!(1.25);
+            text["groupIds"] = json!(ancestors);
+            text["frameId"] = json!(frame_id);
+            elements.push(text);
+        }
+    }
+
+    for (i, edge) in doc.edges.iter().enumerate() {
+        let start = world_to_rect_px(doc.block_positions[edge.from].position, 0.0, 0.0);
+        let end = world_to_rect_px(doc.block_positions[edge.to].position, 0.0, 0.0);
+        let dx = end[0] - start[0];
+        let dy = end[1] - start[1];
+        let stroke = to_hex_color(doc.block_positions[edge.from].color);
+        let rect = [start[0], start[1], dx.abs(), dy.abs()];
+        let mut arrow = base_element(format!("edge-{}", i), "arrow", rect, &stroke, i as u64);
+        arrow["points"] = json!([[0.0, 0.0], [dx, dy]]);
+        arrow["lastCommittedPoint"] = Value::Null;
+        arrow["startBinding"] = json!({ "elementId": shape_id(edge.from), "focus": 0, "gap": 8 });
+        arrow["endBinding"] = json!({ "elementId": shape_id(edge.to), "focus": 0, "gap": 8 });
+        arrow["startArrowhead"] = Value::Null;
+        arrow["endArrowhead"] = json!("arrow");
+        elements.push(arrow);
+    }
+
+    let root = json!({
+        "type": "excalidraw",
+        "version": 2,
+        "source": "wgpu_perf_test",
+        "elements": elements,
+        "appState": { "viewBackgroundColor": "#ffffff", "gridSize": null },
+        "files": {},
+    });
+    serde_json::to_string_pretty(&root).map_err(|e| FormatError::Parse(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::fixtures::{group_summary, grouped_board};
+
+    #[test]
+    fn groups_round_trip_as_frames() {
+        let doc = grouped_board();
+        let text = to_string(&doc).expect("serializes");
+        let parsed = parse(&text).expect("exported Excalidraw parses");
+        assert_eq!(group_summary(&doc), group_summary(&parsed));
+        assert_eq!(parsed.edges.len(), 2);
+    }
+
+    #[test]
+    fn group_ids_become_group_cards() {
+        let text = r#"{
+            "type": "excalidraw",
+            "elements": [
+                {"id": "a", "type": "rectangle", "x": 0, "y": 0, "width": 100, "height": 50, "groupIds": ["inner", "outer"]},
+                {"id": "b", "type": "rectangle", "x": 200, "y": 0, "width": 100, "height": 50, "groupIds": ["inner", "outer"]},
+                {"id": "c", "type": "ellipse", "x": 0, "y": 300, "width": 100, "height": 50, "groupIds": ["outer"]},
+                {"id": "d", "type": "diamond", "x": 900, "y": 900, "width": 100, "height": 50}
+            ]
+        }"#;
+        let doc = parse(text).expect("parses");
+        let groups: Vec<usize> = (0..doc.len())
+            .filter(|&i| doc.cards[i].kind == CardKind::Group)
+            .collect();
+        assert_eq!(groups.len(), 2);
+        let parent_of = |i: usize| {
+            doc.cards[i]
+                .parent
+                .and_then(|p| doc.cards.iter().position(|c| c.id == p))
+        };
+        let inner = parent_of(0).expect("a is grouped");
+        let outer = parent_of(2).expect("c is grouped");
+        assert_eq!(parent_of(1), Some(inner));
+        assert_eq!(parent_of(inner), Some(outer));
+        assert_eq!(parent_of(outer), None);
+        assert_eq!(parent_of(3), None);
+    }
+
+    #[test]
+    fn large_ids_export_without_overflow() {
+        let mut doc = grouped_board();
+        // 그룹에 속하지 않은 텍스트 카드 C (본문 요소 seed가 id + 1)
+        doc.cards[4].id = u64::MAX;
+        let text = to_string(&doc).expect("serializes");
+        let value: Value = serde_json::from_str(&text).expect("valid JSON");
+        let seeds = value["elements"]
+            .as_array()
+            .expect("elements")
+            .iter()
+            .filter_map(|e| e["seed"].as_u64());
+        assert!(seeds.into_iter().all(|seed| seed < 1 << 31));
+    }
+}
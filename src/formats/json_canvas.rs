@@ -0,0 +1,321 @@
+// JSON Canvas (.canvas) 1.0 — https://jsoncanvas.org/spec/1.0/
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::types::{nearest_palette_index, CARD_COLORS};
+
+#[derive(Serialize, Deserialize, Default)]
+struct Canvas {
+    #[serde(default)]
+    nodes: Vec<CanvasNode>,
+    #[serde(default)]
+    edges: Vec<CanvasEdge>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CanvasNode {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CanvasEdge {
+    id: String,
+    #[serde(rename = "fromNode")]
+    from_node: String,
+    #[serde(rename = "toNode")]
+    to_node: String,
+}
+
+// JSON Canvas 프리셋 색상 "1"~"6" ↔ CARD_COLORS 인덱스
+const PRESET_TO_PALETTE: [usize; 6] = [
+    0, // 1 빨강 → 분홍
+    4, // 2 주황
+    2, // 3 노랑
+    3, // 4 초록 → 시안
+    3, // 5 시안
+    1, // 6 보라
+];
+const PALETTE_TO_PRESET: [Option<&str>; 6] = [
+    Some("1"),
+    Some("6"),
+    Some("3"),
+    Some("5"),
+    Some("2"),
+    None, // 다크는 프리셋이 없어 hex로 저장
+];
+
+fn color_from_canvas(color: Option<&str>) -> Option<[f32; 3]> {
+    let color = color?;
+    if let Ok(preset) = color.parse::<usize>() {
+        let idx = PRESET_TO_PALETTE.get(preset.checked_sub(1)?)?;
+        return Some(CARD_COLORS[*idx]);
+    }
+    parse_hex_color(color).map(|c| CARD_COLORS[nearest_palette_index(c)])
+}
+
+fn color_to_canvas(color: [f32; 3]) -> String {
+    let idx = nearest_palette_index(color);
+    match PALETTE_TO_PRESET[idx] {
+        Some(preset) => preset.to_string(),
+        None => to_hex_color(CARD_COLORS[idx]),
+    }
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    let canvas: Canvas =
+        serde_json::from_str(text).map_err(|e| FormatError::Parse(e.to_string()))?;
+
+    let mut doc = Document::default();
+    let mut index_by_id = HashMap::new();
+    let mut group_rects = Vec::new();
+
+    for (i, node) in canvas.nodes.iter().enumerate() {
+        let (kind, text) = match node.kind.as_str() {
+            "text" => (CardKind::Text, node.text.as_deref()),
+            "file" => (CardKind::File, node.file.as_deref()),
+            "link" => (CardKind::Link, node.url.as_deref()),
+            "group" => (CardKind::Group, node.label.as_deref()),
+            other => {
+                return Err(FormatError::Parse(format!(
+                    "노드 {}: 알 수 없는 타입 '{}'",
+                    node.id, other
+                )))
+            }
+        };
+        let color =
+            color_from_canvas(node.color.as_deref()).unwrap_or(CARD_COLORS[i % CARD_COLORS.len()]);
+        let center = rect_px_to_world(node.x, node.y, node.width, node.height);
+        let idx = doc.push_card(center, color, kind, text.unwrap_or(""));
+        doc.cards[idx].id = card_id_from(&node.id);
+        if index_by_id.insert(node.id.as_str(), idx).is_some() {
+            return Err(FormatError::Parse(format!(
+                "노드 ID '{}'가 중복됩니다",
+                node.id
+            )));
+        }
+
+        if kind == CardKind::Group {
+            group_rects.push((idx, node.x, node.y, node.width, node.height));
+        }
+    }
+
+    // 그룹 사각형 안에 중심이 들어오는 카드를 그룹 멤버로 지정 (작은 그룹 우선)
+    group_rects.sort_by(|a, b| (a.3 * a.4).total_cmp(&(b.3 * b.4)));
+    for (i, node) in canvas.nodes.iter().enumerate() {
+        let cx = node.x + node.width * 0.5;
+        let cy = node.y + node.height * 0.5;
+        let container = group_rects
+            .iter()
+            .find(|(g, x, y, w, h)| *g != i && cx >= *x && cx <= x + w && cy >= *y && cy <= y + h);
+        if let Some(&(g, ..)) = container {
+            doc.cards[i].parent = Some(doc.cards[g].id);
+            doc.cards[i].group = doc.cards[g].text.clone();
+        }
+    }
+
+    for edge in &canvas.edges {
+        let from = index_by_id.get(edge.from_node.as_str());
+        let to = index_by_id.get(edge.to_node.as_str());
+        match (from, to) {
+            (Some(&from), Some(&to)) => doc.connect(from, to),
+            _ => {
+                return Err(FormatError::Parse(format!(
+                    "엣지 {}: 존재하지 않는 노드 참조",
+                    edge.id
+                )))
+            }
+        }
+    }
+
+    Ok(doc)
+}
+
+pub fn to_string(doc: &Document) -> Result<String, FormatError> {
+    let node_id = |idx: usize| format!("{:016x}", doc.cards[idx].id);
+    let mut canvas = Canvas::default();
+
+    for (idx, (instance, meta)) in doc.block_positions.iter().zip(&doc.cards).enumerate() {
+        let [x, y, width, height] = card_rect_px(doc, idx);
+
+        let text = Some(meta.text.clone());
+        let mut node = CanvasNode {
+            id: node_id(idx),
+            kind: String::new(),
+            x: x.round(),
+            y: y.round(),
+            width: width.round(),
+            height: height.round(),
+            color: Some(color_to_canvas(instance.color)),
+            text: None,
+            file: None,
+            url: None,
+            label: None,
+        };
+        match meta.kind {
+            CardKind::Text => {
+                node.kind = "text".to_string();
+                node.text = text;
+            }
+            CardKind::File => {
+                node.kind = "file".to_string();
+                node.file = text;
+            }
+            CardKind::Link => {
+                node.kind = "link".to_string();
+                node.url = text;
+            }
+            CardKind::Group => {
+                node.kind = "group".to_string();
+                node.label = text;
+            }
+        }
+        canvas.nodes.push(node);
+    }
+
+    for (i, edge) in doc.edges.iter().enumerate() {
+        canvas.edges.push(CanvasEdge {
+            id: format!("e{}", i),
+            from_node: node_id(edge.from),
+            to_node: node_id(edge.to),
+        });
+    }
+
+    serde_json::to_string_pretty(&canvas).map_err(|e| FormatError::Parse(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r##"{
+        "nodes": [
+            {"id": "g1", "type": "group", "x": -100, "y": -100, "width": 800, "height": 400, "label": "기획"},
+            {"id": "g2", "type": "group", "x": 1000, "y": 0, "width": 400, "height": 300, "color": "#22c55e"},
+            {"id": "a", "type": "text", "x": 0, "y": 0, "width": 250, "height": 150, "text": "첫 카드\n둘째 줄", "color": "1"},
+            {"id": "b", "type": "file", "x": 300, "y": 0, "width": 250, "height": 150, "file": "notes/회의.md", "color": "6"},
+            {"id": "c", "type": "link", "x": 1050, "y": 50, "width": 250, "height": 150, "url": "https://jsoncanvas.org", "color": "#ff8800"},
+            {"id": "d", "type": "text", "x": 2000, "y": 2000, "width": 250, "height": 150, "text": ""}
+        ],
+        "edges": [
+            {"id": "e1", "fromNode": "a", "toNode": "b"},
+            {"id": "e2", "fromNode": "b", "toNode": "c"},
+            {"id": "e3", "fromNode": "d", "toNode": "a"}
+        ]
+    }"##;
+
+    // 비교용 요약: 종류, 텍스트, 색, 위치(px 반올림), 그룹 카드 인덱스
+    type CardSummary = (CardKind, String, String, [i32; 2], Option<usize>);
+
+    fn summary(doc: &Document) -> Vec<CardSummary> {
+        let index_of = |id: u64| doc.cards.iter().position(|c| c.id == id);
+        doc.block_positions
+            .iter()
+            .zip(&doc.cards)
+            .map(|(instance, meta)| {
+                let px = instance
+                    .position
+                    .map(|v| (v * PIXELS_PER_UNIT).round() as i32);
+                let parent = meta.parent.and_then(index_of);
+                (
+                    meta.kind,
+                    meta.text.clone(),
+                    to_hex_color(instance.color),
+                    px,
+                    parent,
+                )
+            })
+            .collect()
+    }
+
+    fn edges(doc: &Document) -> Vec<(usize, usize)> {
+        doc.edges.iter().map(|e| (e.from, e.to)).collect()
+    }
+
+    #[test]
+    fn round_trip_keeps_nodes_edges_colors_and_groups() {
+        let first = parse(SAMPLE).expect("sample parses");
+        let text = to_string(&first).expect("serializes");
+        let second = parse(&text).expect("exported canvas parses");
+
+        assert_eq!(summary(&first), summary(&second));
+        assert_eq!(edges(&first), edges(&second));
+        // 다시 내보내도 같은 결과
+        assert_eq!(text, to_string(&second).expect("serializes"));
+    }
+
+    #[test]
+    fn parse_assigns_kinds_colors_and_groups() {
+        let doc = parse(SAMPLE).expect("sample parses");
+        let kinds: Vec<CardKind> = doc.cards.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                CardKind::Group,
+                CardKind::Group,
+                CardKind::Text,
+                CardKind::File,
+                CardKind::Link,
+                CardKind::Text
+            ]
+        );
+        assert_eq!(doc.block_positions[2].color, CARD_COLORS[0]);
+        assert_eq!(doc.block_positions[3].color, CARD_COLORS[1]);
+
+        let parents: Vec<Option<u64>> = doc.cards.iter().map(|c| c.parent).collect();
+        let (g1, g2) = (doc.cards[0].id, doc.cards[1].id);
+        assert_eq!(parents, [None, None, Some(g1), Some(g1), Some(g2), None]);
+        assert_eq!(doc.cards[2].group, "기획");
+        assert_eq!(edges(&doc), [(2, 3), (3, 4), (5, 2)]);
+    }
+
+    #[test]
+    fn unlabeled_group_only_covers_its_members() {
+        let doc = parse(SAMPLE).expect("sample parses");
+        // 이름 없는 그룹(g2)이 그룹 밖의 카드(d)까지 감싸지 않음
+        let [_, _, width, height] = card_rect_px(&doc, 1);
+        assert!(width < 1000.0 && height < 1000.0, "{} x {}", width, height);
+    }
+
+    #[test]
+    fn rejects_unknown_node_type_and_dangling_edge() {
+        let unknown =
+            r#"{"nodes": [{"id": "a", "type": "shape", "x": 0, "y": 0, "width": 1, "height": 1}]}"#;
+        assert!(matches!(parse(unknown), Err(FormatError::Parse(_))));
+        let dangling = r#"{"nodes": [], "edges": [{"id": "e", "fromNode": "a", "toNode": "b"}]}"#;
+        assert!(matches!(parse(dangling), Err(FormatError::Parse(_))));
+    }
+
+    #[test]
+    fn node_ids_are_stable_across_imports() {
+        let doc = parse(SAMPLE).expect("sample parses");
+        let again = parse(SAMPLE).expect("sample parses");
+        let ids = |d: &Document| d.cards.iter().map(|c| c.id).collect::<Vec<_>>();
+        assert_eq!(ids(&doc), ids(&again));
+        assert_eq!(doc.cards[0].id, card_id_from("g1"));
+
+        let duplicate = r#"{"nodes": [
+            {"id": "a", "type": "text", "x": 0, "y": 0, "width": 1, "height": 1},
+            {"id": "a", "type": "text", "x": 9, "y": 0, "width": 1, "height": 1}
+        ]}"#;
+        assert!(matches!(parse(duplicate), Err(FormatError::Parse(_))));
+    }
+}
@@ -0,0 +1,408 @@
+// Mermaid flowchart 부분 집합: 노드 모양, 연결선(라벨 포함), & 묶음, subgraph, style fill
+use std::collections::HashMap;
+
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::layout::{self, LayoutDirection};
+use crate::types::{nearest_palette_index, CARD_COLORS};
+
+// 노드 모양 여는 기호 → 닫는 기호 (긴 것부터 검사)
+const SHAPES: [(&str, &str); 12] = [
+    ("(((", ")))"),
+    ("((", "))"),
+    ("([", "])"),
+    ("[[", "]]"),
+    ("[(", ")]"),
+    ("[/", "/]"),
+    ("[\\", "\\]"),
+    ("{{", "}}"),
+    (">", "]"),
+    ("(", ")"),
+    ("[", "]"),
+    ("{", "}"),
+];
+
+struct Builder {
+    doc: Document,
+    index_by_id: HashMap<String, usize>,
+    subgraphs: Vec<String>,
+}
+
+impl Builder {
+    fn node(&mut self, id: &str, text: Option<String>) -> usize {
+        let idx = match self.index_by_id.get(id) {
+            Some(&idx) => idx,
+            None => {
+                let color = CARD_COLORS[self.doc.len() % CARD_COLORS.len()];
+                let idx = self.doc.push_card([0.0, 0.0], color, CardKind::Text, id);
+                self.index_by_id.insert(id.to_string(), idx);
+                idx
+            }
+        };
+        if let Some(text) = text {
+            self.doc.cards[idx].text = text;
+        }
+        if let Some(group) = self.subgraphs.last() {
+            if self.doc.cards[idx].group.is_empty() {
+                self.doc.cards[idx].group = group.clone();
+            }
+        }
+        idx
+    }
+}
+
+pub fn parse(text: &str) -> Result<Document, FormatError> {
+    let mut builder = Builder {
+        doc: Document::default(),
+        index_by_id: HashMap::new(),
+        subgraphs: Vec::new(),
+    };
+    let mut direction = None;
+
+    for (line_no, line) in text.lines().enumerate() {
+        let err = |msg: String| FormatError::Parse(format!("{}행: {}", line_no + 1, msg));
+        for stmt in split_statements(line) {
+            let stmt = stmt.trim();
+            if stmt.is_empty() || stmt.starts_with("%%") {
+                continue;
+            }
+            let (keyword, rest) = stmt
+                .split_once(char::is_whitespace)
+                .map(|(k, r)| (k, r.trim()))
+                .unwrap_or((stmt, ""));
+
+            if direction.is_none() {
+                if keyword != "flowchart" && keyword != "graph" {
+                    return Err(err("'flowchart' 또는 'graph' 헤더가 필요합니다".into()));
+                }
+                direction = Some(LayoutDirection::parse(rest).unwrap_or_default());
+                continue;
+            }
+
+            match keyword {
+                "subgraph" => builder.subgraphs.push(subgraph_title(rest)),
+                "end" => {
+                    if builder.subgraphs.pop().is_none() {
+                        return Err(err("짝이 없는 'end'".into()));
+                    }
+                }
+                "direction" | "classDef" | "class" | "click" | "linkStyle" => {}
+                "style" => apply_style(&mut builder, rest),
+                _ => parse_chain(&mut builder, stmt).map_err(err)?,
+            }
+        }
+    }
+
+    if direction.is_none() {
+        return Err(FormatError::Parse("빈 다이어그램".into()));
+    }
+    let mut doc = builder.doc;
+    layout::layered(&mut doc, direction.unwrap_or_default());
+    Ok(doc)
+}
+
+/// 따옴표/괄호 밖의 ';'로 문장 분리
+fn split_statements(line: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_quote = false;
+    let mut start = 0;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quote = !in_quote,
+            '[' | '(' | '{' if !in_quote => depth += 1,
+            ']' | ')' | '}' if !in_quote => depth -= 1,
+            ';' if !in_quote && depth <= 0 => {
+                parts.push(&line[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&line[start..]);
+    parts
+}
+
+fn subgraph_title(rest: &str) -> String {
+    // subgraph id [제목] | subgraph id["제목"] | subgraph 제목
+    if let Some(open) = rest.find('[') {
+        let inner = rest[open + 1..].trim_end().trim_end_matches(']');
+        return unquote(inner).to_string();
+    }
+    unquote(rest).to_string()
+}
+
+fn apply_style(builder: &mut Builder, rest: &str) {
+    let Some((id, props)) = rest.split_once(char::is_whitespace) else {
+        return;
+    };
+    let fill = props
+        .split(',')
+        .filter_map(|p| p.trim().strip_prefix("fill:"))
+        .find_map(parse_css_color);
+    if let (Some(&idx), Some(color)) = (builder.index_by_id.get(id), fill) {
+        builder.doc.block_positions[idx].color = CARD_COLORS[nearest_palette_index(color)];
+    }
+}
+
+/// "#rgb" 또는 "#rrggbb"
+fn parse_css_color(s: &str) -> Option<[f32; 3]> {
+    let s = s.trim();
+    let hex = s.strip_prefix('#')?;
+    if hex.len() == 3 {
+        let expanded: String = hex.chars().flat_map(|c| [c, c]).collect();
+        return parse_hex_color(&format!("#{}", expanded));
+    }
+    parse_hex_color(s)
+}
+
+fn unquote(s: &str) -> &str {
+    let s = s.trim();
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(s)
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// A & B --> C -->|라벨| D -- 라벨 --> E
+fn parse_chain(builder: &mut Builder, stmt: &str) -> Result<(), String> {
+    let mut rest = stmt;
+    let mut previous: Vec<usize> = Vec::new();
+
+    loop {
+        let (group, after) = parse_node_group(builder, rest)?;
+        for &from in &previous {
+            for &to in &group {
+                builder.doc.connect(from, to);
+            }
+        }
+        rest = after.trim_start();
+        if rest.is_empty() {
+            return Ok(());
+        }
+        rest =
+            parse_link(rest).ok_or_else(|| format!("연결선을 해석할 수 없습니다: '{}'", rest))?;
+        previous = group;
+    }
+}
+
+fn parse_node_group<'a>(
+    builder: &mut Builder,
+    s: &'a str,
+) -> Result<(Vec<usize>, &'a str), String> {
+    let mut group = Vec::new();
+    let mut rest = s;
+    loop {
+        let (idx, after) = parse_node(builder, rest.trim_start())?;
+        group.push(idx);
+        let trimmed = after.trim_start();
+        match trimmed.strip_prefix('&') {
+            Some(next) => rest = next,
+            None => return Ok((group, after)),
+        }
+    }
+}
+
+fn parse_node<'a>(builder: &mut Builder, s: &'a str) -> Result<(usize, &'a str), String> {
+    let id_len = s.find(|c: char| !is_id_char(c)).unwrap_or(s.len());
+    if id_len == 0 {
+        return Err(format!("노드 ID가 필요합니다: '{}'", s));
+    }
+    let id = &s[..id_len];
+    let mut rest = &s[id_len..];
+    let mut text = None;
+
+    if let Some((open, close)) = SHAPES.iter().find(|(open, _)| rest.starts_with(open)) {
+        let body = &rest[open.len()..];
+        // 따옴표로 감싼 텍스트는 닫는 따옴표 뒤에서 닫는 기호 검색
+        let search_from = body
+            .strip_prefix('"')
+            .and_then(|quoted| quoted.find('"'))
+            .map_or(0, |i| i + 2);
+        let end = body[search_from..]
+            .find(close)
+            .map(|i| i + search_from)
+            .ok_or_else(|| format!("'{}'의 닫는 '{}'가 없습니다", id, close))?;
+        text = Some(unquote(&body[..end]).to_string());
+        rest = &body[end + close.len()..];
+    }
+
+    // :::클래스 접미사 무시
+    if let Some(after) = rest.strip_prefix(":::") {
+        let len = after.find(|c: char| !is_id_char(c)).unwrap_or(after.len());
+        rest = &after[len..];
+    }
+
+    Ok((builder.node(id, text), rest))
+}
+
+/// 연결선 토큰과 라벨을 건너뛰고 나머지 반환
+fn parse_link(s: &str) -> Option<&str> {
+    let mut rest = s.strip_prefix('<').unwrap_or(s);
+    let len = rest
+        .find(|c: char| !matches!(c, '-' | '=' | '.'))
+        .unwrap_or(rest.len());
+    if len < 2 {
+        return None;
+    }
+    let token = &rest[..len];
+    rest = &rest[len..];
+
+    let has_head = rest.starts_with(['>', 'x', 'o'])
+        && rest[1..].starts_with(|c: char| c.is_whitespace() || c == '|' || c.is_alphanumeric());
+    if has_head {
+        rest = &rest[1..];
+    } else if matches!(token, "--" | "==" | "-.") {
+        // A -- 라벨 --> B 형식: 다음 연결선 토큰까지 라벨
+        let label_end = ["--", "==", ".-"]
+            .iter()
+            .filter_map(|t| rest.find(t))
+            .min()?;
+        return parse_link(&rest[label_end..]);
+    }
+
+    let rest = rest.trim_start();
+    if let Some(label) = rest.strip_prefix('|') {
+        let end = label.find('|')?;
+        return Some(&label[end + 1..]);
+    }
+    Some(rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::fixtures::edge_texts;
+
+    fn texts(doc: &Document) -> Vec<&str> {
+        doc.cards.iter().map(|c| c.text.as_str()).collect()
+    }
+
+    fn edges(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        let mut out: Vec<_> = pairs
+            .iter()
+            .map(|&(a, b)| (a.to_string(), b.to_string()))
+            .collect();
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn reads_node_shapes() {
+        let doc = parse(
+            r#"flowchart TD
+    A[사각] --> B(둥근)
+    B --> C{마름모}
+    C --> D((원))
+    D --> E>깃발]
+    E --> F[(DB)]
+    F --> G{{육각}}
+    G --> H(["경기장"])
+    H --> I["따옴표 [괄호] 포함"]
+    I --> J[[서브루틴]]:::강조
+    J --> K
+"#,
+        )
+        .expect("parses");
+        assert_eq!(
+            texts(&doc),
+            [
+                "사각",
+                "둥근",
+                "마름모",
+                "원",
+                "깃발",
+                "DB",
+                "육각",
+                "경기장",
+                "따옴표 [괄호] 포함",
+                "서브루틴",
+                "K"
+            ]
+        );
+        assert_eq!(doc.edges.len(), 10);
+    }
+
+    #[test]
+    fn chains_ampersands_and_labelled_links() {
+        let doc = parse(
+            "graph TD\n\
+             A --> B --> C\n\
+             C & D --> E & F\n\
+             A -->|예| D\n\
+             B -- 아니오 --> F\n\
+             E -.-> G\n\
+             F ==> G\n",
+        )
+        .expect("parses");
+        assert_eq!(
+            edge_texts(&doc),
+            edges(&[
+                ("A", "B"),
+                ("B", "C"),
+                ("C", "E"),
+                ("C", "F"),
+                ("D", "E"),
+                ("D", "F"),
+                ("A", "D"),
+                ("B", "F"),
+                ("E", "G"),
+                ("F", "G"),
+            ])
+        );
+        // 같은 ID는 한 카드
+        assert_eq!(doc.len(), 7);
+    }
+
+    #[test]
+    fn subgraphs_comments_and_direction() {
+        let doc = parse(
+            r#"%% 머리 주석
+graph LR
+  subgraph 백엔드 [서버 묶음]
+    api --> db
+  end
+  %% 중간 주석
+  web --> api; web --> cdn
+  style web fill:#f00
+"#,
+        )
+        .expect("parses");
+        let group = |text: &str| {
+            let card = doc.cards.iter().find(|c| c.text == text).expect("card");
+            card.group.as_str()
+        };
+        assert_eq!(group("api"), "서버 묶음");
+        assert_eq!(group("db"), "서버 묶음");
+        assert_eq!(group("web"), "");
+        assert_eq!(group("cdn"), "");
+
+        // 왼쪽 → 오른쪽으로 계층이 이어짐
+        let x = |text: &str| {
+            let idx = doc.cards.iter().position(|c| c.text == text).expect("card");
+            doc.block_positions[idx].position[0]
+        };
+        assert!(x("web") < x("api") && x("api") < x("db"));
+        let web = doc
+            .cards
+            .iter()
+            .position(|c| c.text == "web")
+            .expect("card");
+        assert_eq!(
+            doc.block_positions[web].color,
+            CARD_COLORS[nearest_palette_index([1.0, 0.0, 0.0])]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_diagrams() {
+        assert!(parse("").is_err());
+        assert!(parse("A --> B").is_err());
+        assert!(parse("graph TD\nA --> B\nend").is_err());
+        assert!(parse("graph TD\nA[닫히지 않음 --> B").is_err());
+        assert!(parse("graph TD\nA ~~ B").is_err());
+    }
+}
@@ -0,0 +1,421 @@
+pub mod binary;
+pub mod board_text;
+pub mod csv;
+pub mod dot;
+pub mod drawio;
+pub mod excalidraw;
+pub mod json_canvas;
+pub mod mermaid;
+pub mod outline;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use crate::document::{CardKind, Document};
+use crate::transcript::TranscriptEntry;
+use crate::types::{CARD_HALF_H, CARD_HALF_W};
+
+// 외부 포맷의 픽셀 좌표 ↔ 월드 좌표 배율 (카드 0.5 x 0.3 → 250px x 150px)
+pub const PIXELS_PER_UNIT: f32 = 500.0;
+pub const CARD_WIDTH_PX: f32 = CARD_HALF_W * 2.0 * PIXELS_PER_UNIT;
+pub const CARD_HEIGHT_PX: f32 = CARD_HALF_H * 2.0 * PIXELS_PER_UNIT;
+
+// 그룹 경계 상자의 카드 바깥 여백 (px)
+const GROUP_PADDING_PX: f32 = 40.0;
+
+#[derive(Debug)]
+pub enum FormatError {
+    Io(std::io::Error),
+    Parse(String),
+    Unsupported(String),
+    // 행 단위 검증 오류 목록
+    Invalid(Vec<String>),
+}
+
+// 오류 목록을 표시할 때 보여줄 최대 개수
+const MAX_LISTED_ERRORS: usize = 10;
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Io(err) => write!(f, "파일 오류: {}", err),
+            FormatError::Parse(msg) => write!(f, "형식 오류: {}", msg),
+            FormatError::Unsupported(msg) => write!(f, "지원하지 않는 형식: {}", msg),
+            FormatError::Invalid(errors) => {
+                write!(f, "검증 오류 {}건", errors.len())?;
+                for err in errors.iter().take(MAX_LISTED_ERRORS) {
+                    write!(f, "\n{}", err)?;
+                }
+                if errors.len() > MAX_LISTED_ERRORS {
+                    write!(f, "\n외 {}건", errors.len() - MAX_LISTED_ERRORS)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl From<std::io::Error> for FormatError {
+    fn from(err: std::io::Error) -> Self {
+        FormatError::Io(err)
+    }
+}
+
+/// 내보내기 부가 정보 (회의록 제목, 전사문 첨부)
+pub struct ExportOptions<'a> {
+    pub title: &'a str,
+    pub transcript: Option<&'a [TranscriptEntry]>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    JsonCanvas,
+    Mermaid,
+    Dot,
+    Markdown,
+    Opml,
+    Csv,
+    Excalidraw,
+    DrawIo,
+    Binary,
+    BoardText,
+}
+
+impl Format {
+    pub const ALL: [Format; 10] = [
+        Format::JsonCanvas,
+        Format::Mermaid,
+        Format::Dot,
+        Format::Markdown,
+        Format::Opml,
+        Format::Csv,
+        Format::Excalidraw,
+        Format::DrawIo,
+        Format::Binary,
+        Format::BoardText,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Format::JsonCanvas => "JSON Canvas",
+            Format::Mermaid => "Mermaid",
+            Format::Dot => "Graphviz DOT",
+            Format::Markdown => "Markdown",
+            Format::Opml => "OPML",
+            Format::Csv => "CSV",
+            Format::Excalidraw => "Excalidraw",
+            Format::DrawIo => "draw.io",
+            Format::Binary => "바이너리",
+            Format::BoardText => "보드 텍스트 (git)",
+        }
+    }
+
+    /// 대표 확장자가 맨 앞
+    pub fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Format::JsonCanvas => &["canvas"],
+            Format::Mermaid => &["mmd", "mermaid"],
+            Format::Dot => &["dot", "gv"],
+            Format::Markdown => &["md", "markdown"],
+            Format::Opml => &["opml"],
+            Format::Csv => &["csv"],
+            Format::Excalidraw => &["excalidraw"],
+            Format::DrawIo => &["drawio"],
+            Format::Binary => &["wpb"],
+            Format::BoardText => &["board"],
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        self.extensions()[0]
+    }
+
+    pub fn can_export(self) -> bool {
+        !matches!(self, Format::Mermaid | Format::Dot | Format::Opml)
+    }
+
+    /// 좌표 없이 자동 배치되는 포맷 (가져올 때 카메라 중심에 놓음)
+    pub fn is_auto_layout(self) -> bool {
+        matches!(
+            self,
+            Format::Mermaid | Format::Dot | Format::Markdown | Format::Opml
+        )
+    }
+
+    /// 카드 종류, 그룹, 연결, ID까지 모두 담는 포맷 (내보내면 보드를 저장한 것으로 봄)
+    pub fn is_lossless(self) -> bool {
+        matches!(
+            self,
+            Format::JsonCanvas | Format::Binary | Format::BoardText
+        )
+    }
+
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        Format::ALL
+            .into_iter()
+            .find(|f| f.extensions().contains(&ext.as_str()))
+    }
+}
+
+pub fn import_file(path: &Path) -> Result<Document, FormatError> {
+    let format = Format::from_path(path)
+        .ok_or_else(|| FormatError::Unsupported(path.display().to_string()))?;
+    let text = || std::fs::read_to_string(path);
+    match format {
+        Format::JsonCanvas => json_canvas::parse(&text()?),
+        Format::Mermaid => mermaid::parse(&text()?),
+        Format::Dot => dot::parse(&text()?),
+        Format::Markdown => outline::parse_markdown(&text()?),
+        Format::Opml => outline::parse_opml(&text()?),
+        Format::Csv => csv::parse(&text()?),
+        Format::Excalidraw => excalidraw::parse(&text()?),
+        Format::DrawIo => drawio::parse(&text()?),
+        Format::BoardText => board_text::parse(&text()?),
+        Format::Binary => {
+            let file = std::fs::File::open(path)?;
+            binary::read_from(std::io::BufReader::new(file))
+        }
+    }
+}
+
+pub fn export_file(
+    doc: &Document,
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<(), FormatError> {
+    let format = Format::from_path(path)
+        .ok_or_else(|| FormatError::Unsupported(path.display().to_string()))?;
+    let text = match format {
+        Format::JsonCanvas => json_canvas::to_string(doc)?,
+        Format::Markdown => outline::to_markdown(doc, options),
+        Format::Csv => csv::to_string(doc),
+        Format::Excalidraw => excalidraw::to_string(doc)?,
+        Format::DrawIo => drawio::to_string(doc),
+        Format::BoardText => board_text::to_string(doc),
+        Format::Binary => {
+            let file = std::fs::File::create(path)?;
+            return binary::write_to(doc, std::io::BufWriter::new(file));
+        }
+        Format::Mermaid | Format::Dot | Format::Opml => {
+            return Err(FormatError::Unsupported(format!(
+                "{} 내보내기",
+                format.label()
+            )))
+        }
+    };
+    std::fs::write(path, text)?;
+    Ok(())
+}
+
+/// 파일의 노드 ID 문자열 → 카드 ID. 다시 가져와도 ID가 바뀌지 않도록
+/// 내보낸 16자리 16진수는 그대로 읽고, 그 밖의 문자열은 FNV-1a 해시로
+pub fn card_id_from(file_id: &str) -> u64 {
+    if file_id.len() == 16 && file_id.bytes().all(|b| b.is_ascii_hexdigit()) {
+        if let Ok(id) = u64::from_str_radix(file_id, 16) {
+            return id;
+        }
+    }
+    file_id.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// "#rrggbb" → 0~1 RGB
+pub fn parse_hex_color(s: &str) -> Option<[f32; 3]> {
+    let hex = s.trim().strip_prefix('#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let channel = |i: usize| {
+        u8::from_str_radix(hex.get(i..i + 2)?, 16)
+            .ok()
+            .map(|v| v as f32 / 255.0)
+    };
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+/// 0~1 RGB → "#rrggbb"
+pub fn to_hex_color(color: [f32; 3]) -> String {
+    let c = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", c(color[0]), c(color[1]), c(color[2]))
+}
+
+/// 좌상단 기준 픽셀 사각형(y 아래 방향) → 월드 중심 좌표(y 위 방향)
+pub fn rect_px_to_world(x: f32, y: f32, width: f32, height: f32) -> [f32; 2] {
+    [
+        (x + width * 0.5) / PIXELS_PER_UNIT,
+        -(y + height * 0.5) / PIXELS_PER_UNIT,
+    ]
+}
+
+/// 월드 중심 좌표 → 좌상단 기준 픽셀 좌표
+pub fn world_to_rect_px(center: [f32; 2], width: f32, height: f32) -> [f32; 2] {
+    [
+        center[0] * PIXELS_PER_UNIT - width * 0.5,
+        -center[1] * PIXELS_PER_UNIT - height * 0.5,
+    ]
+}
+
+/// 카드의 외부 포맷 사각형 [x, y, width, height] (px, 좌상단 기준).
+/// 그룹 카드는 중심을 유지한 채 멤버 카드를 모두 감싸는 크기
+pub fn card_rect_px(doc: &Document, idx: usize) -> [f32; 4] {
+    let center = doc.block_positions[idx].position;
+    let meta = &doc.cards[idx];
+    let (mut width, mut height) = (CARD_WIDTH_PX, CARD_HEIGHT_PX);
+
+    if meta.kind == CardKind::Group {
+        let members = doc
+            .block_positions
+            .iter()
+            .zip(&doc.cards)
+            .filter(|(_, m)| m.parent == Some(meta.id));
+        for (member, _) in members {
+            let dx = (member.position[0] - center[0]).abs() * PIXELS_PER_UNIT;
+            let dy = (member.position[1] - center[1]).abs() * PIXELS_PER_UNIT;
+            width = width.max((dx + GROUP_PADDING_PX) * 2.0 + CARD_WIDTH_PX);
+            height = height.max((dy + GROUP_PADDING_PX) * 2.0 + CARD_HEIGHT_PX);
+        }
+    }
+
+    let [x, y] = world_to_rect_px(center, width, height);
+    [x, y, width, height]
+}
+
+/// 카드마다 감싸는 그룹 카드의 인덱스. 없는 카드나 그룹이 아닌 카드를 가리키는 참조,
+/// 순환하는 참조는 끊어서 항상 트리가 되게 함
+pub fn group_parents(doc: &Document) -> Vec<Option<usize>> {
+    let index_by_id: HashMap<u64, usize> = doc
+        .cards
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.id, i))
+        .collect();
+    let mut parents: Vec<Option<usize>> = doc
+        .cards
+        .iter()
+        .map(|c| {
+            let p = *index_by_id.get(&c.parent?)?;
+            (doc.cards[p].kind == CardKind::Group).then_some(p)
+        })
+        .collect();
+    for idx in 0..parents.len() {
+        let mut current = parents[idx];
+        for _ in 0..parents.len() {
+            match current {
+                Some(p) if p == idx => {
+                    parents[idx] = None;
+                    break;
+                }
+                Some(p) => current = parents[p],
+                None => break,
+            }
+        }
+    }
+    parents
+}
+
+/// 그룹이 멤버보다 먼저 오는 카드 순서 (같은 깊이끼리는 원래 순서)
+pub fn parents_first(parents: &[Option<usize>]) -> Vec<usize> {
+    let depth = |mut idx: usize| {
+        let mut depth = 0;
+        while let Some(p) = parents[idx] {
+            depth += 1;
+            idx = p;
+        }
+        depth
+    };
+    let mut order: Vec<usize> = (0..parents.len()).collect();
+    order.sort_by_cached_key(|&i| depth(i));
+    order
+}
+
+/// XML 속성/텍스트용 이스케이프
+pub fn xml_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\n' => out.push_str("&#xa;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_lossless_exports_count_as_saved() {
+        let saved =
+            |name: &str| Format::from_path(Path::new(name)).is_some_and(Format::is_lossless);
+        assert!(saved("board.canvas"));
+        assert!(saved("board.wpb"));
+        assert!(saved("board.board"));
+        // 연결, 종류, 그룹, ID를 잃는 포맷으로 내보내도 보드는 저장되지 않은 상태
+        assert!(!saved("board.csv"));
+        assert!(!saved("board.excalidraw"));
+        assert!(!saved("board.drawio"));
+        assert!(!saved("board.md"));
+    }
+}
+
+// 포맷 테스트가 함께 쓰는 문서
+#[cfg(test)]
+pub mod fixtures {
+    use super::*;
+    use crate::types::CARD_COLORS;
+
+    /// 텍스트 기준 (종류, 텍스트, 감싸는 그룹 텍스트) 목록
+    pub fn group_summary(doc: &Document) -> Vec<(CardKind, String, Option<String>)> {
+        let mut out: Vec<_> = doc
+            .cards
+            .iter()
+            .map(|c| {
+                let parent = c
+                    .parent
+                    .and_then(|p| doc.cards.iter().find(|g| g.id == p))
+                    .map(|g| g.text.clone());
+                (c.kind, c.text.clone(), parent)
+            })
+            .collect();
+        out.sort_by(|a, b| a.1.cmp(&b.1));
+        out
+    }
+
+    /// 연결을 (출발 텍스트, 도착 텍스트)로, 정렬해서
+    pub fn edge_texts(doc: &Document) -> Vec<(String, String)> {
+        let mut out: Vec<_> = doc
+            .edges
+            .iter()
+            .map(|e| (doc.cards[e.from].text.clone(), doc.cards[e.to].text.clone()))
+            .collect();
+        out.sort();
+        out
+    }
+
+    /// 그룹 안에 그룹이 있는 문서
+    pub fn grouped_board() -> Document {
+        let mut doc = Document::default();
+        let outer = doc.push_card([0.0, 0.0], CARD_COLORS[0], CardKind::Group, "바깥");
+        let inner = doc.push_card([0.5, 0.0], CARD_COLORS[1], CardKind::Group, "안쪽");
+        let a = doc.push_card([-0.5, 0.0], CARD_COLORS[2], CardKind::Text, "A");
+        let b = doc.push_card([0.5, 0.1], CARD_COLORS[3], CardKind::Text, "B");
+        let c = doc.push_card([3.0, 3.0], CARD_COLORS[4], CardKind::Text, "C");
+        let (outer_id, inner_id) = (doc.cards[outer].id, doc.cards[inner].id);
+        doc.cards[inner].parent = Some(outer_id);
+        doc.cards[a].parent = Some(outer_id);
+        doc.cards[b].parent = Some(inner_id);
+        doc.connect(a, c);
+        doc.connect(b, a);
+        doc
+    }
+}
@@ -0,0 +1,393 @@
+// 중첩 목록(Markdown, OPML) ↔ 카드 트리
+use super::*;
+use crate::document::{CardKind, Document};
+use crate::layout;
+use crate::transcript;
+use crate::types::CARD_COLORS;
+
+// 루트가 여러 개일 때 만드는 가상 루트 제목
+const DEFAULT_ROOT_TITLE: &str = "마인드맵";
+const ROOT_COLOR: usize = 5;
+
+struct OutlineItem {
+    depth: usize,
+    text: String,
+}
+
+pub fn parse_markdown(text: &str) -> Result<Document, FormatError> {
+    let mut items = Vec::new();
+    let mut heading_base = 0;
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let trimmed = line.trim_start();
+
+        let hashes = trimmed.chars().take_while(|&c| c == '#').count();
+        if (1..=6).contains(&hashes) && trimmed[hashes..].starts_with(' ') {
+            items.push(OutlineItem {
+                depth: hashes - 1,
+                text: trimmed[hashes..].trim().to_string(),
+            });
+            heading_base = hashes;
+            continue;
+        }
+
+        // 들여쓰기: 탭 1개 또는 공백 2개당 한 단계
+        let indent: usize = line
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 2 } else { 1 })
+            .sum();
+        let depth = heading_base + indent / 2;
+        let content = strip_list_marker(trimmed);
+        if !content.is_empty() {
+            items.push(OutlineItem {
+                depth,
+                text: content.to_string(),
+            });
+        }
+    }
+
+    build_mind_map(items, DEFAULT_ROOT_TITLE)
+}
+
+fn strip_list_marker(line: &str) -> &str {
+    let rest = if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        rest
+    } else {
+        // 번호 목록 "1. " / "1) "
+        let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+        match line[digits..]
+            .strip_prefix(". ")
+            .or(line[digits..].strip_prefix(") "))
+        {
+            Some(rest) if digits > 0 => rest,
+            _ => line,
+        }
+    };
+    let rest = rest
+        .strip_prefix("[ ] ")
+        .or_else(|| rest.strip_prefix("[x] "))
+        .or_else(|| rest.strip_prefix("[X] "))
+        .unwrap_or(rest);
+    rest.trim()
+}
+
+pub fn parse_opml(text: &str) -> Result<Document, FormatError> {
+    let xml = roxmltree::Document::parse(text).map_err(|e| FormatError::Parse(e.to_string()))?;
+    let root = xml.root_element();
+    if !root.has_tag_name("opml") {
+        return Err(FormatError::Parse("<opml> 루트 요소가 없습니다".into()));
+    }
+
+    let title = root
+        .descendants()
+        .find(|n| n.has_tag_name("title"))
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .unwrap_or(DEFAULT_ROOT_TITLE);
+    let body = root
+        .children()
+        .find(|n| n.has_tag_name("body"))
+        .ok_or_else(|| FormatError::Parse("<body> 요소가 없습니다".into()))?;
+
+    let mut items = Vec::new();
+    let mut stack: Vec<(roxmltree::Node, usize)> = body
+        .children()
+        .filter(|n| n.has_tag_name("outline"))
+        .map(|n| (n, 0))
+        .collect();
+    stack.reverse();
+    while let Some((node, depth)) = stack.pop() {
+        let text = node
+            .attribute("text")
+            .or_else(|| node.attribute("title"))
+            .unwrap_or("");
+        items.push(OutlineItem {
+            depth,
+            text: text.to_string(),
+        });
+        let children: Vec<_> = node
+            .children()
+            .filter(|n| n.has_tag_name("outline"))
+            .collect();
+        stack.extend(children.into_iter().rev().map(|n| (n, depth + 1)));
+    }
+
+    build_mind_map(items, title)
+}
+
+/// 붙여넣은 텍스트가 OPML이면 OPML로, 목록/제목 줄이 있으면 Markdown으로 해석
+pub fn parse_pasted(text: &str) -> Option<Result<Document, FormatError>> {
+    let trimmed = text.trim_start();
+    if trimmed.starts_with("<?xml") || trimmed.starts_with("<opml") {
+        return Some(parse_opml(text));
+    }
+    let is_outline = text.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with("- ") || line.starts_with("* ") || line.starts_with("# ")
+    });
+    is_outline.then(|| parse_markdown(text))
+}
+
+/// 깊이 목록 → 부모-자식 연결 카드 트리 (루트는 원점, 1단계 가지마다 색상 부여)
+fn build_mind_map(items: Vec<OutlineItem>, root_title: &str) -> Result<Document, FormatError> {
+    if items.is_empty() {
+        return Err(FormatError::Parse("목록 항목이 없습니다".into()));
+    }
+
+    let min_depth = items.iter().map(|i| i.depth).min().unwrap_or(0);
+    let root_count = items.iter().filter(|i| i.depth == min_depth).count();
+    let mut doc = Document::default();
+
+    // (깊이, 인덱스) 스택 — 가상 루트는 깊이 0보다 얕은 단계로 취급
+    let mut stack: Vec<(isize, usize)> = Vec::new();
+    if root_count > 1 || items[0].depth != min_depth {
+        let root = doc.push_card(
+            [0.0, 0.0],
+            CARD_COLORS[ROOT_COLOR],
+            CardKind::Text,
+            root_title,
+        );
+        stack.push((-1, root));
+    }
+
+    let mut branch = 0;
+    for item in items {
+        let depth = (item.depth - min_depth) as isize;
+        while stack.last().is_some_and(|&(d, _)| d >= depth) {
+            stack.pop();
+        }
+        let parent = stack.last().map(|&(_, idx)| idx);
+        let color = match parent {
+            None => CARD_COLORS[ROOT_COLOR],
+            Some(_) if stack.len() == 1 => {
+                branch += 1;
+                CARD_COLORS[(branch - 1) % ROOT_COLOR]
+            }
+            Some(p) => doc.block_positions[p].color,
+        };
+        let idx = doc.push_card([0.0, 0.0], color, CardKind::Text, &item.text);
+        if let Some(parent) = parent {
+            doc.connect(parent, idx);
+        }
+        stack.push((depth, idx));
+    }
+
+    layout::mind_map(&mut doc, 0);
+    Ok(doc)
+}
+
+/// 연결 그래프를 루트(들어오는 엣지가 없는 카드)부터 따라가며 중첩 목록으로 출력.
+/// 전사문이 주어지면 화자별로 묶어 뒤에 붙인다.
+pub fn to_markdown(doc: &Document, options: &ExportOptions) -> String {
+    let n = doc.len();
+    let mut children = vec![Vec::new(); n];
+    let mut has_parent = vec![false; n];
+    for edge in &doc.edges {
+        children[edge.from].push(edge.to);
+        has_parent[edge.to] = true;
+    }
+
+    let mut out = format!("# {}\n\n", options.title);
+    let mut visited = vec![false; n];
+    // 루트 우선, 사이클만으로 이뤄진 나머지 카드는 인덱스 순으로 이어서 출력
+    let roots = (0..n)
+        .filter(|&i| !has_parent[i])
+        .chain(0..n)
+        .collect::<Vec<_>>();
+    for root in roots {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![(root, 0usize)];
+        while let Some((node, depth)) = stack.pop() {
+            write_item(&mut out, doc, node, depth);
+            for &child in children[node].iter().rev() {
+                if !visited[child] {
+                    visited[child] = true;
+                    stack.push((child, depth + 1));
+                }
+            }
+        }
+    }
+
+    if let Some(entries) = options.transcript {
+        out.push_str("\n## 전사문\n");
+        for speaker in transcript::speakers(entries) {
+            out.push_str(&format!("\n### {}\n\n", speaker));
+            for entry in entries.iter().filter(|e| e.speaker == speaker) {
+                out.push_str(&format!("- [{}] {}\n", entry.timestamp, entry.text));
+            }
+        }
+    }
+    out
+}
+
+fn write_item(out: &mut String, doc: &Document, idx: usize, depth: usize) {
+    let indent = "  ".repeat(depth);
+    let meta = &doc.cards[idx];
+    let text = if meta.text.trim().is_empty() {
+        format!("카드 {}", meta.id)
+    } else {
+        meta.text.trim().to_string()
+    };
+    let mut lines = text.lines();
+    out.push_str(&format!("{}- {}\n", indent, lines.next().unwrap_or("")));
+    // 여러 줄 텍스트는 목록 항목의 연속 줄로
+    for line in lines {
+        out.push_str(&format!("{}  {}\n", indent, line));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formats::fixtures::edge_texts;
+
+    fn edges(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        let mut out: Vec<_> = pairs
+            .iter()
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+            .collect();
+        out.sort();
+        out
+    }
+
+    #[test]
+    fn indent_jumps_attach_to_the_nearest_shallower_item() {
+        // B는 세 단계 깊지만 A의 자식, C는 다시 A의 자식
+        let doc = parse_markdown("- A\n      - B\n  - C\n\t- D\n").expect("parses");
+        assert_eq!(doc.cards[0].text, "A");
+        assert_eq!(
+            edge_texts(&doc),
+            edges(&[("A", "B"), ("A", "C"), ("A", "D")])
+        );
+    }
+
+    #[test]
+    fn headings_and_several_roots_share_one_root() {
+        let doc =
+            parse_markdown("# 기획\n- 목표\n  1. 첫째\n# 일정\n- [x] 완료\n").expect("parses");
+        assert_eq!(
+            edge_texts(&doc),
+            edges(&[
+                ("마인드맵", "기획"),
+                ("마인드맵", "일정"),
+                ("기획", "목표"),
+                ("목표", "첫째"),
+                ("일정", "완료"),
+            ])
+        );
+    }
+
+    #[test]
+    fn opml_nesting_becomes_edges_under_the_title() {
+        let text = r#"<?xml version="1.0"?>
+<opml version="2.0">
+  <head><title>회의</title></head>
+  <body>
+    <outline text="A">
+      <outline text="B"><outline title="C"/></outline>
+    </outline>
+    <outline text="D"/>
+  </body>
+</opml>"#;
+        let doc = parse_opml(text).expect("parses");
+        assert_eq!(
+            edge_texts(&doc),
+            edges(&[("회의", "A"), ("회의", "D"), ("A", "B"), ("B", "C")])
+        );
+        assert!(matches!(parse_pasted(text), Some(Ok(_))));
+    }
+
+    #[test]
+    fn markdown_export_reads_back_as_the_same_tree() {
+        let mut doc = Document::default();
+        for text in ["계획", "목표", "일정", "세부", "메모"] {
+            doc.push_card([0.0, 0.0], CARD_COLORS[0], CardKind::Text, text);
+        }
+        doc.connect(0, 1);
+        doc.connect(0, 2);
+        doc.connect(2, 3);
+        let options = ExportOptions {
+            title: "회의",
+            transcript: None,
+        };
+        let text = to_markdown(&doc, &options);
+        assert_eq!(
+            text,
+            "# 회의\n\n- 계획\n  - 목표\n  - 일정\n    - 세부\n- 메모\n"
+        );
+
+        // 제목이 루트가 되고 연결 없는 카드도 그 아래에 붙음
+        let back = parse_markdown(&text).expect("parses");
+        let mut expected = edge_texts(&doc);
+        expected.extend(edges(&[("회의", "계획"), ("회의", "메모")]));
+        expected.sort();
+        assert_eq!(edge_texts(&back), expected);
+    }
+
+    #[test]
+    fn minutes_group_the_transcript_by_speaker() {
+        let mut doc = Document::default();
+        doc.push_card([0.0, 0.0], CARD_COLORS[0], CardKind::Text, "안건");
+        let entries = [
+            transcript::TranscriptEntry {
+                speaker: "민지",
+                text: "시작할게요",
+                timestamp: "00:01",
+            },
+            transcript::TranscriptEntry {
+                speaker: "준호",
+                text: "네",
+                timestamp: "00:02",
+            },
+            transcript::TranscriptEntry {
+                speaker: "민지",
+                text: "첫 안건입니다",
+                timestamp: "00:03",
+            },
+        ];
+        let options = ExportOptions {
+            title: "회의록",
+            transcript: Some(&entries),
+        };
+        let text = to_markdown(&doc, &options);
+        let minutes = text.split_once("## 전사문\n").expect("has transcript").1;
+        assert_eq!(
+            minutes,
+            "\n### 민지\n\n- [00:01] 시작할게요\n- [00:03] 첫 안건입니다\n\n### 준호\n\n- [00:02] 네\n"
+        );
+    }
+
+    #[test]
+    fn malformed_outlines_are_parse_errors() {
+        for text in ["", "\n  \n"] {
+            assert!(matches!(parse_markdown(text), Err(FormatError::Parse(_))));
+        }
+        for text in [
+            "<opml><body>",
+            "<root><body><outline text=\"A\"/></body></root>",
+            "<opml><head/></opml>",
+            "<opml><body/></opml>",
+        ] {
+            assert!(
+                matches!(parse_opml(text), Err(FormatError::Parse(_))),
+                "{}",
+                text
+            );
+        }
+        // 목록/제목이 없는 평범한 글은 개요로 보지 않음
+        assert!(parse_pasted("그냥 한 줄 메모").is_none());
+        assert!(matches!(parse_pasted("<opml>"), Some(Err(_))));
+    }
+}
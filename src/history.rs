@@ -0,0 +1,338 @@
+// 이름 붙인 스냅샷과 두 문서 버전 사이의 차이 (카드 ID 기준 비교)
+use std::collections::HashMap;
+
+use crate::document::{CardKind, CardMeta, Document};
+use crate::types::*;
+
+// 이 거리(월드 단위) 미만의 위치 변화는 이동으로 보지 않음
+const MOVE_EPSILON: f32 = 0.01;
+// 화살촉 길이 (월드 단위)와 벌어진 각도
+const ARROW_HEAD_LEN: f32 = 0.08;
+const ARROW_HEAD_ANGLE: f32 = 0.45;
+
+pub const DIFF_ADDED_COLOR: [f32; 3] = [0.13, 0.72, 0.35];
+pub const DIFF_REMOVED_COLOR: [f32; 3] = [0.90, 0.20, 0.20];
+pub const DIFF_MOVED_COLOR: [f32; 3] = [0.23, 0.51, 0.96];
+
+pub struct Snapshot {
+    pub name: String,
+    pub document: Document,
+}
+
+#[derive(Default)]
+pub struct History {
+    pub snapshots: Vec<Snapshot>,
+}
+
+impl History {
+    /// 이름이 비어 있으면 "스냅샷 N"
+    pub fn take(&mut self, name: &str, document: &Document) {
+        let name = match name.trim() {
+            "" => format!("스냅샷 {}", self.snapshots.len() + 1),
+            name => name.to_string(),
+        };
+        self.snapshots.push(Snapshot {
+            name,
+            document: document.clone(),
+        });
+    }
+}
+
+pub struct CardChange {
+    pub label: String,
+    pub position: [f32; 2],
+}
+
+pub struct MovedCard {
+    pub label: String,
+    pub from: [f32; 2],
+    pub to: [f32; 2],
+}
+
+#[derive(Default)]
+pub struct DocumentDiff {
+    pub added: Vec<CardChange>,
+    pub removed: Vec<CardChange>,
+    pub moved: Vec<MovedCard>,
+    // (이전 카드 이름, 이전 텍스트, 새 텍스트)
+    pub retexted: Vec<(String, String, String)>,
+    pub edges_added: usize,
+    pub edges_removed: usize,
+}
+
+fn kind_name(kind: CardKind) -> &'static str {
+    match kind {
+        CardKind::Text => "카드",
+        CardKind::File => "파일",
+        CardKind::Link => "링크",
+        CardKind::Group => "그룹",
+    }
+}
+
+/// 텍스트 첫 줄, 비어 있으면 종류와 위치 (예: "카드 (3.0, 0.0)")
+fn label(meta: &CardMeta, position: [f32; 2]) -> String {
+    let first_line = meta.text.lines().next().unwrap_or("").trim();
+    if first_line.is_empty() {
+        format!(
+            "{} ({:.1}, {:.1})",
+            kind_name(meta.kind),
+            position[0],
+            position[1]
+        )
+    } else {
+        first_line.to_string()
+    }
+}
+
+fn edge_counts(doc: &Document) -> HashMap<(u64, u64), usize> {
+    let mut counts = HashMap::new();
+    for edge in &doc.edges {
+        *counts
+            .entry((doc.cards[edge.from].id, doc.cards[edge.to].id))
+            .or_insert(0) += 1;
+    }
+    counts
+}
+
+impl DocumentDiff {
+    pub fn between(old: &Document, new: &Document) -> Self {
+        let old_by_id: HashMap<u64, usize> = old
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.id, i))
+            .collect();
+        let new_ids: HashMap<u64, usize> = new
+            .cards
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (c.id, i))
+            .collect();
+
+        let mut diff = DocumentDiff::default();
+        for (i, meta) in new.cards.iter().enumerate() {
+            let position = new.block_positions[i].position;
+            let Some(&j) = old_by_id.get(&meta.id) else {
+                diff.added.push(CardChange {
+                    label: label(meta, position),
+                    position,
+                });
+                continue;
+            };
+            let from = old.block_positions[j].position;
+            let dist = ((position[0] - from[0]).powi(2) + (position[1] - from[1]).powi(2)).sqrt();
+            if dist >= MOVE_EPSILON {
+                diff.moved.push(MovedCard {
+                    label: label(meta, position),
+                    from,
+                    to: position,
+                });
+            }
+            if old.cards[j].text != meta.text {
+                diff.retexted.push((
+                    label(&old.cards[j], from),
+                    old.cards[j].text.clone(),
+                    meta.text.clone(),
+                ));
+            }
+        }
+        for (j, meta) in old.cards.iter().enumerate() {
+            if !new_ids.contains_key(&meta.id) {
+                diff.removed.push(CardChange {
+                    label: label(meta, old.block_positions[j].position),
+                    position: old.block_positions[j].position,
+                });
+            }
+        }
+
+        let (old_edges, new_edges) = (edge_counts(old), edge_counts(new));
+        for (key, &count) in &new_edges {
+            diff.edges_added += count.saturating_sub(old_edges.get(key).copied().unwrap_or(0));
+        }
+        for (key, &count) in &old_edges {
+            diff.edges_removed += count.saturating_sub(new_edges.get(key).copied().unwrap_or(0));
+        }
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.moved.is_empty()
+            && self.retexted.is_empty()
+            && self.edges_added == 0
+            && self.edges_removed == 0
+    }
+
+    /// 한 줄 요약 (인스펙터 머리글)
+    pub fn headline(&self) -> String {
+        if self.is_empty() {
+            return "변경 없음".into();
+        }
+        format!(
+            "추가 {} · 삭제 {} · 이동 {} · 텍스트 {} · 연결 +{}/−{}",
+            self.added.len(),
+            self.removed.len(),
+            self.moved.len(),
+            self.retexted.len(),
+            self.edges_added,
+            self.edges_removed
+        )
+    }
+
+    /// 카드별 변경 내역 (최대 limit줄)
+    pub fn details(&self, limit: usize) -> Vec<String> {
+        let added = self.added.iter().map(|c| format!("+ {}", c.label));
+        let removed = self.removed.iter().map(|c| format!("− {}", c.label));
+        let moved = self.moved.iter().map(|m| {
+            format!(
+                "↗ {} ({:.2}, {:.2}) → ({:.2}, {:.2})",
+                m.label, m.from[0], m.from[1], m.to[0], m.to[1]
+            )
+        });
+        let retexted = self
+            .retexted
+            .iter()
+            .map(|(old, _, new)| format!("✎ {}: {}", old, new.lines().next().unwrap_or("")));
+        added
+            .chain(removed)
+            .chain(moved)
+            .chain(retexted)
+            .take(limit)
+            .collect()
+    }
+
+    /// 캔버스 오버레이용 카드 테두리: 추가는 새 위치에 초록, 삭제는 옛 위치에 빨간 잔상
+    pub fn overlay_instances(&self) -> Vec<InstanceRaw> {
+        let added = self.added.iter().map(|c| InstanceRaw {
+            position: c.position,
+            color: DIFF_ADDED_COLOR,
+        });
+        let removed = self.removed.iter().map(|c| InstanceRaw {
+            position: c.position,
+            color: DIFF_REMOVED_COLOR,
+        });
+        added.chain(removed).collect()
+    }
+
+    /// 이동한 카드의 옛 위치 → 새 위치 화살표 (LineList 버텍스)
+    pub fn overlay_lines(&self) -> Vec<Vertex> {
+        let mut verts = Vec::with_capacity(self.moved.len() * 6);
+        let color = DIFF_MOVED_COLOR;
+        for m in &self.moved {
+            let (dx, dy) = (m.to[0] - m.from[0], m.to[1] - m.from[1]);
+            let angle = dy.atan2(dx);
+            verts.push(Vertex {
+                position: m.from,
+                color,
+            });
+            verts.push(Vertex {
+                position: m.to,
+                color,
+            });
+            for side in [-1.0, 1.0] {
+                let a = angle + std::f32::consts::PI + side * ARROW_HEAD_ANGLE;
+                verts.push(Vertex {
+                    position: m.to,
+                    color,
+                });
+                verts.push(Vertex {
+                    position: [
+                        m.to[0] + a.cos() * ARROW_HEAD_LEN,
+                        m.to[1] + a.sin() * ARROW_HEAD_LEN,
+                    ],
+                    color,
+                });
+            }
+        }
+        verts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(cards: &[(u64, [f32; 2], &str)], edges: &[(u64, u64)]) -> Document {
+        let mut doc = Document::default();
+        for &(id, position, text) in cards {
+            let idx = doc.push_card(position, [1.0; 3], CardKind::Text, text);
+            doc.cards[idx].id = id;
+        }
+        let index = |id: u64| doc.cards.iter().position(|c| c.id == id).expect("card");
+        let edges: Vec<(usize, usize)> = edges.iter().map(|&(a, b)| (index(a), index(b))).collect();
+        for (from, to) in edges {
+            doc.connect(from, to);
+        }
+        doc
+    }
+
+    fn old_board() -> Document {
+        board(
+            &[
+                (1, [0.0, 0.0], "A"),
+                (2, [1.0, 0.0], "B"),
+                (3, [2.0, 0.0], "C"),
+                (4, [3.0, 0.0], ""),
+            ],
+            &[(1, 2), (2, 3), (3, 4)],
+        )
+    }
+
+    #[test]
+    fn same_document_has_no_changes() {
+        let old = old_board();
+        let diff = DocumentDiff::between(&old, &old.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.headline(), "변경 없음");
+    }
+
+    #[test]
+    fn counts_each_kind_of_change_by_card_id() {
+        // 순서가 바뀌어도 ID로 비교, B는 기준보다 조금만 움직여 이동 아님
+        let new = board(
+            &[
+                (3, [2.0, 0.0], "C 수정"),
+                (1, [0.5, 0.0], "A"),
+                (2, [1.005, 0.0], "B"),
+                (5, [4.0, 0.0], "새 카드"),
+            ],
+            &[(1, 2), (1, 2), (3, 5)],
+        );
+        let diff = DocumentDiff::between(&old_board(), &new);
+
+        let labels = |changes: &[CardChange]| -> Vec<String> {
+            changes.iter().map(|c| c.label.clone()).collect()
+        };
+        assert_eq!(labels(&diff.added), ["새 카드"]);
+        // 텍스트가 없는 카드는 종류와 위치로 표시
+        assert_eq!(labels(&diff.removed), ["카드 (3.0, 0.0)"]);
+        assert_eq!(diff.removed[0].position, [3.0, 0.0]);
+        assert_eq!(diff.moved.len(), 1);
+        assert_eq!(
+            (
+                diff.moved[0].label.as_str(),
+                diff.moved[0].from,
+                diff.moved[0].to
+            ),
+            ("A", [0.0, 0.0], [0.5, 0.0])
+        );
+        assert_eq!(
+            diff.retexted,
+            [("C".to_string(), "C".to_string(), "C 수정".to_string())]
+        );
+        // 같은 연결이 하나 더 생긴 것도 추가, 지운 카드에 걸린 연결도 삭제로 셈
+        assert_eq!((diff.edges_added, diff.edges_removed), (2, 2));
+        assert_eq!(
+            diff.headline(),
+            "추가 1 · 삭제 1 · 이동 1 · 텍스트 1 · 연결 +2/−2"
+        );
+        assert_eq!(diff.details(2), ["+ 새 카드", "− 카드 (3.0, 0.0)"]);
+        assert_eq!(
+            diff.details(10).last().map(String::as_str),
+            Some("✎ C: C 수정")
+        );
+        assert_eq!(diff.overlay_instances().len(), 2);
+        assert_eq!(diff.overlay_lines().len(), 6);
+    }
+}
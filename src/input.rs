@@ -1,31 +1,105 @@
 use winit::dpi::PhysicalSize;
 use winit::event::MouseScrollDelta;
-use winit::keyboard::KeyCode;
+use winit::keyboard::{KeyCode, ModifiersState};
 
+use crate::camera::Easing;
+use crate::camera_motion::CAMERA_ZOOM_DURATION;
+use crate::journal::Operation;
+use crate::selection::{self, SelectionMode};
+use crate::settings::WheelAction;
+use crate::snapping::{self, SnapOptions};
 use crate::state::{AppState, InteractionMode};
 use crate::types::*;
+use crate::ui::context_menu::{ContextMenu, ContextTarget};
+
+// 휠 한 칸을 화면 이동량으로 바꿀 때의 px
+const PIXELS_PER_WHEEL_LINE: f32 = 50.0;
+// 올가미 점을 새로 기록하는 최소 커서 이동 (px)
+const LASSO_MIN_STEP: f32 = 4.0;
+// 카드를 옮길 때 이 거리(px) 안의 정렬/간격/격자에 맞춤
+const SNAP_DISTANCE: f32 = 6.0;
 
 impl AppState {
-    fn is_pointer_in_canvas(&self) -> bool {
+    /// 커서가 캔버스 위에 있고 툴바/메뉴 같은 egui 오버레이에 가려지지 않았는지
+    pub fn is_pointer_in_canvas(&self) -> bool {
         let canvas = self.egui.canvas_rect;
         let [mx, my] = self.mouse_pixel;
+        let over_overlay = self
+            .egui
+            .ctx
+            .layer_id_at(egui::pos2(mx, my))
+            .is_some_and(|layer| layer.order != egui::Order::Background);
         canvas.width() > 0.0
             && canvas.height() > 0.0
             && mx >= canvas.min.x
             && mx <= canvas.max.x
             && my >= canvas.min.y
             && my <= canvas.max.y
+            && !over_overlay
+    }
+
+    /// 커서 아래 맨 위에 그려진 카드
+    fn card_at_cursor(&self) -> Option<usize> {
+        self.card_at(self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect()))
+    }
+
+    /// 월드 좌표 아래 맨 위에 그려진 카드
+    pub fn card_at(&self, world: [f32; 2]) -> Option<usize> {
+        self.document.block_positions.iter().rposition(|pos| {
+            (pos.position[0] - world[0]).abs() < CARD_HALF_W
+                && (pos.position[1] - world[1]).abs() < CARD_HALF_H
+        })
+    }
+
+    fn begin_pan(&mut self, middle_button: bool) {
+        self.pan_samples.clear();
+        self.record_pan_sample();
+        self.interaction = InteractionMode::Panning {
+            start_ndc: self.mouse_ndc,
+            start_camera: self.camera.position,
+            middle_button,
+        };
     }
 
     pub fn handle_keyboard(&mut self, key: KeyCode, pressed: bool) {
+        // 텍스트 입력 중에는 캔버스 단축키 무시
+        if pressed && self.egui.ctx.wants_keyboard_input() {
+            return;
+        }
+
         if key == KeyCode::Space {
             self.space_pressed = pressed;
-            if !pressed {
-                if matches!(self.interaction, InteractionMode::Panning { .. }) {
-                    self.interaction = InteractionMode::Idle;
+            // 가운데 버튼으로 시작한 이동은 Space와 상관없음
+            let space_pan = matches!(
+                self.interaction,
+                InteractionMode::Panning {
+                    middle_button: false,
+                    ..
                 }
+            );
+            if !pressed && space_pan {
+                self.interaction = InteractionMode::Idle;
             }
         }
+
+        if !pressed {
+            return;
+        }
+        // 열린 우클릭 메뉴가 있으면 Esc는 메뉴만 닫음
+        if key == KeyCode::Escape && self.context_menu_state.open.is_some() {
+            self.context_menu_state.open = None;
+            self.window.request_redraw();
+            return;
+        }
+        self.handle_shortcut(key);
+    }
+
+    /// 옮기는 중 Alt를 누르거나 떼면 커서가 멈춰 있어도 맞춤을 바로 다시 계산
+    pub fn handle_modifiers(&mut self, modifiers: ModifiersState) {
+        self.modifiers = modifiers;
+        if matches!(self.interaction, InteractionMode::MovingSelection { .. }) {
+            self.drag_selection();
+        }
     }
 
     pub fn handle_cursor_moved(&mut self, x: f64, y: f64) {
@@ -55,121 +129,329 @@ impl AppState {
             InteractionMode::Panning {
                 start_ndc,
                 start_camera,
+                ..
             } => {
                 let aspect = self.canvas_aspect();
                 let dx_ndc = self.mouse_ndc[0] - start_ndc[0];
                 let dy_ndc = self.mouse_ndc[1] - start_ndc[1];
                 self.camera.position[0] = start_camera[0] - dx_ndc * aspect / self.camera.zoom;
                 self.camera.position[1] = start_camera[1] - dy_ndc / self.camera.zoom;
+                self.record_pan_sample();
                 self.update_camera_buffer();
                 self.window.request_redraw();
             }
-            InteractionMode::DragSelecting { start, .. } => {
+            InteractionMode::DragSelecting { start, mode, .. } => {
                 let world = self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect());
-                let start = *start;
                 self.interaction = InteractionMode::DragSelecting {
-                    start,
+                    start: *start,
                     end: world,
+                    mode: *mode,
                 };
                 self.window.request_redraw();
             }
-            InteractionMode::MovingSelection { last_world } => {
+            InteractionMode::Lasso { points, .. } => {
                 let world = self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect());
-                let dx = world[0] - last_world[0];
-                let dy = world[1] - last_world[1];
-                for &idx in &self.selected_indices {
-                    if idx < self.block_positions.len() {
-                        self.block_positions[idx].position[0] += dx;
-                        self.block_positions[idx].position[1] += dy;
+                let min_step =
+                    LASSO_MIN_STEP / (self.camera.zoom * self.egui.canvas_rect.height() * 0.5);
+                let far_enough = points
+                    .last()
+                    .is_none_or(|last| (world[0] - last[0]).hypot(world[1] - last[1]) >= min_step);
+                if far_enough {
+                    if let InteractionMode::Lasso { points, .. } = &mut self.interaction {
+                        points.push(world);
                     }
+                    self.window.request_redraw();
                 }
-                self.interaction = InteractionMode::MovingSelection { last_world: world };
-                self.mark_positions_dirty();
-                self.window.request_redraw();
             }
+            InteractionMode::MovingSelection { .. } => self.drag_selection(),
             InteractionMode::Idle => {}
         }
     }
 
     pub fn handle_mouse_button(&mut self, pressed: bool) {
-        if pressed && !self.is_pointer_in_canvas() {
+        // 가운데 버튼으로 이동 중이면 무시
+        if pressed
+            && (!self.is_pointer_in_canvas()
+                || !matches!(self.interaction, InteractionMode::Idle))
+        {
             return;
         }
 
         if pressed {
+            // 새 입력은 진행 중인 관성 이동을 멈춤
+            self.stop_camera_animation();
             if self.space_pressed {
-                self.interaction = InteractionMode::Panning {
-                    start_ndc: self.mouse_ndc,
-                    start_camera: self.camera.position,
+                self.begin_pan(false);
+            } else if self.modifiers.alt_key() {
+                // Alt+드래그 → 올가미 선택 (Shift/Ctrl 조합은 사각형 선택과 같음)
+                let mode = SelectionMode::from_modifiers(self.modifiers);
+                if mode == SelectionMode::Replace {
+                    self.selected_indices.clear();
+                }
+                let start = self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect());
+                self.interaction = InteractionMode::Lasso {
+                    points: vec![start],
+                    mode,
                 };
+                self.window.request_redraw();
             } else {
                 let mouse_world = self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect());
+                let mode = SelectionMode::from_modifiers(self.modifiers);
 
                 // 클릭한 위치에 노드가 있는지 확인
-                let clicked_node = self.block_positions.iter().position(|pos| {
-                    let dx = (pos.position[0] - mouse_world[0]).abs();
-                    let dy = (pos.position[1] - mouse_world[1]).abs();
-                    dx < CARD_HALF_W && dy < CARD_HALF_H
-                });
-
-                if let Some(idx) = clicked_node {
-                    if !self.selected_indices.contains(&idx) {
-                        self.selected_indices.clear();
-                        self.selected_indices.push(idx);
+                if let Some(idx) = self.card_at_cursor() {
+                    let was_selected = self.selected_indices.contains(&idx);
+                    match mode {
+                        // 이미 선택된 카드를 누르면 선택 전체를 끌어 옮김
+                        SelectionMode::Replace if was_selected => {}
+                        _ => selection::combine(&mut self.selected_indices, &[idx], mode),
+                    }
+                    // Ctrl로 선택을 해제한 카드는 옮기지 않음
+                    if self.selected_indices.contains(&idx) {
+                        self.begin_move(idx, mouse_world);
                     }
-                    self.interaction = InteractionMode::MovingSelection {
-                        last_world: mouse_world,
-                    };
                 } else {
-                    // 빈 공간 클릭 → 드래그 선택 시작
-                    self.selected_indices.clear();
+                    // 빈 공간 클릭 → 드래그 선택 시작 (수식키가 있으면 기존 선택 유지)
+                    if mode == SelectionMode::Replace {
+                        self.selected_indices.clear();
+                    }
                     self.interaction = InteractionMode::DragSelecting {
                         start: mouse_world,
                         end: mouse_world,
+                        mode,
                     };
                 }
 
                 self.window.request_redraw();
             }
         } else {
-            if let InteractionMode::DragSelecting { start, end } = &self.interaction {
-                // 드래그 선택 완료 → 사각형 안의 노드 전부 선택
-                let min_x = start[0].min(end[0]);
-                let max_x = start[0].max(end[0]);
-                let min_y = start[1].min(end[1]);
-                let max_y = start[1].max(end[1]);
-
-                self.selected_indices.clear();
-                for (i, pos) in self.block_positions.iter().enumerate() {
-                    let [px, py] = pos.position;
-                    if px >= min_x && px <= max_x && py >= min_y && py <= max_y {
-                        self.selected_indices.push(i);
-                    }
+            // 가운데 버튼으로 이동 중이면 그 버튼을 놓을 때까지 유지
+            if matches!(
+                self.interaction,
+                InteractionMode::Panning {
+                    middle_button: true,
+                    ..
                 }
+            ) {
+                return;
+            }
+
+            if let InteractionMode::DragSelecting { start, end, mode } = self.interaction {
+                // 드래그 선택 완료 → 사각형에 걸린 카드를 기존 선택과 합성
+                let hits = selection::marquee_hits(&self.document, start, end);
+                selection::combine(&mut self.selected_indices, &hits, mode);
+                self.window.request_redraw();
+            }
+
+            if let InteractionMode::Lasso { points, mode } = &self.interaction {
+                let hits = selection::lasso_hits(&self.document, points);
+                selection::combine(&mut self.selected_indices, &hits, *mode);
                 self.window.request_redraw();
             }
 
+            if matches!(self.interaction, InteractionMode::Panning { .. }) {
+                self.release_pan();
+            }
+
+            if let InteractionMode::MovingSelection { origins, .. } = &self.interaction {
+                self.snap_guides.clear();
+                // 누르기만 하고 놓은 클릭은 기록하지 않음 (보드도 바뀌지 않은 상태로 유지)
+                let positions = &self.document.block_positions;
+                let moved = self
+                    .selected_indices
+                    .iter()
+                    .filter_map(|&i| positions.get(i))
+                    .zip(origins)
+                    .any(|(instance, origin)| instance.position != *origin);
+                if moved {
+                    let op = Operation::positions(&self.document, &self.selected_indices);
+                    self.record_operation(op);
+                }
+            }
+
             self.interaction = InteractionMode::Idle;
         }
     }
 
-    pub fn handle_scroll(&mut self, delta: MouseScrollDelta) {
-        if !self.is_pointer_in_canvas() {
+    /// 가운데 버튼 끌기는 Space 없이 바로 화면 이동
+    pub fn handle_middle_button(&mut self, pressed: bool) {
+        if pressed {
+            if self.is_pointer_in_canvas() && matches!(self.interaction, InteractionMode::Idle) {
+                self.stop_camera_animation();
+                self.begin_pan(true);
+            }
+        } else if matches!(
+            self.interaction,
+            InteractionMode::Panning {
+                middle_button: true,
+                ..
+            }
+        ) {
+            self.release_pan();
+            self.interaction = InteractionMode::Idle;
+        }
+    }
+
+    /// 우클릭: 카드 위면 그 카드(선택에 없으면 단독 선택) 메뉴, 빈 공간이면 캔버스 메뉴
+    pub fn handle_right_button(&mut self, pressed: bool) {
+        if !pressed
+            || !self.is_pointer_in_canvas()
+            || !matches!(self.interaction, InteractionMode::Idle)
+        {
             return;
         }
+        self.stop_camera_animation();
+        let target = match self.card_at_cursor() {
+            Some(index) => {
+                if !self.selected_indices.contains(&index) {
+                    self.selected_indices = vec![index];
+                }
+                ContextTarget::Card { index }
+            }
+            None => ContextTarget::Canvas,
+        };
+        let [x, y] = self.mouse_pixel;
+        self.context_menu_state.open = Some(ContextMenu {
+            screen: egui::pos2(x, y),
+            world: self.camera.ndc_to_world(self.mouse_ndc, self.canvas_aspect()),
+            target,
+        });
+        self.window.request_redraw();
+    }
 
-        let scroll_y = match delta {
-            MouseScrollDelta::LineDelta(_, y) => y,
-            MouseScrollDelta::PixelDelta(pos) => pos.y as f32 / 50.0,
+    /// grabbed 카드를 잡고 선택 전체를 옮기기 시작
+    fn begin_move(&mut self, grabbed: usize, grab_world: [f32; 2]) {
+        let positions = &self.document.block_positions;
+        let mut moving = vec![false; positions.len()];
+        let mut origins = Vec::with_capacity(self.selected_indices.len());
+        for &idx in &self.selected_indices {
+            if let Some(instance) = positions.get(idx) {
+                moving[idx] = true;
+                origins.push(instance.position);
+            }
+        }
+        self.interaction = InteractionMode::MovingSelection {
+            grab_world,
+            origins,
+            anchor: positions[grabbed].position,
+            moving,
         };
+    }
+
+    /// 커서를 따라 선택을 옮김. 설정에 따라 주변 카드/격자에 맞추고, Alt를 누르면 맞춤 없이
+    fn drag_selection(&mut self) {
+        let InteractionMode::MovingSelection {
+            grab_world,
+            origins,
+            anchor,
+            moving,
+        } = &self.interaction
+        else {
+            return;
+        };
+        let aspect = self.canvas_aspect();
+        let world = self.camera.ndc_to_world(self.mouse_ndc, aspect);
+        let raw_delta = [world[0] - grab_world[0], world[1] - grab_world[1]];
+
+        let free = self.modifiers.alt_key();
+        let scale = self.camera.zoom * self.egui.canvas_rect.height().max(1.0) * 0.5;
+        let options = SnapOptions {
+            threshold: SNAP_DISTANCE / scale,
+            guides: self.settings.alignment_guides && !free,
+            grid: (self.settings.snap_to_grid && !free).then_some(self.settings.grid_size),
+            view: (
+                self.camera.ndc_to_world([-1.0, -1.0], aspect),
+                self.camera.ndc_to_world([1.0, 1.0], aspect),
+            ),
+        };
+        let snap = snapping::snap_move(
+            &self.document,
+            moving,
+            origins,
+            *anchor,
+            raw_delta,
+            &options,
+        );
+
+        for (&idx, origin) in self.selected_indices.iter().zip(origins) {
+            if let Some(instance) = self.document.block_positions.get_mut(idx) {
+                instance.position = [origin[0] + snap.delta[0], origin[1] + snap.delta[1]];
+            }
+        }
+        self.snap_guides = snap.guides;
+        self.mark_positions_dirty();
+        self.window.request_redraw();
+    }
+
+    /// 화면 이동량(논리 px)만큼 캔버스를 끌어당기듯 이동
+    fn pan_by_pixels(&mut self, dx: f32, dy: f32) {
+        self.stop_camera_animation();
+        // 월드 1단위당 화면 px (가로세로 같음)
+        let scale = self.camera.zoom * self.egui.canvas_rect.height().max(1.0) * 0.5;
+        self.camera.position[0] -= dx / scale;
+        self.camera.position[1] += dy / scale;
+        self.update_camera_buffer();
+        self.window.request_redraw();
+    }
 
-        let factor = if scroll_y > 0.0 { 1.1 } else { 1.0 / 1.1 };
+    /// 커서 위치를 고정한 채 즉시 확대/축소 (트랙패드처럼 입력이 잦고 연속적인 경우)
+    fn zoom_at_cursor(&mut self, factor: f32) {
+        self.stop_camera_animation();
         self.camera
             .zoom_at(self.mouse_ndc, factor, self.canvas_aspect());
         self.update_camera_buffer();
         self.window.request_redraw();
     }
 
+    /// 휠(줄 단위)은 설정에 따라 확대 또는 이동, 트랙패드(픽셀 단위)는 이동.
+    /// Ctrl/Cmd를 누르고 있으면 어느 쪽이든 확대 (다른 플랫폼의 핀치도 이렇게 들어옴)
+    pub fn handle_scroll(&mut self, delta: MouseScrollDelta) {
+        if !self.is_pointer_in_canvas() {
+            return;
+        }
+
+        let step = self.settings.zoom_step;
+        let zoom_modifier = self.modifiers.control_key() || self.modifiers.super_key();
+        match delta {
+            MouseScrollDelta::LineDelta(x, y)
+                if zoom_modifier || self.settings.wheel_action == WheelAction::Zoom =>
+            {
+                // 휠 한 칸은 짧은 전환으로 부드럽게. 연속 입력은 진행 중인 목표에 누적
+                let lines = if y != 0.0 { y } else { x };
+                let factor = if lines > 0.0 { step } else { 1.0 / step };
+                let mut target = self.camera_target();
+                target.zoom_at(self.mouse_ndc, factor, self.canvas_aspect());
+                self.animate_camera_to(target, CAMERA_ZOOM_DURATION, Easing::EaseOutCubic);
+            }
+            MouseScrollDelta::LineDelta(x, y) => {
+                // Shift+휠은 가로 이동
+                let (dx, dy) = if self.modifiers.shift_key() {
+                    (y, x)
+                } else {
+                    (x, y)
+                };
+                self.pan_by_pixels(dx * PIXELS_PER_WHEEL_LINE, dy * PIXELS_PER_WHEEL_LINE);
+            }
+            MouseScrollDelta::PixelDelta(pos) => {
+                let scale = self.window.scale_factor();
+                let (dx, dy) = ((pos.x / scale) as f32, (pos.y / scale) as f32);
+                if zoom_modifier {
+                    self.zoom_at_cursor(step.powf(dy / PIXELS_PER_WHEEL_LINE));
+                } else {
+                    self.pan_by_pixels(dx, dy);
+                }
+            }
+        }
+    }
+
+    /// 트랙패드 핀치 (delta > 0이면 확대)
+    pub fn handle_pinch(&mut self, delta: f64) {
+        if !self.is_pointer_in_canvas() {
+            return;
+        }
+        self.zoom_at_cursor((delta as f32).exp());
+    }
+
     pub fn handle_resize(&mut self, new_size: PhysicalSize<u32>) {
         if new_size.width > 0 && new_size.height > 0 {
             self.config.width = new_size.width;
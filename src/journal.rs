@@ -0,0 +1,634 @@
+// 자동 저장: 추가 전용 작업 저널 + 주기적 스냅샷 (백그라운드 스레드에서 기록)
+//
+// 저널 레코드: [u32 길이][u32 FNV-1a 체크섬][페이로드]. 복구 시 잘리거나 손상된
+// 첫 레코드에서 재생을 멈추므로, 기록 도중 종료돼도 그 앞까지는 살릴 수 있다.
+// 스냅샷과 저널은 맨 앞에 같은 세대 번호(u64)를 기록한다. 스냅샷 교체 직후 저널을
+// 비우기 전에 종료되면 세대가 어긋나므로, 이미 스냅샷에 반영된 저널은 재생하지 않는다.
+// 보드마다 자동 저장 디렉터리 아래 자기 디렉터리에 따로 기록하고, 복구할 때 탭 이름도 되살린다.
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::document::{Document, Edge};
+use crate::formats::{binary, FormatError};
+
+const SNAPSHOT_FILE: &str = "snapshot.bin";
+const JOURNAL_FILE: &str = "journal.log";
+const NAME_FILE: &str = "name.txt";
+
+// 스냅샷 주기: 작업 수 또는 경과 시간 중 먼저 도달하는 쪽
+const SNAPSHOT_EVERY_OPS: usize = 200;
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60);
+
+const TAG_CLEAR: u8 = 0;
+const TAG_APPEND: u8 = 1;
+const TAG_SET_POSITIONS: u8 = 2;
+const TAG_REMOVE: u8 = 3;
+const TAG_SET_COLORS: u8 = 4;
+const TAG_RAISE: u8 = 5;
+
+/// 문서 변경 단위. 재생하면 같은 순서로 같은 결과가 나온다
+pub enum Operation {
+    Clear,
+    // 끝에 추가된 카드와 엣지 (엣지는 전체 문서 인덱스 기준)
+    Append { cards: Document, edges: Vec<Edge> },
+    SetPositions(Vec<(usize, [f32; 2])>),
+    // 삭제한 카드 (삭제 전 인덱스). 연결된 엣지도 함께 삭제
+    Remove(Vec<usize>),
+    SetColors(Vec<(usize, [f32; 3])>),
+    // 맨 앞으로 가져온 카드 (이동 전 인덱스)
+    Raise(Vec<usize>),
+}
+
+impl Operation {
+    /// card_start / edge_start 이후에 추가된 카드와 엣지
+    pub fn appended(doc: &Document, card_start: usize, edge_start: usize) -> Self {
+        Operation::Append {
+            cards: Document {
+                block_positions: doc.block_positions[card_start..].to_vec(),
+                cards: doc.cards[card_start..].to_vec(),
+                edges: Vec::new(),
+            },
+            edges: doc.edges[edge_start..].to_vec(),
+        }
+    }
+
+    pub fn positions(doc: &Document, indices: &[usize]) -> Self {
+        Operation::SetPositions(
+            indices
+                .iter()
+                .filter_map(|&i| Some((i, doc.block_positions.get(i)?.position)))
+                .collect(),
+        )
+    }
+
+    pub fn colors(doc: &Document, indices: &[usize]) -> Self {
+        Operation::SetColors(
+            indices
+                .iter()
+                .filter_map(|&i| Some((i, doc.block_positions.get(i)?.color)))
+                .collect(),
+        )
+    }
+
+    fn encode(&self) -> Result<Vec<u8>, FormatError> {
+        let mut out = Vec::new();
+        match self {
+            Operation::Clear => out.push(TAG_CLEAR),
+            Operation::Append { cards, edges } => {
+                out.push(TAG_APPEND);
+                binary::write_to(cards, &mut out)?;
+                out.extend_from_slice(&(edges.len() as u32).to_le_bytes());
+                for edge in edges {
+                    out.extend_from_slice(&(edge.from as u32).to_le_bytes());
+                    out.extend_from_slice(&(edge.to as u32).to_le_bytes());
+                }
+            }
+            Operation::SetPositions(moves) => {
+                out.push(TAG_SET_POSITIONS);
+                out.extend_from_slice(&(moves.len() as u32).to_le_bytes());
+                for (idx, [x, y]) in moves {
+                    out.extend_from_slice(&(*idx as u32).to_le_bytes());
+                    out.extend_from_slice(&x.to_le_bytes());
+                    out.extend_from_slice(&y.to_le_bytes());
+                }
+            }
+            Operation::Remove(indices) => {
+                out.push(TAG_REMOVE);
+                write_indices(&mut out, indices);
+            }
+            Operation::Raise(indices) => {
+                out.push(TAG_RAISE);
+                write_indices(&mut out, indices);
+            }
+            Operation::SetColors(colors) => {
+                out.push(TAG_SET_COLORS);
+                out.extend_from_slice(&(colors.len() as u32).to_le_bytes());
+                for (idx, rgb) in colors {
+                    out.extend_from_slice(&(*idx as u32).to_le_bytes());
+                    for channel in rgb {
+                        out.extend_from_slice(&channel.to_le_bytes());
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    fn decode(mut payload: &[u8]) -> Option<Self> {
+        let mut tag = [0u8];
+        payload.read_exact(&mut tag).ok()?;
+        let op = match tag[0] {
+            TAG_CLEAR => Operation::Clear,
+            TAG_APPEND => {
+                let cards = binary::read_from(&mut payload).ok()?;
+                let count = read_u32(&mut payload)?;
+                let mut edges = Vec::new();
+                for _ in 0..count {
+                    let from = read_u32(&mut payload)? as usize;
+                    let to = read_u32(&mut payload)? as usize;
+                    edges.push(Edge { from, to });
+                }
+                Operation::Append { cards, edges }
+            }
+            TAG_SET_POSITIONS => {
+                let count = read_u32(&mut payload)?;
+                let mut moves = Vec::new();
+                for _ in 0..count {
+                    let idx = read_u32(&mut payload)? as usize;
+                    let x = f32::from_bits(read_u32(&mut payload)?);
+                    let y = f32::from_bits(read_u32(&mut payload)?);
+                    moves.push((idx, [x, y]));
+                }
+                Operation::SetPositions(moves)
+            }
+            TAG_REMOVE => Operation::Remove(read_indices(&mut payload)?),
+            TAG_RAISE => Operation::Raise(read_indices(&mut payload)?),
+            TAG_SET_COLORS => {
+                let count = read_u32(&mut payload)?;
+                let mut colors = Vec::new();
+                for _ in 0..count {
+                    let idx = read_u32(&mut payload)? as usize;
+                    let mut rgb = [0.0; 3];
+                    for channel in &mut rgb {
+                        *channel = f32::from_bits(read_u32(&mut payload)?);
+                    }
+                    colors.push((idx, rgb));
+                }
+                Operation::SetColors(colors)
+            }
+            _ => return None,
+        };
+        payload.is_empty().then_some(op)
+    }
+
+    /// 문서에 적용. 범위를 벗어나는 인덱스는 무시
+    pub fn apply(self, doc: &mut Document) {
+        match self {
+            Operation::Clear => doc.clear(),
+            Operation::Append { cards, edges } => {
+                doc.block_positions.extend(cards.block_positions);
+                doc.cards.extend(cards.cards);
+                for edge in edges {
+                    doc.connect(edge.from, edge.to);
+                }
+            }
+            Operation::SetPositions(moves) => {
+                for (idx, position) in moves {
+                    if let Some(instance) = doc.block_positions.get_mut(idx) {
+                        instance.position = position;
+                    }
+                }
+            }
+            Operation::Remove(indices) => doc.remove_cards(&indices),
+            Operation::SetColors(colors) => {
+                for (idx, color) in colors {
+                    if let Some(instance) = doc.block_positions.get_mut(idx) {
+                        instance.color = color;
+                    }
+                }
+            }
+            Operation::Raise(indices) => doc.raise(&indices),
+        }
+    }
+}
+
+fn read_u32(reader: &mut &[u8]) -> Option<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes).ok()?;
+    Some(u32::from_le_bytes(bytes))
+}
+
+fn write_indices(out: &mut Vec<u8>, indices: &[usize]) {
+    out.extend_from_slice(&(indices.len() as u32).to_le_bytes());
+    for &idx in indices {
+        out.extend_from_slice(&(idx as u32).to_le_bytes());
+    }
+}
+
+fn read_indices(reader: &mut &[u8]) -> Option<Vec<usize>> {
+    let count = read_u32(reader)?;
+    (0..count)
+        .map(|_| read_u32(reader).map(|idx| idx as usize))
+        .collect()
+}
+
+fn read_generation<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811c_9dc5, |hash, &b| {
+        (hash ^ b as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+fn frame(payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 8);
+    out.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    out.extend_from_slice(&checksum(payload).to_le_bytes());
+    out.extend_from_slice(payload);
+    out
+}
+
+/// 저널 바이트를 문서에 재생. 온전히 적용된 작업 수 반환
+pub fn replay(doc: &mut Document, mut journal: &[u8]) -> usize {
+    let mut applied = 0;
+    while let (Some(len), Some(sum)) = (read_u32(&mut journal), read_u32(&mut journal)) {
+        let Some(payload) = journal.get(..len as usize) else {
+            break;
+        };
+        if checksum(payload) != sum {
+            break;
+        }
+        let Some(op) = Operation::decode(payload) else {
+            break;
+        };
+        op.apply(doc);
+        applied += 1;
+        journal = &journal[len as usize..];
+    }
+    applied
+}
+
+/// 이전 세션이 정상 종료되지 않아 남은 자동 저장 파일 (보드 하나)
+pub struct Recovery {
+    pub dir: PathBuf,
+    // 기록할 때의 탭 이름
+    pub name: Option<String>,
+    pub snapshot_time: Option<std::time::SystemTime>,
+    pub journal_bytes: u64,
+}
+
+impl Recovery {
+    /// 자동 저장 디렉터리 아래 보드별 디렉터리
+    pub fn find_all(root: &Path) -> Vec<Recovery> {
+        let mut dirs: Vec<PathBuf> = fs::read_dir(root)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .collect()
+            })
+            .unwrap_or_default();
+        dirs.sort();
+        dirs.iter().filter_map(|dir| Recovery::find(dir)).collect()
+    }
+
+    pub fn find(dir: &Path) -> Option<Recovery> {
+        let snapshot = fs::metadata(dir.join(SNAPSHOT_FILE)).ok();
+        let journal_bytes = fs::metadata(dir.join(JOURNAL_FILE)).map_or(0, |m| m.len());
+        if snapshot.is_none() && journal_bytes == 0 {
+            return None;
+        }
+        Some(Recovery {
+            dir: dir.to_path_buf(),
+            name: fs::read_to_string(dir.join(NAME_FILE)).ok(),
+            snapshot_time: snapshot.and_then(|m| m.modified().ok()),
+            journal_bytes,
+        })
+    }
+
+    /// 마지막 스냅샷 위에 저널을 재생한 문서와 재생된 작업 수
+    pub fn load(&self) -> Result<(Document, usize), FormatError> {
+        let (mut doc, generation) = match File::open(self.dir.join(SNAPSHOT_FILE)) {
+            Ok(file) => {
+                let mut reader = io::BufReader::new(file);
+                let generation = read_generation(&mut reader)?;
+                (binary::read_from(reader)?, Some(generation))
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (Document::default(), None),
+            Err(err) => return Err(err.into()),
+        };
+        let journal = match fs::read(self.dir.join(JOURNAL_FILE)) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err.into()),
+        };
+        let mut records = journal.as_slice();
+        let applied = match read_generation(&mut records) {
+            Ok(g) if generation.is_none_or(|s| s == g) => replay(&mut doc, records),
+            _ => 0,
+        };
+        Ok((doc, applied))
+    }
+
+    /// 복구했거나 버린 파일 정리 (보드별 디렉터리는 비면 함께 삭제)
+    pub fn discard(&self) {
+        remove_files(&self.dir);
+    }
+}
+
+fn remove_files(dir: &Path) {
+    for file in [JOURNAL_FILE, SNAPSHOT_FILE, NAME_FILE] {
+        let _ = fs::remove_file(dir.join(file));
+    }
+    let _ = fs::remove_dir(dir);
+}
+
+enum Message {
+    Record(Vec<u8>),
+    Snapshot(Vec<u8>),
+    // 탭 이름 (복구할 때 표시)
+    Name(String),
+    // 정상 종료 또는 보드를 닫음: 자동 저장 파일 삭제
+    Finish,
+}
+
+pub struct Journal {
+    sender: Sender<Message>,
+    handle: Option<JoinHandle<()>>,
+    ops_since_snapshot: usize,
+    last_snapshot: Instant,
+}
+
+impl Journal {
+    /// 디렉터리를 만들고 탭 이름과 현재 문서를 첫 스냅샷으로 기록하며 시작
+    pub fn start(dir: PathBuf, name: &str, doc: &Document) -> io::Result<Journal> {
+        fs::create_dir_all(&dir)?;
+        let journal = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(dir.join(JOURNAL_FILE))?;
+        let (sender, receiver) = mpsc::channel();
+        let handle = std::thread::Builder::new()
+            .name("autosave".into())
+            .spawn(move || writer_loop(&dir, journal, receiver))?;
+        let mut journal = Journal {
+            sender,
+            handle: Some(handle),
+            ops_since_snapshot: 0,
+            last_snapshot: Instant::now(),
+        };
+        journal.rename(name);
+        journal.snapshot(doc);
+        Ok(journal)
+    }
+
+    /// 작업 기록. doc은 작업이 적용된 뒤의 문서 (스냅샷 주기가 되면 사용)
+    pub fn record(&mut self, op: &Operation, doc: &Document) {
+        match op.encode() {
+            Ok(payload) => {
+                let _ = self.sender.send(Message::Record(frame(&payload)));
+            }
+            Err(err) => eprintln!("자동 저장 기록 실패: {}", err),
+        }
+        self.ops_since_snapshot += 1;
+        if self.ops_since_snapshot >= SNAPSHOT_EVERY_OPS
+            || self.last_snapshot.elapsed() >= SNAPSHOT_INTERVAL
+        {
+            self.snapshot(doc);
+        }
+    }
+
+    pub fn snapshot(&mut self, doc: &Document) {
+        let mut bytes = Vec::new();
+        match binary::write_to(doc, &mut bytes) {
+            Ok(()) => {
+                let _ = self.sender.send(Message::Snapshot(bytes));
+            }
+            Err(err) => eprintln!("자동 저장 스냅샷 실패: {}", err),
+        }
+        self.ops_since_snapshot = 0;
+        self.last_snapshot = Instant::now();
+    }
+
+    pub fn rename(&self, name: &str) {
+        let _ = self.sender.send(Message::Name(name.to_string()));
+    }
+
+    /// 남은 기록을 모두 쓰되 파일은 남겨 둠 (다음 실행에서 복구 안내)
+    pub fn close(self) {
+        let Journal { sender, handle, .. } = self;
+        drop(sender);
+        if let Some(handle) = handle {
+            let _ = handle.join();
+        }
+    }
+
+    /// 남은 기록을 마치고 자동 저장 파일을 정리
+    pub fn finish(mut self) {
+        let _ = self.sender.send(Message::Finish);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn writer_loop(dir: &Path, mut journal: File, receiver: Receiver<Message>) {
+    let snapshot_path = dir.join(SNAPSHOT_FILE);
+    let mut generation: u64 = 0;
+    for message in receiver {
+        let result = match message {
+            Message::Record(frame) => journal.write_all(&frame),
+            Message::Snapshot(bytes) => {
+                generation += 1;
+                // 임시 파일에 완전히 쓴 뒤 교체해야 스냅샷이 반쯤 쓰인 채로 남지 않음
+                let tmp = snapshot_path.with_extension("tmp");
+                let write_snapshot = || -> io::Result<()> {
+                    let mut file = File::create(&tmp)?;
+                    file.write_all(&generation.to_le_bytes())?;
+                    file.write_all(&bytes)?;
+                    file.sync_all()?;
+                    fs::rename(&tmp, &snapshot_path)
+                };
+                write_snapshot().and_then(|()| {
+                    journal.set_len(0)?;
+                    journal.seek(SeekFrom::Start(0))?;
+                    journal.write_all(&generation.to_le_bytes())
+                })
+            }
+            Message::Name(name) => fs::write(dir.join(NAME_FILE), name),
+            Message::Finish => {
+                drop(journal);
+                remove_files(dir);
+                return;
+            }
+        };
+        if let Err(err) = result {
+            eprintln!("자동 저장 쓰기 실패: {}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::CardKind;
+
+    // 카드 ID/글, 위치, 색, 엣지 (비교용)
+    type CardState = (u64, String, [f32; 2], [f32; 3]);
+    type DocState = (Vec<CardState>, Vec<(usize, usize)>);
+
+    fn state(doc: &Document) -> DocState {
+        let cards = doc
+            .cards
+            .iter()
+            .zip(&doc.block_positions)
+            .map(|(meta, inst)| (meta.id, meta.text.clone(), inst.position, inst.color))
+            .collect();
+        let edges = doc.edges.iter().map(|e| (e.from, e.to)).collect();
+        (cards, edges)
+    }
+
+    fn appended(base: &Document, texts: &[&str]) -> Operation {
+        let mut doc = base.clone();
+        let (card_start, edge_start) = (doc.len(), doc.edges.len());
+        for (i, text) in texts.iter().enumerate() {
+            let idx = doc.push_card([i as f32, 0.0], [1.0; 3], CardKind::Text, text);
+            if i > 0 {
+                doc.connect(idx - 1, idx);
+            }
+        }
+        Operation::appended(&doc, card_start, edge_start)
+    }
+
+    /// 모든 작업 종류를 한 번 이상 쓰는 작업 목록과, 각 작업 뒤의 문서 상태 (0번은 빈 문서)
+    fn sample() -> (Vec<Vec<u8>>, Vec<DocState>) {
+        let mut doc = Document::default();
+        let mut frames = Vec::new();
+        let mut states = vec![state(&doc)];
+        let steps: Vec<fn(&Document) -> Operation> = vec![
+            |doc| appended(doc, &["가", "나", "다"]),
+            |_| Operation::SetPositions(vec![(0, [1.0, 2.0]), (2, [3.0, 4.0])]),
+            |_| Operation::SetColors(vec![(1, [0.1, 0.2, 0.3])]),
+            |_| Operation::Raise(vec![0]),
+            |_| Operation::Remove(vec![1]),
+            |doc| appended(doc, &["라", "마"]),
+            |_| Operation::Clear,
+            |doc| appended(doc, &["바"]),
+        ];
+        for step in steps {
+            let op = step(&doc);
+            frames.push(frame(&op.encode().expect("encodes")));
+            op.apply(&mut doc);
+            states.push(state(&doc));
+        }
+        (frames, states)
+    }
+
+    fn replayed(bytes: &[u8]) -> (usize, DocState) {
+        let mut doc = Document::default();
+        let applied = replay(&mut doc, bytes);
+        (applied, state(&doc))
+    }
+
+    #[test]
+    fn replays_every_operation_kind() {
+        let (frames, states) = sample();
+        let bytes = frames.concat();
+        assert_eq!(
+            replayed(&bytes),
+            (frames.len(), states[frames.len()].clone())
+        );
+    }
+
+    #[test]
+    fn truncation_applies_only_complete_records() {
+        let (frames, states) = sample();
+        let bytes = frames.concat();
+        for cut in 0..=bytes.len() {
+            let mut end = 0;
+            let complete = frames
+                .iter()
+                .take_while(|f| {
+                    end += f.len();
+                    end <= cut
+                })
+                .count();
+            assert_eq!(
+                replayed(&bytes[..cut]),
+                (complete, states[complete].clone()),
+                "{}바이트에서 잘림",
+                cut
+            );
+        }
+    }
+
+    #[test]
+    fn corruption_stops_at_the_damaged_record() {
+        let (frames, states) = sample();
+        let bytes = frames.concat();
+        let mut record = 0;
+        let mut record_end = frames[0].len();
+        for offset in 0..bytes.len() {
+            if offset == record_end {
+                record += 1;
+                record_end += frames[record].len();
+            }
+            let mut damaged = bytes.clone();
+            damaged[offset] ^= 0x01;
+            assert_eq!(
+                replayed(&damaged),
+                (record, states[record].clone()),
+                "{}바이트 손상",
+                offset
+            );
+        }
+    }
+
+    #[test]
+    fn finds_and_discards_each_board_directory() {
+        let root = std::env::temp_dir().join(format!(
+            "journal-test-{:016x}",
+            crate::document::random_id()
+        ));
+        let (frames, states) = sample();
+        for (board, name) in [("a", "기획"), ("b", "회의")] {
+            let dir = root.join(board);
+            fs::create_dir_all(&dir).expect("creates board dir");
+            fs::write(dir.join(NAME_FILE), name).expect("writes name");
+            let mut journal = 1u64.to_le_bytes().to_vec();
+            journal.extend(frames[..3].concat());
+            fs::write(dir.join(JOURNAL_FILE), journal).expect("writes journal");
+        }
+        // 보드별 디렉터리 밖에 있는 파일은 복구 대상이 아님
+        fs::write(root.join(JOURNAL_FILE), frames[0].clone()).expect("writes stray journal");
+
+        let recoveries = Recovery::find_all(&root);
+        let names: Vec<Option<&str>> = recoveries.iter().map(|r| r.name.as_deref()).collect();
+        assert_eq!(names, [Some("기획"), Some("회의")]);
+        for recovery in &recoveries {
+            let (doc, applied) = recovery.load().expect("loads");
+            assert_eq!((applied, state(&doc)), (3, states[3].clone()));
+            recovery.discard();
+            assert!(!recovery.dir.exists());
+        }
+        assert!(Recovery::find_all(&root).is_empty());
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn close_keeps_files_for_recovery_and_finish_removes_them() {
+        let root = std::env::temp_dir().join(format!(
+            "journal-test-{:016x}",
+            crate::document::random_id()
+        ));
+        let mut doc = Document::default();
+        let mut journal = Journal::start(root.join("a"), "기획", &doc).expect("starts");
+        let op = appended(&doc, &["가", "나"]);
+        Operation::decode(&op.encode().expect("encodes"))
+            .expect("decodes")
+            .apply(&mut doc);
+        journal.record(&op, &doc);
+        journal.close();
+
+        let recoveries = Recovery::find_all(&root);
+        assert_eq!(recoveries.len(), 1);
+        let (restored, _) = recoveries[0].load().expect("loads");
+        assert_eq!(state(&restored), state(&doc));
+
+        Journal::start(root.join("a"), "기획", &doc)
+            .expect("starts")
+            .finish();
+        assert!(Recovery::find_all(&root).is_empty());
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,431 @@
+// 단축키 (설정 디렉터리의 keymap.json)
+//
+// 파일에는 바꾸고 싶은 명령만 적는다. 값은 단축키 문자열 하나나 배열이고,
+// 빈 배열은 그 명령의 단축키를 없앤다. 적지 않은 명령은 기본 단축키를 쓴다.
+//
+//   { "zoom_to_fit": "Shift+1", "delete": ["Delete", "Backspace"], "new_tab": [] }
+//
+// 한 단축키를 여러 명령에 적으면 파일에서 먼저 나온 명령이 쓰인다.
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use winit::keyboard::{KeyCode, ModifiersState};
+
+use crate::commands::Command;
+use crate::navigation::Direction;
+use crate::paths;
+
+const FILE_NAME: &str = "keymap.json";
+
+const DEFAULT_BINDINGS: &[(Command, &str)] = &[
+    (Command::OpenPalette, "Ctrl+K"),
+    (Command::SelectAll, "Ctrl+A"),
+    (Command::ClearSelection, "Escape"),
+    (Command::Duplicate, "Ctrl+D"),
+    (Command::Delete, "Delete"),
+    (Command::Delete, "Backspace"),
+    (Command::BringToFront, "Ctrl+Shift+]"),
+    (Command::Nudge(Direction::Left), "Left"),
+    (Command::Nudge(Direction::Right), "Right"),
+    (Command::Nudge(Direction::Up), "Up"),
+    (Command::Nudge(Direction::Down), "Down"),
+    (Command::NudgeFar(Direction::Left), "Shift+Left"),
+    (Command::NudgeFar(Direction::Right), "Shift+Right"),
+    (Command::NudgeFar(Direction::Up), "Shift+Up"),
+    (Command::NudgeFar(Direction::Down), "Shift+Down"),
+    (Command::ToggleNavigation, "Ctrl+Shift+N"),
+    (Command::ZoomToFit, "Shift+1"),
+    (Command::ZoomToSelection, "Shift+2"),
+    (Command::ZoomToActualSize, "Ctrl+0"),
+    (Command::Import, "Ctrl+O"),
+    (Command::Export, "Ctrl+Shift+S"),
+    (Command::NewTab, "Ctrl+T"),
+    (Command::CloseTab, "Ctrl+W"),
+    (Command::ToggleInspector, "Ctrl+I"),
+    (Command::ShowSettings, "Ctrl+,"),
+];
+
+// 이름 ↔ 키. 같은 키에 이름이 여럿이면 첫 이름으로 표시
+const KEY_NAMES: &[(&str, KeyCode)] = &[
+    ("A", KeyCode::KeyA),
+    ("B", KeyCode::KeyB),
+    ("C", KeyCode::KeyC),
+    ("D", KeyCode::KeyD),
+    ("E", KeyCode::KeyE),
+    ("F", KeyCode::KeyF),
+    ("G", KeyCode::KeyG),
+    ("H", KeyCode::KeyH),
+    ("I", KeyCode::KeyI),
+    ("J", KeyCode::KeyJ),
+    ("K", KeyCode::KeyK),
+    ("L", KeyCode::KeyL),
+    ("M", KeyCode::KeyM),
+    ("N", KeyCode::KeyN),
+    ("O", KeyCode::KeyO),
+    ("P", KeyCode::KeyP),
+    ("Q", KeyCode::KeyQ),
+    ("R", KeyCode::KeyR),
+    ("S", KeyCode::KeyS),
+    ("T", KeyCode::KeyT),
+    ("U", KeyCode::KeyU),
+    ("V", KeyCode::KeyV),
+    ("W", KeyCode::KeyW),
+    ("X", KeyCode::KeyX),
+    ("Y", KeyCode::KeyY),
+    ("Z", KeyCode::KeyZ),
+    ("0", KeyCode::Digit0),
+    ("1", KeyCode::Digit1),
+    ("2", KeyCode::Digit2),
+    ("3", KeyCode::Digit3),
+    ("4", KeyCode::Digit4),
+    ("5", KeyCode::Digit5),
+    ("6", KeyCode::Digit6),
+    ("7", KeyCode::Digit7),
+    ("8", KeyCode::Digit8),
+    ("9", KeyCode::Digit9),
+    ("F1", KeyCode::F1),
+    ("F2", KeyCode::F2),
+    ("F3", KeyCode::F3),
+    ("F4", KeyCode::F4),
+    ("F5", KeyCode::F5),
+    ("F6", KeyCode::F6),
+    ("F7", KeyCode::F7),
+    ("F8", KeyCode::F8),
+    ("F9", KeyCode::F9),
+    ("F10", KeyCode::F10),
+    ("F11", KeyCode::F11),
+    ("F12", KeyCode::F12),
+    ("Delete", KeyCode::Delete),
+    ("Backspace", KeyCode::Backspace),
+    ("Enter", KeyCode::Enter),
+    ("Escape", KeyCode::Escape),
+    ("Esc", KeyCode::Escape),
+    ("Tab", KeyCode::Tab),
+    ("Space", KeyCode::Space),
+    ("Up", KeyCode::ArrowUp),
+    ("Down", KeyCode::ArrowDown),
+    ("Left", KeyCode::ArrowLeft),
+    ("Right", KeyCode::ArrowRight),
+    ("Home", KeyCode::Home),
+    ("End", KeyCode::End),
+    ("PageUp", KeyCode::PageUp),
+    ("PageDown", KeyCode::PageDown),
+    ("-", KeyCode::Minus),
+    ("=", KeyCode::Equal),
+    (",", KeyCode::Comma),
+    (".", KeyCode::Period),
+    ("/", KeyCode::Slash),
+    ("\\", KeyCode::Backslash),
+    (";", KeyCode::Semicolon),
+    ("'", KeyCode::Quote),
+    ("`", KeyCode::Backquote),
+    ("[", KeyCode::BracketLeft),
+    ("]", KeyCode::BracketRight),
+];
+
+/// 키 하나와 수식키 조합. Ctrl은 macOS의 Cmd도 포함
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+}
+
+impl KeyChord {
+    pub fn new(key: KeyCode, modifiers: ModifiersState) -> Self {
+        Self {
+            key,
+            ctrl: modifiers.control_key() || modifiers.super_key(),
+            shift: modifiers.shift_key(),
+            alt: modifiers.alt_key(),
+        }
+    }
+
+    /// "Ctrl+Shift+K" 형식 (수식키는 대소문자 무관, Cmd/Command/Option 별칭 허용)
+    pub fn parse(text: &str) -> Option<KeyChord> {
+        let parts: Vec<&str> = text.split('+').map(str::trim).collect();
+        // "Ctrl++"처럼 + 키 자체는 지원하지 않음 ("="를 사용)
+        let (key, modifiers) = parts.split_last()?;
+        let key = KEY_NAMES
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))?
+            .1;
+        let mut chord = KeyChord {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        };
+        for modifier in modifiers {
+            match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" | "cmd" | "command" => chord.ctrl = true,
+                "shift" => chord.shift = true,
+                "alt" | "option" => chord.alt = true,
+                _ => return None,
+            }
+        }
+        Some(chord)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str(if cfg!(target_os = "macos") {
+                "Cmd+"
+            } else {
+                "Ctrl+"
+            })?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        let name = KEY_NAMES
+            .iter()
+            .find(|(_, key)| *key == self.key)
+            .map_or("?", |(name, _)| name);
+        f.write_str(name)
+    }
+}
+
+pub struct Keymap {
+    // 단축키 → 명령 (우선순위 순)
+    pub bindings: Vec<(KeyChord, Command)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULT_BINDINGS
+            .iter()
+            .filter_map(|&(command, text)| Some((KeyChord::parse(text)?, command)))
+            .collect();
+        Self { bindings }
+    }
+}
+
+/// 파일에 적힌 순서 그대로의 (명령 id, 값) 목록.
+/// serde_json::Map은 키 순으로 정렬하므로 직접 읽는다
+struct Entries(Vec<(String, serde_json::Value)>);
+
+impl<'de> Deserialize<'de> for Entries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = Entries;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("JSON 객체")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Entries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(Entries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    Some(paths::config_dir()?.join(FILE_NAME))
+}
+
+impl Keymap {
+    pub fn lookup(&self, chord: KeyChord) -> Option<Command> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == chord)
+            .map(|&(_, command)| command)
+    }
+
+    /// 메뉴/팔레트에 표시할 첫 단축키 (없으면 빈 문자열)
+    pub fn label(&self, command: Command) -> String {
+        self.bindings
+            .iter()
+            .find(|(_, bound)| *bound == command)
+            .map_or_else(String::new, |(chord, _)| chord.to_string())
+    }
+
+    /// 파일 내용으로 기본 단축키를 덮어씀. 읽을 수 없는 항목은 건너뛰고 경고
+    pub fn parse(text: &str) -> (Keymap, Vec<String>) {
+        let mut keymap = Keymap::default();
+        let mut warnings = Vec::new();
+        let entries = match serde_json::from_str::<Entries>(text) {
+            Ok(Entries(entries)) => entries,
+            Err(err) if err.is_data() => {
+                return (keymap, vec!["키맵 파일이 JSON 객체가 아닙니다".into()])
+            }
+            Err(err) => return (keymap, vec![format!("키맵 파일 형식 오류: {}", err)]),
+        };
+
+        let mut custom: Vec<(KeyChord, Command)> = Vec::new();
+        for (id, value) in &entries {
+            let Some(command) = Command::from_id(id) else {
+                warnings.push(format!("알 수 없는 명령 '{}'", id));
+                continue;
+            };
+            let texts: Vec<&serde_json::Value> = match value {
+                serde_json::Value::Array(items) => items.iter().collect(),
+                other => vec![other],
+            };
+            keymap.bindings.retain(|&(_, bound)| bound != command);
+            for text in texts {
+                let chord = text.as_str().and_then(KeyChord::parse);
+                let Some(chord) = chord else {
+                    warnings.push(format!("'{}'의 단축키 {}을(를) 읽을 수 없음", id, text));
+                    continue;
+                };
+                if let Some(&(_, other)) = custom.iter().find(|(bound, _)| *bound == chord) {
+                    warnings.push(format!(
+                        "'{}'이(가) '{}'와 '{}'에 중복 — '{}'만 사용",
+                        chord,
+                        other.id(),
+                        id,
+                        other.id()
+                    ));
+                    continue;
+                }
+                custom.push((chord, command));
+            }
+        }
+
+        // 파일에 적은 단축키가 같은 키의 기본 단축키보다 우선
+        keymap
+            .bindings
+            .retain(|(chord, _)| !custom.iter().any(|(bound, _)| bound == chord));
+        custom.append(&mut keymap.bindings);
+        keymap.bindings = custom;
+        (keymap, warnings)
+    }
+
+    /// 파일이 없으면 기본 단축키 (경고 없음)
+    pub fn load(path: &Path) -> (Keymap, Vec<String>) {
+        match fs::read_to_string(path) {
+            Ok(text) => Keymap::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (Keymap::default(), Vec::new()),
+            Err(err) => (
+                Keymap::default(),
+                vec![format!("키맵 파일을 읽지 못했습니다: {}", err)],
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> KeyChord {
+        KeyChord::parse(text).expect("valid chord")
+    }
+
+    fn parse_clean(text: &str) -> Keymap {
+        let (keymap, warnings) = Keymap::parse(text);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        keymap
+    }
+
+    #[test]
+    fn chords_accept_aliases_in_any_case() {
+        assert_eq!(
+            KeyChord::parse("cmd + SHIFT + k"),
+            Some(KeyChord {
+                key: KeyCode::KeyK,
+                ctrl: true,
+                shift: true,
+                alt: false,
+            })
+        );
+        assert_eq!(chord("Option+Esc"), chord("Alt+Escape"));
+        assert_eq!(chord("Control+]"), chord("Ctrl+]"));
+        assert_eq!(KeyChord::parse("Hyper+K"), None);
+        assert_eq!(KeyChord::parse("Ctrl+"), None);
+        assert_eq!(KeyChord::parse("Ctrl++"), None);
+        assert!(chord("Ctrl+Shift+]").to_string().ends_with("Shift+]"));
+    }
+
+    #[test]
+    fn file_overrides_only_the_listed_commands() {
+        let keymap = parse_clean(r#"{ "zoom_to_fit": "Shift+F" }"#);
+        assert_eq!(keymap.lookup(chord("Shift+F")), Some(Command::ZoomToFit));
+        assert_eq!(keymap.lookup(chord("Shift+1")), None);
+        assert_eq!(keymap.lookup(chord("Ctrl+T")), Some(Command::NewTab));
+
+        // 배열은 기본 단축키를 모두 바꿈
+        let keymap = parse_clean(r#"{ "delete": ["X", "Ctrl+X"] }"#);
+        assert_eq!(keymap.lookup(chord("X")), Some(Command::Delete));
+        assert_eq!(keymap.lookup(chord("Ctrl+X")), Some(Command::Delete));
+        assert_eq!(keymap.lookup(chord("Delete")), None);
+        assert_eq!(keymap.lookup(chord("Backspace")), None);
+    }
+
+    #[test]
+    fn empty_array_unbinds_the_command() {
+        let keymap = parse_clean(r#"{ "new_tab": [] }"#);
+        assert_eq!(keymap.lookup(chord("Ctrl+T")), None);
+        assert_eq!(keymap.label(Command::NewTab), "");
+        assert_eq!(keymap.lookup(chord("Ctrl+W")), Some(Command::CloseTab));
+    }
+
+    #[test]
+    fn file_binding_beats_the_default_on_the_same_key() {
+        let keymap = parse_clean(r#"{ "duplicate": "Ctrl+A" }"#);
+        assert_eq!(keymap.lookup(chord("Ctrl+A")), Some(Command::Duplicate));
+        assert_eq!(keymap.lookup(chord("Ctrl+D")), None);
+        // 키를 빼앗긴 기본 명령은 단축키 없이 남음
+        assert_eq!(keymap.label(Command::SelectAll), "");
+    }
+
+    #[test]
+    fn duplicate_chord_keeps_the_entry_written_first_in_the_file() {
+        // 알파벳 순으로는 duplicate가 앞서지만 파일에서는 select_all이 먼저
+        let (keymap, warnings) =
+            Keymap::parse(r#"{ "select_all": "Ctrl+E", "duplicate": "Ctrl+E" }"#);
+        assert_eq!(keymap.lookup(chord("Ctrl+E")), Some(Command::SelectAll));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("'duplicate'"), "{}", warnings[0]);
+    }
+
+    #[test]
+    fn duplicate_chord_in_the_file_keeps_the_first_and_warns() {
+        let (keymap, warnings) =
+            Keymap::parse(r#"{ "duplicate": "Ctrl+E", "select_all": "Ctrl+E" }"#);
+        assert_eq!(keymap.lookup(chord("Ctrl+E")), Some(Command::Duplicate));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("select_all"), "{}", warnings[0]);
+        let count = keymap
+            .bindings
+            .iter()
+            .filter(|(bound, _)| *bound == chord("Ctrl+E"))
+            .count();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn bad_entries_warn_and_keep_the_rest() {
+        let (keymap, warnings) = Keymap::parse(
+            r#"{ "no_such_command": "X", "import": "Ctrl+Nope", "new_tab": "Ctrl+N" }"#,
+        );
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+        assert_eq!(keymap.lookup(chord("Ctrl+N")), Some(Command::NewTab));
+        assert_eq!(keymap.lookup(chord("Ctrl+K")), Some(Command::OpenPalette));
+
+        for text in ["[]", "not json"] {
+            let (keymap, warnings) = Keymap::parse(text);
+            assert_eq!(warnings.len(), 1);
+            assert_eq!(keymap.bindings, Keymap::default().bindings);
+        }
+    }
+}
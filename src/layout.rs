@@ -0,0 +1,380 @@
+use crate::document::Document;
+use crate::types::{GRID_SPACING_X, GRID_SPACING_Y};
+
+// 계층 레이아웃 간격 (카드 0.5 x 0.3 기준)
+const LAYER_GAP_VERTICAL: f32 = 0.6;
+const LAYER_GAP_HORIZONTAL: f32 = 0.9;
+const SIBLING_GAP_HORIZONTAL: f32 = 0.7;
+const SIBLING_GAP_VERTICAL: f32 = 0.45;
+const BARYCENTER_SWEEPS: usize = 4;
+
+// 마인드맵 간격
+const MIND_MAP_LEVEL_GAP: f32 = 0.8;
+const MIND_MAP_LEAF_GAP: f32 = 0.4;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LayoutDirection {
+    #[default]
+    TopDown,
+    BottomUp,
+    LeftRight,
+    RightLeft,
+}
+
+impl LayoutDirection {
+    /// Mermaid(TD/TB/BT/LR/RL), DOT rankdir 표기 해석
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "TD" | "TB" => Some(Self::TopDown),
+            "BT" => Some(Self::BottomUp),
+            "LR" => Some(Self::LeftRight),
+            "RL" => Some(Self::RightLeft),
+            _ => None,
+        }
+    }
+}
+
+/// 엣지 방향을 따라 계층을 나누고 배리센터 정렬로 교차를 줄이는 레이아웃.
+/// 결과는 원점 중심으로 배치된다.
+pub fn layered(doc: &mut Document, direction: LayoutDirection) {
+    let n = doc.len();
+    if n == 0 {
+        return;
+    }
+
+    let mut outgoing = vec![Vec::new(); n];
+    for edge in &doc.edges {
+        outgoing[edge.from].push(edge.to);
+    }
+
+    // DFS로 역방향(사이클) 엣지를 제외한 DAG 구성
+    let dag = acyclic_edges(&outgoing);
+    let mut incoming = vec![Vec::new(); n];
+    for (from, targets) in dag.iter().enumerate() {
+        for &to in targets {
+            incoming[to].push(from);
+        }
+    }
+
+    // 최장 경로 기준 계층 배정 (위상 정렬 순서)
+    let mut in_degree: Vec<usize> = incoming.iter().map(|v| v.len()).collect();
+    let mut queue: std::collections::VecDeque<usize> =
+        (0..n).filter(|&i| in_degree[i] == 0).collect();
+    let mut layer_of = vec![0usize; n];
+    while let Some(node) = queue.pop_front() {
+        for &next in &dag[node] {
+            layer_of[next] = layer_of[next].max(layer_of[node] + 1);
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let layer_count = layer_of.iter().max().map_or(0, |m| m + 1);
+    let mut layers = vec![Vec::new(); layer_count];
+    for (node, &layer) in layer_of.iter().enumerate() {
+        layers[layer].push(node);
+    }
+
+    // 배리센터 스윕: 아래로 내려가며 부모 기준, 올라가며 자식 기준 정렬
+    let mut order = vec![0.0f32; n];
+    for layer in &layers {
+        for (i, &node) in layer.iter().enumerate() {
+            order[node] = i as f32;
+        }
+    }
+    for sweep in 0..BARYCENTER_SWEEPS {
+        let downward = sweep % 2 == 0;
+        let range: Vec<usize> = if downward {
+            (1..layer_count).collect()
+        } else {
+            (0..layer_count.saturating_sub(1)).rev().collect()
+        };
+        for l in range {
+            let neighbors = if downward { &incoming } else { &dag };
+            let mut keyed: Vec<(f32, usize)> = layers[l]
+                .iter()
+                .map(|&node| {
+                    let adj = &neighbors[node];
+                    let key = if adj.is_empty() {
+                        order[node]
+                    } else {
+                        adj.iter().map(|&a| order[a]).sum::<f32>() / adj.len() as f32
+                    };
+                    (key, node)
+                })
+                .collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            layers[l] = keyed.into_iter().map(|(_, node)| node).collect();
+            for (i, &node) in layers[l].iter().enumerate() {
+                order[node] = i as f32;
+            }
+        }
+    }
+
+    for (l, layer) in layers.iter().enumerate() {
+        let offset = (layer.len() as f32 - 1.0) * 0.5;
+        for (i, &node) in layer.iter().enumerate() {
+            let along = l as f32;
+            let across = i as f32 - offset;
+            doc.block_positions[node].position = match direction {
+                LayoutDirection::TopDown => {
+                    [across * SIBLING_GAP_HORIZONTAL, -along * LAYER_GAP_VERTICAL]
+                }
+                LayoutDirection::BottomUp => {
+                    [across * SIBLING_GAP_HORIZONTAL, along * LAYER_GAP_VERTICAL]
+                }
+                LayoutDirection::LeftRight => {
+                    [along * LAYER_GAP_HORIZONTAL, -across * SIBLING_GAP_VERTICAL]
+                }
+                LayoutDirection::RightLeft => [
+                    -along * LAYER_GAP_HORIZONTAL,
+                    -across * SIBLING_GAP_VERTICAL,
+                ],
+            };
+        }
+    }
+
+    doc.center_at_origin();
+}
+
+/// 루트를 원점에 두고 1단계 가지를 좌우로 나눠 펼치는 마인드맵 레이아웃.
+/// 각 서브트리는 잎 개수만큼 세로 공간을 차지한다.
+pub fn mind_map(doc: &mut Document, root: usize) {
+    let n = doc.len();
+    if root >= n {
+        return;
+    }
+
+    // 루트에서 BFS로 트리 구성 (이미 방문한 노드로 가는 엣지는 무시)
+    let mut adjacency = vec![Vec::new(); n];
+    for edge in &doc.edges {
+        adjacency[edge.from].push(edge.to);
+        adjacency[edge.to].push(edge.from);
+    }
+    let mut children = vec![Vec::new(); n];
+    let mut visited = vec![false; n];
+    let mut order = vec![root];
+    visited[root] = true;
+    let mut head = 0;
+    while head < order.len() {
+        let node = order[head];
+        head += 1;
+        for &next in &adjacency[node] {
+            if !visited[next] {
+                visited[next] = true;
+                children[node].push(next);
+                order.push(next);
+            }
+        }
+    }
+
+    // 잎 개수 (역 BFS 순서로 누적)
+    let mut leaves = vec![1usize; n];
+    for &node in order.iter().rev() {
+        if !children[node].is_empty() {
+            leaves[node] = children[node].iter().map(|&c| leaves[c]).sum();
+        }
+    }
+
+    // 1단계 가지를 잎 개수가 비슷하도록 오른쪽/왼쪽으로 분배
+    let total: usize = children[root].iter().map(|&c| leaves[c]).sum();
+    let mut right = Vec::new();
+    let mut left = Vec::new();
+    let mut right_leaves = 0;
+    for &child in &children[root] {
+        if right_leaves * 2 < total {
+            right_leaves += leaves[child];
+            right.push(child);
+        } else {
+            left.push(child);
+        }
+    }
+
+    doc.block_positions[root].position = [0.0, 0.0];
+    for (side, dir) in [(right, 1.0f32), (left, -1.0f32)] {
+        let side_leaves: usize = side.iter().map(|&c| leaves[c]).sum();
+        let mut stack: Vec<(usize, usize, f32)> = Vec::new();
+        let mut top = side_leaves as f32 * MIND_MAP_LEAF_GAP * 0.5;
+        for &child in &side {
+            stack.push((child, 1, top));
+            top -= leaves[child] as f32 * MIND_MAP_LEAF_GAP;
+        }
+        // (노드, 깊이, 할당된 세로 구간의 위쪽 끝)
+        while let Some((node, depth, top)) = stack.pop() {
+            let height = leaves[node] as f32 * MIND_MAP_LEAF_GAP;
+            doc.block_positions[node].position =
+                [dir * depth as f32 * MIND_MAP_LEVEL_GAP, top - height * 0.5];
+            let mut child_top = top;
+            for &child in &children[node] {
+                stack.push((child, depth + 1, child_top));
+                child_top -= leaves[child] as f32 * MIND_MAP_LEAF_GAP;
+            }
+        }
+    }
+}
+
+/// 지정한 카드들을 나머지 카드의 경계 상자 아래(없으면 원점부터) 정사각형에 가까운 격자로 배치
+pub fn fill_grid(doc: &mut Document, indices: &[usize]) {
+    if indices.is_empty() {
+        return;
+    }
+
+    let mut unplaced = vec![false; doc.len()];
+    for &idx in indices {
+        unplaced[idx] = true;
+    }
+    let mut placed_min: Option<[f32; 2]> = None;
+    for (i, pos) in doc.block_positions.iter().enumerate() {
+        if unplaced[i] {
+            continue;
+        }
+        let min = placed_min.get_or_insert(pos.position);
+        min[0] = min[0].min(pos.position[0]);
+        min[1] = min[1].min(pos.position[1]);
+    }
+    let origin = placed_min.map_or([0.0, 0.0], |[x, y]| [x, y - GRID_SPACING_Y * 2.0]);
+
+    let cols = (indices.len() as f32).sqrt().ceil() as usize;
+    for (i, &idx) in indices.iter().enumerate() {
+        let col = (i % cols) as f32;
+        let row = (i / cols) as f32;
+        doc.block_positions[idx].position = [
+            origin[0] + col * GRID_SPACING_X,
+            origin[1] - row * GRID_SPACING_Y,
+        ];
+    }
+}
+
+/// 사이클을 만드는 역방향 엣지를 제거한 인접 리스트
+fn acyclic_edges(outgoing: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    const UNVISITED: u8 = 0;
+    const ON_STACK: u8 = 1;
+    const DONE: u8 = 2;
+
+    let n = outgoing.len();
+    let mut state = vec![UNVISITED; n];
+    let mut dag = vec![Vec::new(); n];
+
+    for root in 0..n {
+        if state[root] != UNVISITED {
+            continue;
+        }
+        // 재귀 대신 명시적 스택 (큰 그래프에서 스택 오버플로 방지)
+        let mut stack = vec![(root, 0usize)];
+        state[root] = ON_STACK;
+        while let Some((node, next_child)) = stack.pop() {
+            if let Some(&child) = outgoing[node].get(next_child) {
+                stack.push((node, next_child + 1));
+                match state[child] {
+                    UNVISITED => {
+                        dag[node].push(child);
+                        state[child] = ON_STACK;
+                        stack.push((child, 0));
+                    }
+                    DONE => dag[node].push(child),
+                    _ => {} // 역방향 엣지
+                }
+            } else {
+                state[node] = DONE;
+            }
+        }
+    }
+    dag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::CardKind;
+
+    /// 카드 n장과 (출발, 도착) 연결
+    fn graph(n: usize, edges: &[(usize, usize)]) -> Document {
+        let mut doc = Document::default();
+        for i in 0..n {
+            doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, &i.to_string());
+        }
+        for &(from, to) in edges {
+            doc.connect(from, to);
+        }
+        doc
+    }
+
+    fn positions(doc: &Document) -> Vec<[f32; 2]> {
+        doc.block_positions.iter().map(|p| p.position).collect()
+    }
+
+    #[test]
+    fn parses_mermaid_and_dot_directions() {
+        assert_eq!(LayoutDirection::parse("td"), Some(LayoutDirection::TopDown));
+        assert_eq!(LayoutDirection::parse("TB"), Some(LayoutDirection::TopDown));
+        assert_eq!(
+            LayoutDirection::parse("bt"),
+            Some(LayoutDirection::BottomUp)
+        );
+        assert_eq!(
+            LayoutDirection::parse(" LR "),
+            Some(LayoutDirection::LeftRight)
+        );
+        assert_eq!(
+            LayoutDirection::parse("rl"),
+            Some(LayoutDirection::RightLeft)
+        );
+        assert_eq!(LayoutDirection::parse("XY"), None);
+    }
+
+    #[test]
+    fn chain_follows_the_direction_centered_at_origin() {
+        let mut doc = graph(3, &[(0, 1), (1, 2)]);
+        layered(&mut doc, LayoutDirection::TopDown);
+        let gap = LAYER_GAP_VERTICAL;
+        assert_eq!(positions(&doc), [[0.0, gap], [0.0, 0.0], [0.0, -gap]]);
+
+        layered(&mut doc, LayoutDirection::BottomUp);
+        assert_eq!(positions(&doc), [[0.0, -gap], [0.0, 0.0], [0.0, gap]]);
+
+        let gap = LAYER_GAP_HORIZONTAL;
+        layered(&mut doc, LayoutDirection::LeftRight);
+        assert_eq!(positions(&doc), [[-gap, 0.0], [0.0, 0.0], [gap, 0.0]]);
+
+        layered(&mut doc, LayoutDirection::RightLeft);
+        assert_eq!(positions(&doc), [[gap, 0.0], [0.0, 0.0], [-gap, 0.0]]);
+    }
+
+    #[test]
+    fn siblings_share_a_layer_and_the_longest_path_wins() {
+        // 0 → 1 → 3, 0 → 2 → 3, 0 → 3 (지름길이 있어도 3은 맨 아래 계층)
+        let mut doc = graph(4, &[(0, 1), (0, 2), (1, 3), (2, 3), (0, 3)]);
+        layered(&mut doc, LayoutDirection::TopDown);
+        let p = positions(&doc);
+        assert_eq!(p[1][1], p[2][1]);
+        assert!(p[0][1] > p[1][1] && p[1][1] > p[3][1]);
+        assert!((p[1][0] - p[2][0]).abs() >= SIBLING_GAP_HORIZONTAL - 1e-5);
+    }
+
+    #[test]
+    fn cycles_are_broken_instead_of_looping() {
+        let mut doc = graph(3, &[(0, 1), (1, 2), (2, 0)]);
+        layered(&mut doc, LayoutDirection::TopDown);
+        let mut ys: Vec<f32> = positions(&doc).iter().map(|p| p[1]).collect();
+        ys.sort_by(f32::total_cmp);
+        ys.dedup();
+        assert_eq!(ys.len(), 3);
+    }
+
+    #[test]
+    fn mind_map_splits_branches_left_and_right() {
+        // 루트 0, 가지 1(잎 2개), 2, 3
+        let mut doc = graph(6, &[(0, 1), (1, 4), (1, 5), (0, 2), (0, 3)]);
+        mind_map(&mut doc, 0);
+        let p = positions(&doc);
+        assert_eq!(p[0], [0.0, 0.0]);
+        // 잎이 많은 첫 가지는 오른쪽, 나머지는 왼쪽
+        assert_eq!(p[1][0], MIND_MAP_LEVEL_GAP);
+        assert_eq!(p[4][0], MIND_MAP_LEVEL_GAP * 2.0);
+        assert_eq!(p[2][0], -MIND_MAP_LEVEL_GAP);
+        assert_eq!(p[3][0], -MIND_MAP_LEVEL_GAP);
+        assert!(p[4][1] > p[5][1] && p[2][1] > p[3][1]);
+    }
+}
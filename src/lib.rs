@@ -0,0 +1,6 @@
+// 문서 모델과 파일 포맷 (앱과 벤치마크가 함께 씀)
+pub mod document;
+pub mod formats;
+pub mod layout;
+pub mod transcript;
+pub mod types;
@@ -1,13 +1,35 @@
+mod autosave;
 mod camera;
+mod camera_motion;
+mod card_ops;
+mod command_ops;
+mod commands;
+mod diff_overlay;
 mod egui_integration;
+mod file_ops;
+mod history;
 mod input;
+mod journal;
+mod keymap;
+mod navigation;
+mod paths;
 mod pipeline;
 mod renderer;
+mod selection;
+mod session_layout;
+mod session_ops;
+mod settings;
+mod settings_ops;
+mod snapping;
 mod state;
-mod types;
+mod stats;
+mod tabs;
 mod ui;
+mod workspace;
 
 use std::sync::Arc;
+// 문서 모델과 파일 포맷은 라이브러리(lib.rs)에 있음
+use wgpu_perf_test::{document, formats, transcript, types};
 use winit::{
     application::ApplicationHandler,
     event::*,
@@ -16,6 +38,7 @@ use winit::{
     window::WindowId,
 };
 
+use session_layout::SessionLayout;
 use state::AppState;
 
 struct App {
@@ -28,15 +51,36 @@ impl ApplicationHandler for App {
             return;
         }
 
-        let window = Arc::new(
-            event_loop
-                .create_window(
-                    winit::window::WindowAttributes::default().with_title("Weaving — Canvas"),
-                )
-                .unwrap(),
-        );
+        // 지난 실행의 창 크기/위치를 먼저 읽어 창을 만들 때 적용
+        let layout_path = session_layout::default_path();
+        let layout = layout_path
+            .as_deref()
+            .map(SessionLayout::load)
+            .unwrap_or_default();
+        let mut attributes =
+            winit::window::WindowAttributes::default().with_title("Weaving — Canvas");
+        if let Some(geometry) = &layout.window {
+            attributes = geometry.apply(attributes, event_loop);
+        }
+        let window = Arc::new(event_loop.create_window(attributes).unwrap());
+
+        match AppState::new(window) {
+            Ok(mut state) => {
+                state.restore_layout(layout_path, layout);
+                self.state = Some(state);
+            }
+            Err(err) => {
+                eprintln!("초기화 실패: {}", err);
+                event_loop.exit();
+            }
+        }
+    }
 
-        self.state = Some(AppState::new(window));
+    /// 창 닫기 외의 경로로 끝나도 저장하지 않은 보드의 자동 저장은 남김
+    fn exiting(&mut self, _event_loop: &ActiveEventLoop) {
+        if let Some(state) = self.state.as_mut() {
+            state.end_autosave(false);
+        }
     }
 
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _id: WindowId, event: WindowEvent) {
@@ -51,7 +95,8 @@ impl ApplicationHandler for App {
             .on_window_event(&state.window, &event);
 
         match event {
-            WindowEvent::CloseRequested => event_loop.exit(),
+            // 저장하지 않은 탭이 있으면 확인을 받은 뒤 종료
+            WindowEvent::CloseRequested => state.request_quit(),
 
             WindowEvent::KeyboardInput {
                 event:
@@ -63,6 +108,8 @@ impl ApplicationHandler for App {
                 ..
             } => state.handle_keyboard(code, key_state == ElementState::Pressed),
 
+            WindowEvent::ModifiersChanged(modifiers) => state.handle_modifiers(modifiers.state()),
+
             WindowEvent::CursorMoved { position, .. } => {
                 state.handle_cursor_moved(position.x, position.y);
             }
@@ -73,14 +120,34 @@ impl ApplicationHandler for App {
                 ..
             } => state.handle_mouse_button(button_state == ElementState::Pressed),
 
+            WindowEvent::MouseInput {
+                state: button_state,
+                button: MouseButton::Middle,
+                ..
+            } => state.handle_middle_button(button_state == ElementState::Pressed),
+
+            WindowEvent::MouseInput {
+                state: button_state,
+                button: MouseButton::Right,
+                ..
+            } => state.handle_right_button(button_state == ElementState::Pressed),
+
             WindowEvent::MouseWheel { delta, .. } => state.handle_scroll(delta),
 
+            WindowEvent::PinchGesture { delta, .. } => state.handle_pinch(delta),
+
             WindowEvent::Resized(new_size) => state.handle_resize(new_size),
 
             WindowEvent::RedrawRequested => state.render(),
 
             _ => (),
         }
+
+        if state.quit_confirmed {
+            state.save_layout();
+            state.end_autosave(true);
+            event_loop.exit();
+        }
     }
 }
 
@@ -0,0 +1,129 @@
+// 키보드 탐색: 방향키 방향으로 가장 가까운 카드 찾기
+use crate::document::Document;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+// 방향에서 벗어난 거리는 이만큼 더 멀게 침 (같은 줄/열의 카드를 우선)
+const ACROSS_WEIGHT: f32 = 2.0;
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Left,
+        Direction::Right,
+        Direction::Up,
+        Direction::Down,
+    ];
+
+    /// 월드 좌표의 단위 벡터 (y는 위쪽이 +)
+    pub fn vector(self) -> [f32; 2] {
+        match self {
+            Direction::Left => [-1.0, 0.0],
+            Direction::Right => [1.0, 0.0],
+            Direction::Up => [0.0, 1.0],
+            Direction::Down => [0.0, -1.0],
+        }
+    }
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Direction::Left => "left",
+            Direction::Right => "right",
+            Direction::Up => "up",
+            Direction::Down => "down",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Direction::Left => "왼쪽",
+            Direction::Right => "오른쪽",
+            Direction::Up => "위",
+            Direction::Down => "아래",
+        }
+    }
+}
+
+/// from 카드에서 direction 쪽에 있는 카드 중 가장 가까운 카드 (없으면 None)
+pub fn nearest_toward(doc: &Document, from: usize, direction: Direction) -> Option<usize> {
+    let origin = doc.block_positions.get(from)?.position;
+    let [dx, dy] = direction.vector();
+    doc.block_positions
+        .iter()
+        .enumerate()
+        .filter(|&(i, _)| i != from)
+        .filter_map(|(i, inst)| {
+            let offset = [inst.position[0] - origin[0], inst.position[1] - origin[1]];
+            let along = offset[0] * dx + offset[1] * dy;
+            let across = (offset[0] * dy - offset[1] * dx).abs();
+            (along > 0.0).then_some((i, along + across * ACROSS_WEIGHT))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+/// point에 가장 가까운 카드 (탐색을 처음 시작할 때)
+pub fn nearest_to(doc: &Document, point: [f32; 2]) -> Option<usize> {
+    doc.block_positions
+        .iter()
+        .enumerate()
+        .map(|(i, inst)| {
+            let dx = inst.position[0] - point[0];
+            let dy = inst.position[1] - point[1];
+            (i, dx * dx + dy * dy)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::CardKind;
+
+    fn board(positions: &[[f32; 2]]) -> Document {
+        let mut doc = Document::default();
+        for &position in positions {
+            doc.push_card(position, [1.0; 3], CardKind::Text, "");
+        }
+        doc
+    }
+
+    #[test]
+    fn moves_to_the_nearest_card_in_each_direction() {
+        //     2
+        // 1   0   3
+        //     4
+        let doc = board(&[[0.0, 0.0], [-1.0, 0.0], [0.0, 1.0], [1.0, 0.0], [0.0, -1.0]]);
+        let found: Vec<Option<usize>> = Direction::ALL
+            .iter()
+            .map(|&d| nearest_toward(&doc, 0, d))
+            .collect();
+        assert_eq!(found, [Some(1), Some(3), Some(2), Some(4)]);
+        // 가장자리에서는 더 갈 카드가 없음
+        assert_eq!(nearest_toward(&doc, 1, Direction::Left), None);
+        assert_eq!(nearest_toward(&doc, 9, Direction::Left), None);
+    }
+
+    #[test]
+    fn prefers_cards_in_the_same_row_over_closer_diagonal_ones() {
+        // 1은 더 가깝지만 대각선, 2는 멀어도 같은 줄
+        let doc = board(&[[0.0, 0.0], [0.6, 0.6], [1.2, 0.0]]);
+        assert_eq!(nearest_toward(&doc, 0, Direction::Right), Some(2));
+        // 같은 줄에 없으면 대각선 카드라도 찾음
+        assert_eq!(nearest_toward(&doc, 0, Direction::Up), Some(1));
+    }
+
+    #[test]
+    fn starts_from_the_card_nearest_the_point() {
+        let doc = board(&[[0.0, 0.0], [2.0, 2.0], [-1.0, 0.5]]);
+        assert_eq!(nearest_to(&doc, [1.5, 1.0]), Some(1));
+        assert_eq!(nearest_to(&doc, [-0.6, 0.2]), Some(2));
+        assert_eq!(nearest_to(&Document::default(), [0.0, 0.0]), None);
+    }
+}
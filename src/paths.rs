@@ -0,0 +1,35 @@
+// 사용자 데이터/설정 디렉터리 (플랫폼 관례를 환경 변수로 직접 따름)
+use std::env;
+use std::path::PathBuf;
+
+const APP_DIR: &str = "weaving";
+
+fn env_dir(key: &str) -> Option<PathBuf> {
+    env::var_os(key)
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+}
+
+/// 자동 저장, 스냅샷 등 앱이 만드는 데이터 위치. 홈 디렉터리를 모르면 None
+pub fn data_dir() -> Option<PathBuf> {
+    let base = if cfg!(target_os = "windows") {
+        env_dir("LOCALAPPDATA").or_else(|| env_dir("APPDATA"))?
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME")?.join("Library/Application Support")
+    } else {
+        env_dir("XDG_DATA_HOME").or_else(|| Some(env_dir("HOME")?.join(".local/share")))?
+    };
+    Some(base.join(APP_DIR))
+}
+
+/// 사용자 설정 파일 위치. 홈 디렉터리를 모르면 None
+pub fn config_dir() -> Option<PathBuf> {
+    let base = if cfg!(target_os = "windows") {
+        env_dir("APPDATA")?
+    } else if cfg!(target_os = "macos") {
+        env_dir("HOME")?.join("Library/Application Support")
+    } else {
+        env_dir("XDG_CONFIG_HOME").or_else(|| Some(env_dir("HOME")?.join(".config")))?
+    };
+    Some(base.join(APP_DIR))
+}
@@ -5,9 +5,30 @@ pub fn create_card_pipeline(
     shader: &wgpu::ShaderModule,
     format: wgpu::TextureFormat,
     layout: &wgpu::PipelineLayout,
+) -> wgpu::RenderPipeline {
+    create_instanced_pipeline(device, shader, format, layout, "Card Pipeline", "fs_card")
+}
+
+// 카드 쿼드 + 인스턴스 배치를 공유하고 프래그먼트만 다른 비교 오버레이용
+pub fn create_diff_pipeline(
+    device: &wgpu::Device,
+    shader: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    layout: &wgpu::PipelineLayout,
+) -> wgpu::RenderPipeline {
+    create_instanced_pipeline(device, shader, format, layout, "Diff Pipeline", "fs_diff")
+}
+
+fn create_instanced_pipeline(
+    device: &wgpu::Device,
+    shader: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    layout: &wgpu::PipelineLayout,
+    label: &str,
+    fragment_entry: &str,
 ) -> wgpu::RenderPipeline {
     device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-        label: Some("Card Pipeline"),
+        label: Some(label),
         layout: Some(layout),
         vertex: wgpu::VertexState {
             module: shader,
@@ -28,7 +49,7 @@ pub fn create_card_pipeline(
         },
         fragment: Some(wgpu::FragmentState {
             module: shader,
-            entry_point: Some("fs_card"),
+            entry_point: Some(fragment_entry),
             targets: &[Some(wgpu::ColorTargetState {
                 format,
                 blend: Some(wgpu::BlendState::ALPHA_BLENDING),
@@ -1,17 +1,80 @@
+use crate::commands::Command;
+use crate::document::{CardKind, Document};
+use crate::selection::MarqueeKind;
+use crate::snapping::SnapGuide;
 use crate::state::{AppState, InteractionMode};
 use crate::types::*;
 use crate::ui;
+use crate::ui::left_tab::LeftSection;
+
+// 카드 라벨을 그리기 시작하는 최소 화면 폭 (px)
+const LABEL_MIN_CARD_WIDTH: f32 = 60.0;
 
 struct EguiFrameResult {
     full_output: egui::FullOutput,
-    toolbar_action: ui::toolbar::ToolbarAction,
+    toolbar_command: Option<Command>,
+    palette_command: Option<Command>,
+    left_tab_command: Option<Command>,
+    file_request: Option<ui::file_dialog::FileDialogRequest>,
+    pasted_text: Option<String>,
+    recovery_choice: Option<ui::recovery_dialog::RecoveryChoice>,
+    inspector_action: ui::inspector::InspectorAction,
+    top_bar_action: ui::top_bar::TopBarAction,
+    project_action: ui::project_browser::ProjectBrowserAction,
+    settings_action: ui::settings_panel::SettingsAction,
+    context_menu_action: ui::context_menu::ContextMenuAction,
+}
+
+/// 월드 좌표 → 화면(egui) 좌표
+pub fn world_to_screen(
+    world: [f32; 2],
+    camera_position: [f32; 2],
+    camera_zoom: f32,
+    canvas_rect: egui::Rect,
+) -> egui::Pos2 {
+    let aspect = if canvas_rect.width() > 0.0 && canvas_rect.height() > 0.0 {
+        canvas_rect.width() / canvas_rect.height()
+    } else {
+        1.0
+    };
+    let ndc = [
+        (world[0] - camera_position[0]) * camera_zoom / aspect,
+        (world[1] - camera_position[1]) * camera_zoom,
+    ];
+    egui::pos2(
+        canvas_rect.min.x + (ndc[0] + 1.0) * 0.5 * canvas_rect.width(),
+        canvas_rect.min.y + (-ndc[1] + 1.0) * 0.5 * canvas_rect.height(),
+    )
 }
 
 impl AppState {
     pub fn render(&mut self) {
+        self.poll_benchmark();
+        self.poll_previews();
+        let camera_animating = self.tick_camera_animation();
         self.update_camera_buffer();
 
-        let output = self.surface.get_current_texture().unwrap();
+        let output = match self.surface.get_current_texture() {
+            Ok(output) => output,
+            // 창 상태 변화로 서피스가 무효화되면 재구성 후 다음 프레임에 다시 그림
+            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                self.surface.configure(&self.device, &self.config);
+                self.window.request_redraw();
+                return;
+            }
+            Err(wgpu::SurfaceError::Timeout) => {
+                self.window.request_redraw();
+                return;
+            }
+            Err(err) => {
+                // 복구 불가능한 오류일 수 있으니 현재 문서를 스냅샷으로 남김
+                eprintln!("서피스 텍스처를 얻지 못했습니다: {}", err);
+                if let Some(journal) = &mut self.journal {
+                    journal.snapshot(&self.document);
+                }
+                return;
+            }
+        };
         let view = output
             .texture
             .create_view(&wgpu::TextureViewDescriptor::default());
@@ -23,7 +86,27 @@ impl AppState {
         let frame_result = self.build_egui_frame();
 
         // 2) 툴바 액션 처리
-        self.apply_toolbar_actions(&frame_result.toolbar_action);
+        self.apply_top_bar_action(&frame_result.top_bar_action);
+        self.apply_project_action(&frame_result.project_action);
+        self.apply_settings_action(&frame_result.settings_action);
+        if let Some(request) = &frame_result.file_request {
+            self.apply_file_request(request);
+        }
+        if let Some(text) = &frame_result.pasted_text {
+            self.paste_outline(text);
+        }
+        if let Some(choice) = frame_result.recovery_choice {
+            self.apply_recovery_choice(choice);
+        }
+        self.apply_inspector_action(&frame_result.inspector_action);
+        self.apply_ui_commands(
+            frame_result.toolbar_command,
+            frame_result.palette_command,
+            frame_result.left_tab_command,
+            &frame_result.context_menu_action,
+        );
+        self.refresh_diff();
+        self.refresh_stats();
 
         // 3) egui 텍스처/버퍼 업데이트
         self.egui
@@ -75,7 +158,8 @@ impl AppState {
             .viewport_output
             .values()
             .any(|v| v.repaint_delay.is_zero());
-        if needs_repaint {
+        // 카메라 전환 중에만 계속 다시 그림
+        if needs_repaint || camera_animating {
             self.window.request_redraw();
         }
     }
@@ -86,22 +170,58 @@ impl AppState {
 
         let mut canvas_rect = self.egui.canvas_rect;
         let mut top_bar_state = std::mem::take(&mut self.top_bar_state);
-        let mut left_tab_state = std::mem::take(&mut self.left_tab_state);
+        let left_tab_state = std::mem::take(&mut self.left_tab_state);
         let mut inspector_state = std::mem::take(&mut self.inspector_state);
+        let mut file_dialog_state = std::mem::take(&mut self.file_dialog_state);
+        let mut project_browser_state = std::mem::take(&mut self.project_browser_state);
+        let mut settings_panel_state = std::mem::take(&mut self.settings_panel_state);
+        let mut context_menu_state = std::mem::take(&mut self.context_menu_state);
+        let mut command_palette_state = std::mem::take(&mut self.command_palette_state);
+        let mut paste_error = self.paste_error.take();
+        let palette_entries = if command_palette_state.open {
+            self.palette_entries()
+        } else {
+            Vec::new()
+        };
+        let keymap = &self.keymap;
+        let selection_len = self.selected_indices.len();
+        let show_toolbar = self.settings.show_toolbar;
+        let show_ai_button = self.settings.show_ai_button;
+        let document = &self.document;
         let camera_position = self.camera.position;
         let camera_zoom = self.camera.zoom;
         let drag_select = match self.interaction {
-            InteractionMode::DragSelecting { start, end } => Some((start, end)),
+            InteractionMode::DragSelecting { start, end, .. } => Some((start, end)),
             _ => None,
         };
-        let mut toolbar_action = ui::toolbar::ToolbarAction::default();
+        let lasso = match &self.interaction {
+            InteractionMode::Lasso { points, .. } => points.as_slice(),
+            _ => &[],
+        };
+        let snap_guides = self.snap_guides.as_slice();
+        let navigation_mode = self.navigation_mode;
+        let mut toolbar_command = None;
+        let mut palette_command = None;
+        let mut left_tab_command = None;
+        let mut file_request = None;
+        let mut pasted_text = None;
+        let mut recovery_choice = None;
+        let recovery_prompt = self.recovery_prompt.as_ref();
+        let history = &self.history;
+        let board_stats = self.board_stats.as_ref();
+        let diff = self.diff.as_ref();
+        let mut inspector_action = ui::inspector::InspectorAction::default();
+        let mut top_bar_action = ui::top_bar::TopBarAction::default();
+        let mut project_action = ui::project_browser::ProjectBrowserAction::default();
+        let mut settings_action = ui::settings_panel::SettingsAction::default();
+        let mut context_menu_action = ui::context_menu::ContextMenuAction::default();
 
         let full_output = ctx.run(raw_input, |ctx| {
             egui::TopBottomPanel::top("top_bar")
                 .exact_height(TOP_BAR_HEIGHT)
                 .frame(ui::top_bar::frame())
                 .show(ctx, |ui| {
-                    ui::top_bar::show(ui, &mut top_bar_state);
+                    top_bar_action = ui::top_bar::show(ui, &mut top_bar_state);
                 });
 
             egui::SidePanel::left("left_tab")
@@ -109,17 +229,37 @@ impl AppState {
                 .resizable(false)
                 .frame(ui::left_tab::frame())
                 .show(ctx, |ui| {
-                    ui::left_tab::show(ui, &mut left_tab_state);
+                    left_tab_command = ui::left_tab::show(ui, &left_tab_state);
                 });
 
+            if let Some(section) = left_tab_state.active_section {
+                egui::SidePanel::left("left_section")
+                    .exact_width(SECTION_PANEL_WIDTH)
+                    .resizable(false)
+                    .frame(ui::left_tab::section_frame())
+                    .show(ctx, |ui| match section {
+                        LeftSection::Projects => {
+                            project_action =
+                                ui::project_browser::show(ui, &mut project_browser_state);
+                        }
+                        LeftSection::Dashboard => ui::dashboard::show(ui, board_stats),
+                        LeftSection::Settings => {
+                            settings_action = ui::settings_panel::show(ui, &mut settings_panel_state);
+                        }
+                    });
+            }
+
             if inspector_state.open {
-                egui::SidePanel::right("inspector")
-                    .exact_width(INSPECTOR_WIDTH)
+                let panel = egui::SidePanel::right("inspector")
+                    .default_width(inspector_state.width)
+                    .width_range(INSPECTOR_MIN_WIDTH..=INSPECTOR_MAX_WIDTH)
                     .resizable(true)
                     .frame(ui::inspector::frame())
                     .show(ctx, |ui| {
-                        ui::inspector::show(ui, &mut inspector_state);
+                        inspector_action =
+                            ui::inspector::show(ui, &mut inspector_state, history, diff);
                     });
+                inspector_state.width = panel.response.rect.width();
             }
 
             egui::CentralPanel::default()
@@ -127,52 +267,136 @@ impl AppState {
                 .show(ctx, |ui| {
                     canvas_rect = ui.available_rect_before_wrap();
 
-                    toolbar_action = ui::toolbar::show(ctx, canvas_rect);
+                    if show_toolbar {
+                        toolbar_command = ui::toolbar::show(ctx, canvas_rect, keymap);
+                    }
+
+                    draw_card_labels(ui, document, camera_position, camera_zoom, canvas_rect);
 
                     // 드래그 선택 사각형
                     if let Some((sel_start, sel_end)) = drag_select {
-                        let aspect = if canvas_rect.width() > 0.0 && canvas_rect.height() > 0.0 {
-                            canvas_rect.width() / canvas_rect.height()
-                        } else {
-                            1.0
-                        };
-
-                        let ndc_to_screen = |ndc: [f32; 2]| -> egui::Pos2 {
-                            egui::pos2(
-                                canvas_rect.min.x + (ndc[0] + 1.0) * 0.5 * canvas_rect.width(),
-                                canvas_rect.min.y + (-ndc[1] + 1.0) * 0.5 * canvas_rect.height(),
-                            )
-                        };
-
-                        let ndc_start = [
-                            (sel_start[0] - camera_position[0]) * camera_zoom / aspect,
-                            (sel_start[1] - camera_position[1]) * camera_zoom,
-                        ];
-                        let ndc_end = [
-                            (sel_end[0] - camera_position[0]) * camera_zoom / aspect,
-                            (sel_end[1] - camera_position[1]) * camera_zoom,
-                        ];
-
-                        let p1 = ndc_to_screen(ndc_start);
-                        let p2 = ndc_to_screen(ndc_end);
+                        let p1 =
+                            world_to_screen(sel_start, camera_position, camera_zoom, canvas_rect);
+                        let p2 =
+                            world_to_screen(sel_end, camera_position, camera_zoom, canvas_rect);
 
                         let select_rect = egui::Rect::from_two_pos(p1, p2);
                         let painter = ui.painter();
-                        painter.rect_filled(
-                            select_rect,
-                            0.0,
-                            egui::Color32::from_rgba_premultiplied(59, 130, 246, 30),
-                        );
-                        painter.rect_stroke(
-                            select_rect,
-                            egui::CornerRadius::ZERO,
-                            egui::Stroke::new(1.5, egui::Color32::from_rgb(59, 130, 246)),
-                            egui::StrokeKind::Outside,
-                        );
+                        match MarqueeKind::of(sel_start, sel_end) {
+                            // 완전히 포함: 파란 실선
+                            MarqueeKind::Contain => {
+                                painter.rect_filled(
+                                    select_rect,
+                                    0.0,
+                                    egui::Color32::from_rgba_premultiplied(59, 130, 246, 30),
+                                );
+                                painter.rect_stroke(
+                                    select_rect,
+                                    egui::CornerRadius::ZERO,
+                                    egui::Stroke::new(1.5, egui::Color32::from_rgb(59, 130, 246)),
+                                    egui::StrokeKind::Outside,
+                                );
+                            }
+                            // 걸치기만 해도 선택: 초록 점선
+                            MarqueeKind::Intersect => {
+                                painter.rect_filled(
+                                    select_rect,
+                                    0.0,
+                                    egui::Color32::from_rgba_premultiplied(34, 197, 94, 30),
+                                );
+                                let corners = [
+                                    select_rect.left_top(),
+                                    select_rect.right_top(),
+                                    select_rect.right_bottom(),
+                                    select_rect.left_bottom(),
+                                    select_rect.left_top(),
+                                ];
+                                painter.extend(egui::Shape::dashed_line(
+                                    &corners,
+                                    egui::Stroke::new(1.5, egui::Color32::from_rgb(34, 197, 94)),
+                                    6.0,
+                                    4.0,
+                                ));
+                            }
+                        }
+                    }
+
+                    // 올가미: 그린 경로는 실선, 놓으면 닫힐 구간은 점선
+                    if lasso.len() > 1 {
+                        let points: Vec<egui::Pos2> = lasso
+                            .iter()
+                            .map(|&p| world_to_screen(p, camera_position, camera_zoom, canvas_rect))
+                            .collect();
+                        let stroke = egui::Stroke::new(1.5, egui::Color32::from_rgb(59, 130, 246));
+                        let painter = ui.painter();
+                        painter.add(egui::Shape::line(points.clone(), stroke));
+                        if let (Some(&first), Some(&last)) = (points.first(), points.last()) {
+                            painter.extend(egui::Shape::dashed_line(
+                                &[last, first],
+                                stroke,
+                                6.0,
+                                4.0,
+                            ));
+                        }
                     }
 
-                    ui::ai_button::show(ctx, canvas_rect);
+                    // 카드를 옮기는 동안의 정렬/간격 안내선
+                    let guide_stroke = egui::Stroke::new(1.0, egui::Color32::from_rgb(242, 72, 34));
+                    for guide in snap_guides {
+                        let painter = ui.painter();
+                        match *guide {
+                            SnapGuide::Align { from, to } => {
+                                let from =
+                                    world_to_screen(from, camera_position, camera_zoom, canvas_rect);
+                                let to =
+                                    world_to_screen(to, camera_position, camera_zoom, canvas_rect);
+                                painter.line_segment([from, to], guide_stroke);
+                            }
+                            // 간격 구간 양 끝에 짧은 눈금
+                            SnapGuide::Spacing { from, to } => {
+                                let from =
+                                    world_to_screen(from, camera_position, camera_zoom, canvas_rect);
+                                let to =
+                                    world_to_screen(to, camera_position, camera_zoom, canvas_rect);
+                                painter.line_segment([from, to], guide_stroke);
+                                let tick = (to - from).normalized().rot90() * 4.0;
+                                for end in [from, to] {
+                                    painter.line_segment([end - tick, end + tick], guide_stroke);
+                                }
+                            }
+                        }
+                    }
+
+                    if navigation_mode {
+                        draw_navigation_badge(ui, canvas_rect);
+                    }
+
+                    if show_ai_button {
+                        ui::ai_button::show(ctx, canvas_rect);
+                    }
                 });
+
+            context_menu_action =
+                ui::context_menu::show(ctx, &mut context_menu_state, keymap, selection_len);
+            palette_command =
+                ui::command_palette::show(ctx, &mut command_palette_state, &palette_entries);
+
+            file_request = ui::file_dialog::show(ctx, &mut file_dialog_state);
+            ui::error_dialog::show(ctx, "📋 붙여넣기 실패", &mut paste_error);
+
+            if let Some(prompt) = recovery_prompt {
+                recovery_choice = ui::recovery_dialog::show(ctx, prompt);
+            }
+
+            // 텍스트 입력란 밖에서 붙여넣은 텍스트는 캔버스로 전달
+            if !ctx.wants_keyboard_input() {
+                pasted_text = ctx.input(|i| {
+                    i.events.iter().find_map(|e| match e {
+                        egui::Event::Paste(text) => Some(text.clone()),
+                        _ => None,
+                    })
+                });
+            }
         });
 
         // 상태 복원
@@ -180,44 +404,26 @@ impl AppState {
         self.top_bar_state = top_bar_state;
         self.left_tab_state = left_tab_state;
         self.inspector_state = inspector_state;
+        self.file_dialog_state = file_dialog_state;
+        self.project_browser_state = project_browser_state;
+        self.settings_panel_state = settings_panel_state;
+        self.context_menu_state = context_menu_state;
+        self.command_palette_state = command_palette_state;
+        self.paste_error = paste_error;
 
         EguiFrameResult {
             full_output,
-            toolbar_action,
-        }
-    }
-
-    fn apply_toolbar_actions(&mut self, action: &ui::toolbar::ToolbarAction) {
-        if action.add_node {
-            let color = CARD_COLORS[self.block_positions.len() % CARD_COLORS.len()];
-            self.block_positions.push(InstanceRaw {
-                position: self.camera.position,
-                color,
-            });
-            self.positions_dirty = true;
-        }
-
-        if action.add_batch > 0 {
-            let start = self.block_positions.len();
-            let cols = (action.add_batch as f32).sqrt().ceil() as usize;
-            for i in 0..action.add_batch {
-                let col = (i % cols) as f32;
-                let row = (i / cols) as f32;
-                let color = CARD_COLORS[(start + i) % CARD_COLORS.len()];
-                self.block_positions.push(InstanceRaw {
-                    position: [
-                        self.camera.position[0] + col * GRID_SPACING_X,
-                        self.camera.position[1] + row * GRID_SPACING_Y,
-                    ],
-                    color,
-                });
-            }
-            self.positions_dirty = true;
-        }
-
-        if action.reset {
-            self.block_positions = create_default_grid();
-            self.positions_dirty = true;
+            toolbar_command,
+            palette_command,
+            left_tab_command,
+            file_request,
+            pasted_text,
+            recovery_choice,
+            inspector_action,
+            top_bar_action,
+            project_action,
+            settings_action,
+            context_menu_action,
         }
     }
 
@@ -235,7 +441,7 @@ impl AppState {
                 view,
                 resolve_target: None,
                 ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
+                    load: wgpu::LoadOp::Clear(self.settings.canvas_clear_color()),
                     store: wgpu::StoreOp::Store,
                 },
             })],
@@ -261,7 +467,20 @@ impl AppState {
         rpass.set_pipeline(&self.card_pipeline);
         rpass.set_vertex_buffer(0, self.card_quad_buffer.slice(..));
         rpass.set_vertex_buffer(1, self.instance_buffer.slice(..));
-        rpass.draw(0..4, 0..self.block_positions.len() as u32);
+        rpass.draw(0..4, 0..self.document.len() as u32);
+
+        // 버전 비교 오버레이 (이동 화살표, 추가/삭제 테두리)
+        if self.overlay_line_count > 0 {
+            rpass.set_pipeline(&self.line_pipeline);
+            rpass.set_vertex_buffer(0, self.overlay_line_buffer.slice(..));
+            rpass.draw(0..self.overlay_line_count, 0..1);
+        }
+        if self.overlay_instance_count > 0 {
+            rpass.set_pipeline(&self.diff_pipeline);
+            rpass.set_vertex_buffer(0, self.card_quad_buffer.slice(..));
+            rpass.set_vertex_buffer(1, self.overlay_instance_buffer.slice(..));
+            rpass.draw(0..4, 0..self.overlay_instance_count);
+        }
     }
 
     fn render_egui_pass(
@@ -290,3 +509,74 @@ impl AppState {
             .render(&mut rpass, paint_jobs, screen_descriptor);
     }
 }
+
+/// 확대 시 카드 위에 텍스트 라벨 표시
+fn draw_card_labels(
+    ui: &egui::Ui,
+    document: &Document,
+    camera_position: [f32; 2],
+    camera_zoom: f32,
+    canvas_rect: egui::Rect,
+) {
+    let origin = world_to_screen([0.0, 0.0], camera_position, camera_zoom, canvas_rect);
+    let corner = world_to_screen(
+        [CARD_HALF_W, CARD_HALF_H],
+        camera_position,
+        camera_zoom,
+        canvas_rect,
+    );
+    let half = egui::vec2(corner.x - origin.x, origin.y - corner.y);
+    if half.x * 2.0 < LABEL_MIN_CARD_WIDTH {
+        return;
+    }
+
+    let font = egui::FontId::proportional((half.y * 0.28).clamp(8.0, 28.0));
+    let visible = canvas_rect.expand2(half);
+
+    for (instance, meta) in document.block_positions.iter().zip(&document.cards) {
+        if meta.text.is_empty() {
+            continue;
+        }
+        let center = world_to_screen(instance.position, camera_position, camera_zoom, canvas_rect);
+        if !visible.contains(center) {
+            continue;
+        }
+
+        let card_rect = egui::Rect::from_center_size(center, half * 2.0).shrink(half.y * 0.15);
+        let icon = match meta.kind {
+            CardKind::Text => "",
+            CardKind::File => "📄 ",
+            CardKind::Link => "🔗 ",
+            CardKind::Group => "🗂 ",
+        };
+        let galley = ui.fonts(|f| {
+            f.layout(
+                format!("{}{}", icon, meta.text),
+                font.clone(),
+                egui::Color32::from_rgb(16, 24, 40),
+                card_rect.width(),
+            )
+        });
+        ui.painter()
+            .with_clip_rect(card_rect.intersect(canvas_rect))
+            .galley(card_rect.min, galley, egui::Color32::BLACK);
+    }
+}
+
+/// 키보드 탐색 모드 안내 (캔버스 왼쪽 위)
+fn draw_navigation_badge(ui: &egui::Ui, canvas_rect: egui::Rect) {
+    let galley = ui.fonts(|f| {
+        f.layout_no_wrap(
+            "⌨ 탐색 모드 · 방향키: 카드 이동 · Shift+방향키: 옮기기".to_string(),
+            egui::FontId::proportional(12.0),
+            egui::Color32::WHITE,
+        )
+    });
+    let rect = egui::Rect::from_min_size(
+        canvas_rect.min + egui::vec2(12.0, 12.0),
+        galley.size() + egui::vec2(16.0, 8.0),
+    );
+    let painter = ui.painter();
+    painter.rect_filled(rect, 6.0, egui::Color32::from_rgba_unmultiplied(17, 24, 39, 210));
+    painter.galley(rect.min + egui::vec2(8.0, 4.0), galley, egui::Color32::WHITE);
+}
@@ -0,0 +1,298 @@
+// 클릭/드래그 선택 규칙 (수식키에 따른 선택 합성, 사각형 선택 방식, 올가미 판정)
+use std::collections::HashSet;
+
+use winit::keyboard::ModifiersState;
+
+use crate::document::Document;
+use crate::types::{CARD_HALF_H, CARD_HALF_W};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    // 새로 선택
+    Replace,
+    // Shift: 기존 선택에 더함
+    Add,
+    // Ctrl/Cmd: 선택돼 있으면 빼고 아니면 더함
+    Toggle,
+}
+
+impl SelectionMode {
+    pub fn from_modifiers(modifiers: ModifiersState) -> Self {
+        if modifiers.control_key() || modifiers.super_key() {
+            SelectionMode::Toggle
+        } else if modifiers.shift_key() {
+            SelectionMode::Add
+        } else {
+            SelectionMode::Replace
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MarqueeKind {
+    // 왼쪽 → 오른쪽: 사각형 안에 완전히 들어간 카드만
+    Contain,
+    // 오른쪽 → 왼쪽: 사각형에 걸치기만 해도
+    Intersect,
+}
+
+impl MarqueeKind {
+    pub fn of(start: [f32; 2], end: [f32; 2]) -> Self {
+        if end[0] < start[0] {
+            MarqueeKind::Intersect
+        } else {
+            MarqueeKind::Contain
+        }
+    }
+}
+
+/// 사각형 선택에 걸리는 카드 (드래그 방향으로 방식 결정)
+pub fn marquee_hits(doc: &Document, start: [f32; 2], end: [f32; 2]) -> Vec<usize> {
+    let min = [start[0].min(end[0]), start[1].min(end[1])];
+    let max = [start[0].max(end[0]), start[1].max(end[1])];
+    let kind = MarqueeKind::of(start, end);
+    doc.block_positions
+        .iter()
+        .enumerate()
+        .filter(|(_, inst)| {
+            let [x, y] = inst.position;
+            match kind {
+                MarqueeKind::Contain => {
+                    x - CARD_HALF_W >= min[0]
+                        && x + CARD_HALF_W <= max[0]
+                        && y - CARD_HALF_H >= min[1]
+                        && y + CARD_HALF_H <= max[1]
+                }
+                MarqueeKind::Intersect => {
+                    x + CARD_HALF_W >= min[0]
+                        && x - CARD_HALF_W <= max[0]
+                        && y + CARD_HALF_H >= min[1]
+                        && y - CARD_HALF_H <= max[1]
+                }
+            }
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// 선택에 hits를 합성. 기존 선택의 순서를 유지하고 새 카드는 뒤에 붙임
+pub fn combine(selection: &mut Vec<usize>, hits: &[usize], mode: SelectionMode) {
+    match mode {
+        SelectionMode::Replace => *selection = hits.to_vec(),
+        SelectionMode::Add => {
+            let mut selected: HashSet<usize> = selection.iter().copied().collect();
+            selection.extend(hits.iter().copied().filter(|&idx| selected.insert(idx)));
+        }
+        SelectionMode::Toggle => {
+            let selected: HashSet<usize> = selection.iter().copied().collect();
+            let hit: HashSet<usize> = hits.iter().copied().collect();
+            selection.retain(|idx| !hit.contains(idx));
+            let mut added = HashSet::new();
+            selection.extend(
+                hits.iter()
+                    .copied()
+                    .filter(|idx| !selected.contains(idx) && added.insert(*idx)),
+            );
+        }
+    }
+}
+
+// 올가미 다각형을 가로 띠로 나눠 각 띠에 걸친 변만 모아 둠. 점 판정 시 그 점의 띠만 검사
+const LASSO_BANDS: usize = 64;
+
+struct PolygonIndex {
+    min: [f32; 2],
+    max: [f32; 2],
+    band_height: f32,
+    // 띠별 변 (시작점, 끝점)
+    bands: Vec<Vec<([f32; 2], [f32; 2])>>,
+}
+
+impl PolygonIndex {
+    fn new(polygon: &[[f32; 2]]) -> Option<Self> {
+        if polygon.len() < 3 {
+            return None;
+        }
+        let mut min = polygon[0];
+        let mut max = polygon[0];
+        for p in polygon {
+            min = [min[0].min(p[0]), min[1].min(p[1])];
+            max = [max[0].max(p[0]), max[1].max(p[1])];
+        }
+        let band_height = ((max[1] - min[1]) / LASSO_BANDS as f32).max(f32::EPSILON);
+        let band_of = |y: f32| (((y - min[1]) / band_height) as usize).min(LASSO_BANDS - 1);
+        let mut bands = vec![Vec::new(); LASSO_BANDS];
+        for (i, &a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            for band in &mut bands[band_of(a[1].min(b[1]))..=band_of(a[1].max(b[1]))] {
+                band.push((a, b));
+            }
+        }
+        Some(Self {
+            min,
+            max,
+            band_height,
+            bands,
+        })
+    }
+
+    /// 짝홀 규칙 (오른쪽으로 쏜 반직선이 변을 홀수 번 가로지르면 안쪽)
+    fn contains(&self, p: [f32; 2]) -> bool {
+        if p[0] < self.min[0] || p[0] > self.max[0] || p[1] < self.min[1] || p[1] > self.max[1] {
+            return false;
+        }
+        let band = (((p[1] - self.min[1]) / self.band_height) as usize).min(LASSO_BANDS - 1);
+        let mut inside = false;
+        for &(a, b) in &self.bands[band] {
+            if (a[1] > p[1]) != (b[1] > p[1]) {
+                let x = a[0] + (p[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+                if p[0] < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+/// 중심이 올가미(닫힌 다각형, 월드 좌표) 안에 있는 카드
+pub fn lasso_hits(doc: &Document, polygon: &[[f32; 2]]) -> Vec<usize> {
+    let Some(index) = PolygonIndex::new(polygon) else {
+        return Vec::new();
+    };
+    doc.block_positions
+        .iter()
+        .enumerate()
+        .filter(|(_, inst)| index.contains(inst.position))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::CardKind;
+
+    #[test]
+    fn add_keeps_order_and_skips_selected_cards() {
+        let mut selection = vec![4, 1];
+        combine(&mut selection, &[1, 7, 2, 7], SelectionMode::Add);
+        assert_eq!(selection, [4, 1, 7, 2]);
+    }
+
+    #[test]
+    fn toggle_removes_selected_hits_and_appends_the_rest() {
+        let mut selection = vec![4, 1, 3];
+        combine(&mut selection, &[3, 8, 4, 6], SelectionMode::Toggle);
+        assert_eq!(selection, [1, 8, 6]);
+    }
+
+    #[test]
+    fn combine_scales_to_large_selections() {
+        let n = 200_000;
+        let mut selection: Vec<usize> = (0..n).step_by(2).collect();
+        let hits: Vec<usize> = (0..n).collect();
+        combine(&mut selection, &hits, SelectionMode::Toggle);
+        let odd: Vec<usize> = (1..n).step_by(2).collect();
+        assert_eq!(selection, odd);
+        combine(&mut selection, &hits, SelectionMode::Add);
+        assert_eq!(selection.len(), n);
+    }
+
+    /// 띠 없이 모든 변을 검사하는 짝홀 판정 (PolygonIndex와 비교용)
+    fn contains_naive(polygon: &[[f32; 2]], p: [f32; 2]) -> bool {
+        let mut inside = false;
+        for (i, &a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            if (a[1] > p[1]) != (b[1] > p[1]) {
+                let x = a[0] + (p[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+                if p[0] < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// 바깥 여백을 포함한 격자 점마다 띠 판정과 전체 판정이 같은지
+    fn assert_matches_naive(polygon: &[[f32; 2]]) {
+        let index = PolygonIndex::new(polygon).expect("polygon");
+        let steps = 4 * LASSO_BANDS as i32;
+        for i in -4..=steps + 4 {
+            for j in -4..=steps + 4 {
+                let p = [
+                    index.min[0] + (index.max[0] - index.min[0]) * i as f32 / steps as f32,
+                    index.min[1] + (index.max[1] - index.min[1]) * j as f32 / steps as f32,
+                ];
+                assert_eq!(index.contains(p), contains_naive(polygon, p), "{:?}", p);
+            }
+        }
+    }
+
+    #[test]
+    fn lasso_handles_concave_polygons() {
+        // U자: 가운데 홈(1~2, 1~3)은 바깥
+        let polygon = [
+            [0.0, 0.0],
+            [3.0, 0.0],
+            [3.0, 3.0],
+            [2.0, 3.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 3.0],
+            [0.0, 3.0],
+        ];
+        let mut doc = Document::default();
+        for center in [[0.5, 2.0], [1.5, 2.0], [2.5, 2.0], [1.5, 0.5], [4.0, 1.0]] {
+            doc.push_card(center, [1.0; 3], CardKind::Text, "");
+        }
+        assert_eq!(lasso_hits(&doc, &polygon), [0, 2, 3]);
+        assert_matches_naive(&polygon);
+    }
+
+    #[test]
+    fn lasso_points_on_band_boundaries() {
+        // 높이 64 → 띠 높이 1, 정수 y가 모두 띠 경계. 꼭짓점도 경계 위에 있음
+        let diamond = [[32.0, 0.0], [64.0, 32.0], [32.0, 64.0], [0.0, 32.0]];
+        let index = PolygonIndex::new(&diamond).expect("polygon");
+        assert_eq!(index.band_height, 1.0);
+        assert!(index.contains([32.0, 32.0]));
+        assert!(index.contains([63.5, 32.0]));
+        assert!(index.contains([32.0, 10.0]));
+        assert!(!index.contains([60.0, 10.0]));
+        assert_matches_naive(&diamond);
+    }
+
+    #[test]
+    fn lasso_handles_horizontal_edges() {
+        // 계단 모양: y = 1에 안쪽으로 꺾인 가로 변
+        let step = [
+            [0.0, 0.0],
+            [4.0, 0.0],
+            [4.0, 1.0],
+            [2.0, 1.0],
+            [2.0, 2.0],
+            [0.0, 2.0],
+        ];
+        let index = PolygonIndex::new(&step).expect("polygon");
+        assert!(index.contains([3.0, 0.5]));
+        assert!(index.contains([1.0, 1.5]));
+        assert!(index.contains([1.0, 1.0]));
+        assert!(!index.contains([3.0, 1.5]));
+        assert_matches_naive(&step);
+
+        // 높이가 0인 다각형(한 줄로 그은 올가미)은 아무것도 담지 않음
+        let flat = [[0.0, 1.0], [2.0, 1.0], [4.0, 1.0]];
+        let index = PolygonIndex::new(&flat).expect("polygon");
+        assert!(!index.contains([1.0, 1.0]));
+    }
+
+    #[test]
+    fn lasso_needs_at_least_three_points() {
+        let mut doc = Document::default();
+        doc.push_card([0.0, 0.0], [1.0; 3], CardKind::Text, "");
+        assert!(PolygonIndex::new(&[[-1.0, -1.0], [1.0, 1.0]]).is_none());
+        assert!(lasso_hits(&doc, &[]).is_empty());
+        assert!(lasso_hits(&doc, &[[-1.0, -1.0], [1.0, 1.0]]).is_empty());
+    }
+}
@@ -0,0 +1,222 @@
+// 다음 실행 때 되살릴 창/패널 배치와 보드별 카메라 (설정 디렉터리의 layout.json)
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event_loop::ActiveEventLoop;
+use winit::window::WindowAttributes;
+
+use crate::camera::Camera;
+use crate::paths;
+use crate::ui::left_tab::LeftSection;
+
+const FILE_NAME: &str = "layout.json";
+
+// 이보다 작은 창 크기는 잘못 저장된 값으로 보고 무시
+const MIN_WINDOW_WIDTH: u32 = 400;
+const MIN_WINDOW_HEIGHT: u32 = 300;
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub width: u32,
+    pub height: u32,
+    // 플랫폼이 창 위치를 알려주지 않으면 None
+    pub position: Option<[i32; 2]>,
+    pub maximized: bool,
+}
+
+impl WindowGeometry {
+    /// 크기/최대화를 적용하고, 위치는 지금 연결된 모니터 안에 있을 때만 적용
+    pub fn apply(&self, attrs: WindowAttributes, event_loop: &ActiveEventLoop) -> WindowAttributes {
+        let mut attrs = attrs.with_maximized(self.maximized);
+        if self.width >= MIN_WINDOW_WIDTH && self.height >= MIN_WINDOW_HEIGHT {
+            attrs = attrs.with_inner_size(PhysicalSize::new(self.width, self.height));
+        }
+        if let Some([x, y]) = self.position {
+            let on_screen = event_loop.available_monitors().any(|monitor| {
+                let (origin, size) = (monitor.position(), monitor.size());
+                x >= origin.x
+                    && y >= origin.y
+                    && x < origin.x + size.width as i32
+                    && y < origin.y + size.height as i32
+            });
+            if on_screen {
+                attrs = attrs.with_position(PhysicalPosition::new(x, y));
+            }
+        }
+        attrs
+    }
+}
+
+// 상단 탭 하나. 다음 실행 때 같은 자리의 같은 이름 탭이 보드 ID를 이어받음
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedTab {
+    pub name: String,
+    pub board: u64,
+}
+
+/// 보드별 카메라 키. 작업 공간 파일에서 연 보드는 파일 경로, 나머지는 보드 ID
+pub fn camera_key(board_id: u64, source: Option<&Path>) -> String {
+    match source {
+        Some(path) => format!("file:{}", path.display()),
+        None => format!("board:{:016x}", board_id),
+    }
+}
+
+/// 파일 경로 키인데 파일이 남아 있으면 true (닫은 보드를 다시 열 때 카메라를 되살림)
+fn is_existing_file_key(key: &str) -> bool {
+    key.strip_prefix("file:")
+        .is_some_and(|path| Path::new(path).is_file())
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedCamera {
+    pub position: [f32; 2],
+    pub zoom: f32,
+}
+
+impl SavedCamera {
+    pub fn of(camera: &Camera) -> Self {
+        Self {
+            position: camera.position,
+            zoom: camera.zoom,
+        }
+    }
+
+    /// 손상된 값(NaN, 0 이하 배율)이면 None
+    pub fn to_camera(self) -> Option<Camera> {
+        let finite = self.position.iter().all(|v| v.is_finite());
+        (finite && self.zoom.is_finite() && self.zoom > 0.0)
+            .then(|| Camera::new(self.position, self.zoom))
+    }
+}
+
+// 항목이 없으면 기본 배치를 그대로 씀
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionLayout {
+    pub window: Option<WindowGeometry>,
+    // 마지막에 인스펙터가 열려 있었는지 (없으면 설정의 show_inspector)
+    pub inspector_open: Option<bool>,
+    pub inspector_width: Option<f32>,
+    pub inspector_tab: Option<usize>,
+    pub left_section: Option<LeftSection>,
+    // 활성 상단 탭 이름
+    pub active_tab: Option<String>,
+    pub tabs: Vec<SavedTab>,
+    // camera_key별 마지막 카메라
+    pub cameras: BTreeMap<String, SavedCamera>,
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    Some(paths::config_dir()?.join(FILE_NAME))
+}
+
+impl SessionLayout {
+    /// 없거나 읽을 수 없으면 기본 배치
+    pub fn load(path: &Path) -> SessionLayout {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return SessionLayout::default(),
+            Err(err) => {
+                eprintln!("창 배치를 읽지 못했습니다: {}", err);
+                return SessionLayout::default();
+            }
+        };
+        serde_json::from_str(&text).unwrap_or_else(|err| {
+            eprintln!("창 배치 파일 형식 오류: {}", err);
+            SessionLayout::default()
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, path)
+    }
+
+    pub fn camera_for(&self, key: &str) -> Option<Camera> {
+        self.cameras.get(key)?.to_camera()
+    }
+
+    /// 열린 탭도, 남아 있는 파일도 아닌 카메라 항목 삭제
+    pub fn prune_cameras(&mut self, open: &[String]) {
+        self.cameras
+            .retain(|key, _| open.contains(key) || is_existing_file_key(key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved(x: f32) -> SavedCamera {
+        SavedCamera {
+            position: [x, 0.0],
+            zoom: 1.0,
+        }
+    }
+
+    #[test]
+    fn boards_with_the_same_name_get_separate_keys() {
+        let a = camera_key(1, None);
+        let b = camera_key(2, None);
+        assert_ne!(a, b);
+        let path = Path::new("보드.canvas");
+        assert_eq!(camera_key(1, Some(path)), camera_key(2, Some(path)));
+        assert_ne!(camera_key(1, Some(path)), a);
+    }
+
+    #[test]
+    fn prune_keeps_open_boards_and_existing_files() {
+        let dir = std::env::temp_dir().join(format!("layout-prune-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("temp dir");
+        let kept_file = dir.join("kept.canvas");
+        fs::write(&kept_file, "{}").expect("write file");
+
+        let open = camera_key(1, None);
+        let closed = camera_key(2, None);
+        let existing = camera_key(3, Some(&kept_file));
+        let missing = camera_key(4, Some(&dir.join("missing.canvas")));
+        let mut layout = SessionLayout::default();
+        for (i, key) in [&open, &closed, &existing, &missing]
+            .into_iter()
+            .enumerate()
+        {
+            layout.cameras.insert(key.clone(), saved(i as f32));
+        }
+
+        layout.prune_cameras(std::slice::from_ref(&open));
+        assert_eq!(layout.cameras.len(), 2);
+        assert!(layout.cameras.contains_key(&open));
+        assert!(layout.cameras.contains_key(&existing));
+        fs::remove_dir_all(&dir).expect("cleanup");
+    }
+
+    #[test]
+    fn inspector_open_round_trips_and_is_absent_in_older_files() {
+        let path = std::env::temp_dir()
+            .join(format!("layout-inspector-{}", std::process::id()))
+            .join(FILE_NAME);
+        let layout = SessionLayout {
+            inspector_open: Some(false),
+            ..Default::default()
+        };
+        layout.save(&path).expect("saves");
+        assert_eq!(SessionLayout::load(&path).inspector_open, Some(false));
+
+        // 항목이 없던 파일은 설정을 따르도록 None
+        fs::write(&path, r#"{ "inspector_width": 320.0 }"#).expect("write file");
+        assert_eq!(SessionLayout::load(&path).inspector_open, None);
+        if let Some(dir) = path.parent() {
+            fs::remove_dir_all(dir).expect("cleanup");
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use crate::session_layout::{SavedCamera, SavedTab, SessionLayout, WindowGeometry};
+use crate::state::AppState;
+use crate::types::{INSPECTOR_MAX_WIDTH, INSPECTOR_MIN_WIDTH};
+
+impl AppState {
+    /// 시작 시 지난 실행의 패널 배치, 탭, 보드별 카메라를 되살림.
+    /// 저장된 배치가 없을 때만 설정(show_inspector)대로 인스펙터를 엶
+    pub fn restore_layout(&mut self, path: Option<PathBuf>, layout: SessionLayout) {
+        if let Some(open) = layout.inspector_open {
+            self.inspector_state.open = open;
+        }
+        if let Some(width) = layout.inspector_width.filter(|w| w.is_finite()) {
+            self.inspector_state.width = width.clamp(INSPECTOR_MIN_WIDTH, INSPECTOR_MAX_WIDTH);
+        }
+        if let Some(tab) = layout.inspector_tab.filter(|&t| t <= 2) {
+            self.inspector_state.active_tab = tab;
+        }
+        self.left_tab_state.active_section = layout.left_section;
+
+        // 같은 자리의 같은 이름 탭은 지난 실행의 보드 ID를 이어받아 카메라를 찾음
+        // (자동 저장 디렉터리도 ID를 따르므로 시작한 기록을 새 ID로 다시 시작)
+        let active = self.top_bar_state.active_tab;
+        let mut adopted = false;
+        for (i, saved) in layout.tabs.iter().enumerate() {
+            if self.top_bar_state.tabs.get(i) != Some(&saved.name) {
+                continue;
+            }
+            if i == active {
+                self.board_id = saved.board;
+            } else if let Some(board) = self.boards.get_mut(i) {
+                board.id = saved.board;
+            }
+            adopted = true;
+        }
+        if adopted {
+            self.finish_autosave();
+            self.start_autosave();
+        }
+        for i in 0..self.top_bar_state.tabs.len() {
+            let Some(camera) = layout.camera_for(&self.tab_camera_key(i)) else {
+                continue;
+            };
+            if i == active {
+                self.camera = camera;
+            } else if let Some(board) = self.boards.get_mut(i) {
+                board.camera = camera;
+            }
+        }
+        let restored_tab = layout
+            .active_tab
+            .as_ref()
+            .and_then(|name| self.top_bar_state.tabs.iter().position(|t| t == name));
+        self.session_layout = layout;
+        self.session_layout_path = path;
+        if let Some(idx) = restored_tab {
+            self.switch_tab(idx);
+        }
+        self.update_camera_buffer();
+        self.window.request_redraw();
+    }
+
+    /// 종료 직전 현재 배치를 기록. 최대화 상태면 이전의 보통 크기/위치를 유지
+    pub fn save_layout(&mut self) {
+        let size = self.window.inner_size();
+        let maximized = self.window.is_maximized();
+        let current = WindowGeometry {
+            width: size.width,
+            height: size.height,
+            position: self.window.outer_position().ok().map(|p| [p.x, p.y]),
+            maximized,
+        };
+        let layout = &mut self.session_layout;
+        layout.window = Some(match layout.window {
+            Some(previous) if maximized => WindowGeometry {
+                maximized,
+                ..previous
+            },
+            _ => current,
+        });
+        layout.inspector_open = Some(self.inspector_state.open);
+        layout.inspector_width = Some(self.inspector_state.width);
+        layout.inspector_tab = Some(self.inspector_state.active_tab);
+        layout.left_section = self.left_tab_state.active_section;
+
+        let active = self.top_bar_state.active_tab;
+        let keys: Vec<String> = (0..self.boards.len())
+            .map(|i| self.tab_camera_key(i))
+            .collect();
+        let layout = &mut self.session_layout;
+        layout.active_tab = self.top_bar_state.tabs.get(active).cloned();
+        layout.tabs.clear();
+        for (i, name) in self.top_bar_state.tabs.iter().enumerate() {
+            let (board, camera) = if i == active {
+                (self.board_id, &self.camera)
+            } else {
+                (self.boards[i].id, &self.boards[i].camera)
+            };
+            layout.tabs.push(SavedTab {
+                name: name.clone(),
+                board,
+            });
+            layout
+                .cameras
+                .insert(keys[i].clone(), SavedCamera::of(camera));
+        }
+        layout.prune_cameras(&keys);
+
+        let Some(path) = &self.session_layout_path else {
+            return;
+        };
+        if let Err(err) = layout.save(path) {
+            eprintln!("창 배치를 저장하지 못했습니다: {}", err);
+        }
+    }
+}
@@ -0,0 +1,346 @@
+// 사용자 설정 (설정 디렉터리의 settings.json)
+//
+// 필드별로 따로 읽어서 잘못된 값 하나 때문에 나머지 설정을 잃지 않게 하고,
+// 읽지 못했거나 범위를 벗어난 값은 기본값/경계값으로 바꾼 뒤 경고로 알린다.
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::formats::{parse_hex_color, to_hex_color};
+use crate::paths;
+use crate::types::CARD_COLORS;
+
+const FILE_NAME: &str = "settings.json";
+
+pub const ZOOM_STEP_RANGE: RangeInclusive<f32> = 1.01..=2.0;
+pub const GRID_SIZE_RANGE: RangeInclusive<f32> = 0.01..=1.0;
+pub const PAN_FRICTION_RANGE: RangeInclusive<f32> = 1.0..=20.0;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    #[default]
+    Light,
+    Dark,
+}
+
+// 마우스 휠(줄 단위 스크롤)의 동작. 트랙패드 스크롤은 항상 이동
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WheelAction {
+    #[default]
+    Zoom,
+    Pan,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub theme: Theme,
+    pub wheel_action: WheelAction,
+    // 휠 한 칸당 확대 배율
+    pub zoom_step: f32,
+    // 끌어 이동을 놓은 뒤 미끄러지듯 이어서 이동
+    pub pan_inertia: bool,
+    // 관성 이동의 초당 감쇠율 (클수록 빨리 멈춤)
+    pub pan_friction: f32,
+    // 새 카드 테두리 색 ("#rrggbb"). None이면 팔레트 순환
+    pub default_card_color: Option<String>,
+    pub snap_to_grid: bool,
+    // 격자 간격 (월드 단위)
+    pub grid_size: f32,
+    // 카드를 옮길 때 주변 카드와의 정렬/간격 안내선
+    pub alignment_guides: bool,
+    pub show_inspector: bool,
+    pub show_toolbar: bool,
+    pub show_ai_button: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::Light,
+            wheel_action: WheelAction::Zoom,
+            zoom_step: 1.1,
+            pan_inertia: true,
+            pan_friction: 5.0,
+            default_card_color: None,
+            snap_to_grid: false,
+            grid_size: 0.05,
+            alignment_guides: true,
+            show_inspector: true,
+            show_toolbar: true,
+            show_ai_button: true,
+        }
+    }
+}
+
+pub fn default_path() -> Option<PathBuf> {
+    Some(paths::config_dir()?.join(FILE_NAME))
+}
+
+fn field<T: DeserializeOwned>(
+    object: &serde_json::Map<String, serde_json::Value>,
+    key: &str,
+    slot: &mut T,
+    warnings: &mut Vec<String>,
+) {
+    let Some(value) = object.get(key) else {
+        return;
+    };
+    match serde_json::from_value(value.clone()) {
+        Ok(parsed) => *slot = parsed,
+        Err(err) => warnings.push(format!("'{}' 값을 읽지 못해 기본값 사용: {}", key, err)),
+    }
+}
+
+fn clamp_to(value: &mut f32, range: &RangeInclusive<f32>, key: &str, warnings: &mut Vec<String>) {
+    if range.contains(value) {
+        return;
+    }
+    let clamped = if value.is_nan() {
+        *range.start()
+    } else {
+        value.clamp(*range.start(), *range.end())
+    };
+    warnings.push(format!(
+        "'{}' 값 {}이(가) 범위({}~{})를 벗어나 {}(으)로 조정",
+        key,
+        value,
+        range.start(),
+        range.end(),
+        clamped
+    ));
+    *value = clamped;
+}
+
+impl Settings {
+    /// JSON 텍스트 → 설정과 경고 목록. 문법 오류면 전부 기본값
+    pub fn parse(text: &str) -> (Settings, Vec<String>) {
+        let mut settings = Settings::default();
+        let mut warnings = Vec::new();
+        let object = match serde_json::from_str::<serde_json::Value>(text) {
+            Ok(serde_json::Value::Object(object)) => object,
+            Ok(_) => return (settings, vec!["설정 파일이 JSON 객체가 아닙니다".into()]),
+            Err(err) => return (settings, vec![format!("설정 파일 형식 오류: {}", err)]),
+        };
+
+        field(&object, "theme", &mut settings.theme, &mut warnings);
+        field(
+            &object,
+            "wheel_action",
+            &mut settings.wheel_action,
+            &mut warnings,
+        );
+        field(&object, "zoom_step", &mut settings.zoom_step, &mut warnings);
+        field(
+            &object,
+            "pan_inertia",
+            &mut settings.pan_inertia,
+            &mut warnings,
+        );
+        field(
+            &object,
+            "pan_friction",
+            &mut settings.pan_friction,
+            &mut warnings,
+        );
+        field(
+            &object,
+            "default_card_color",
+            &mut settings.default_card_color,
+            &mut warnings,
+        );
+        field(
+            &object,
+            "snap_to_grid",
+            &mut settings.snap_to_grid,
+            &mut warnings,
+        );
+        field(&object, "grid_size", &mut settings.grid_size, &mut warnings);
+        field(
+            &object,
+            "alignment_guides",
+            &mut settings.alignment_guides,
+            &mut warnings,
+        );
+        field(
+            &object,
+            "show_inspector",
+            &mut settings.show_inspector,
+            &mut warnings,
+        );
+        field(
+            &object,
+            "show_toolbar",
+            &mut settings.show_toolbar,
+            &mut warnings,
+        );
+        field(
+            &object,
+            "show_ai_button",
+            &mut settings.show_ai_button,
+            &mut warnings,
+        );
+
+        warnings.extend(settings.validate());
+        (settings, warnings)
+    }
+
+    /// 범위를 벗어난 값을 고치고 고친 내용을 반환
+    pub fn validate(&mut self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        clamp_to(
+            &mut self.zoom_step,
+            &ZOOM_STEP_RANGE,
+            "zoom_step",
+            &mut warnings,
+        );
+        clamp_to(
+            &mut self.pan_friction,
+            &PAN_FRICTION_RANGE,
+            "pan_friction",
+            &mut warnings,
+        );
+        clamp_to(
+            &mut self.grid_size,
+            &GRID_SIZE_RANGE,
+            "grid_size",
+            &mut warnings,
+        );
+        if let Some(color) = &self.default_card_color {
+            match parse_hex_color(color) {
+                // 표기를 "#rrggbb" 소문자로 통일
+                Some(rgb) => self.default_card_color = Some(to_hex_color(rgb)),
+                None => {
+                    warnings.push(format!(
+                        "'default_card_color' 값 '{}'이(가) 색상이 아님",
+                        color
+                    ));
+                    self.default_card_color = None;
+                }
+            }
+        }
+        warnings
+    }
+
+    /// 파일이 없으면 기본값 (경고 없음)
+    pub fn load(path: &Path) -> (Settings, Vec<String>) {
+        match fs::read_to_string(path) {
+            Ok(text) => Settings::parse(&text),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => (Settings::default(), Vec::new()),
+            Err(err) => (
+                Settings::default(),
+                vec![format!("설정 파일을 읽지 못했습니다: {}", err)],
+            ),
+        }
+    }
+
+    /// 임시 파일에 쓴 뒤 교체 (쓰는 도중 종료돼도 이전 설정 유지)
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let text = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, path)
+    }
+
+    /// n번째로 만드는 카드의 테두리 색
+    pub fn card_color(&self, n: usize) -> [f32; 3] {
+        self.default_card_color
+            .as_deref()
+            .and_then(parse_hex_color)
+            .unwrap_or(CARD_COLORS[n % CARD_COLORS.len()])
+    }
+
+    pub fn canvas_clear_color(&self) -> wgpu::Color {
+        match self.theme {
+            Theme::Light => wgpu::Color::WHITE,
+            Theme::Dark => wgpu::Color {
+                r: 0.09,
+                g: 0.09,
+                b: 0.11,
+                a: 1.0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_fields_keep_defaults_without_warnings() {
+        let (settings, warnings) = Settings::parse(r#"{ "theme": "dark" }"#);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(
+            settings,
+            Settings {
+                theme: Theme::Dark,
+                ..Settings::default()
+            }
+        );
+    }
+
+    #[test]
+    fn one_bad_field_keeps_the_others() {
+        let (settings, warnings) = Settings::parse(
+            r#"{ "theme": "purple", "wheel_action": "pan", "snap_to_grid": "yes", "grid_size": 0.2 }"#,
+        );
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+        assert!(warnings[0].contains("theme"));
+        assert!(warnings[1].contains("snap_to_grid"));
+        assert_eq!(settings.theme, Theme::Light);
+        assert!(!settings.snap_to_grid);
+        assert_eq!(settings.wheel_action, WheelAction::Pan);
+        assert_eq!(settings.grid_size, 0.2);
+    }
+
+    #[test]
+    fn out_of_range_values_are_clamped_with_a_warning() {
+        let (settings, warnings) =
+            Settings::parse(r#"{ "zoom_step": 5.0, "pan_friction": 0.1, "grid_size": 0.5 }"#);
+        assert_eq!(settings.zoom_step, *ZOOM_STEP_RANGE.end());
+        assert_eq!(settings.pan_friction, *PAN_FRICTION_RANGE.start());
+        assert_eq!(settings.grid_size, 0.5);
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+        assert!(warnings[0].contains("zoom_step"));
+        assert!(warnings[1].contains("pan_friction"));
+
+        // JSON으로는 못 쓰지만 설정 화면 등에서 들어온 NaN은 하한으로
+        let mut settings = Settings {
+            grid_size: f32::NAN,
+            ..Settings::default()
+        };
+        assert_eq!(settings.validate().len(), 1);
+        assert_eq!(settings.grid_size, *GRID_SIZE_RANGE.start());
+    }
+
+    #[test]
+    fn card_color_is_normalized_or_reset() {
+        let (settings, warnings) = Settings::parse(r##"{ "default_card_color": "#FFAA00" }"##);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(settings.default_card_color.as_deref(), Some("#ffaa00"));
+        assert_eq!(settings.card_color(3), [1.0, 170.0 / 255.0, 0.0]);
+
+        let (settings, warnings) = Settings::parse(r#"{ "default_card_color": "orange" }"#);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert_eq!(settings.default_card_color, None);
+        assert_eq!(settings.card_color(1), CARD_COLORS[1]);
+    }
+
+    #[test]
+    fn non_object_files_fall_back_to_defaults() {
+        for text in ["[1, 2]", "\"dark\"", "{ broken"] {
+            let (settings, warnings) = Settings::parse(text);
+            assert_eq!(settings, Settings::default());
+            assert_eq!(warnings.len(), 1, "{}", text);
+        }
+    }
+}
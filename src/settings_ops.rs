@@ -0,0 +1,47 @@
+use crate::settings::{Settings, Theme};
+use crate::state::AppState;
+use crate::ui::settings_panel::SettingsAction;
+
+impl AppState {
+    /// 테마, 패널 표시 등 설정을 화면에 반영 (시작 시와 설정 변경 시)
+    pub fn apply_settings(&mut self) {
+        self.egui.ctx.set_visuals(match self.settings.theme {
+            Theme::Light => egui::Visuals::light(),
+            Theme::Dark => egui::Visuals::dark(),
+        });
+        self.window.request_redraw();
+    }
+
+    pub fn apply_settings_action(&mut self, action: &SettingsAction) {
+        if action.reload_keymap {
+            self.reload_keymap();
+        }
+        let panel = &mut self.settings_panel_state;
+        if action.reset {
+            panel.draft = Settings::default();
+        }
+        if action.changed || action.reset {
+            panel.warnings = panel.draft.validate();
+            // 인스펙터 표시는 이 설정을 바꿨을 때만 따름 (패널에서 직접 여닫은 상태를 유지)
+            if panel.draft.show_inspector != self.settings.show_inspector {
+                self.inspector_state.open = panel.draft.show_inspector;
+            }
+            self.settings = panel.draft.clone();
+            self.settings_dirty = true;
+            self.apply_settings();
+        }
+
+        // 슬라이더를 끄는 동안에는 매 프레임 쓰지 않고 손을 뗀 뒤 한 번 저장
+        if self.settings_dirty && !self.egui.ctx.is_using_pointer() {
+            self.settings_dirty = false;
+            let Some(path) = &self.settings_path else {
+                return;
+            };
+            if let Err(err) = self.settings.save(path) {
+                self.settings_panel_state
+                    .warnings
+                    .push(format!("설정을 저장하지 못했습니다: {}", err));
+            }
+        }
+    }
+}
@@ -88,3 +88,23 @@ fn fs_card(in: VertexOutput) -> @location(0) vec4<f32> {
 fn fs_line(in: VertexOutput) -> @location(0) vec4<f32> {
     return vec4<f32>(in.color, 1.0);
 }
+
+// 버전 비교 오버레이 — 인스턴스 색상의 굵은 테두리 + 옅은 채우기
+@fragment
+fn fs_diff(in: VertexOutput) -> @location(0) vec4<f32> {
+    let half_size = vec2<f32>(0.25, 0.15);
+    let r = 0.022;
+    let bw = 0.014;
+
+    let q = abs(in.local_pos) - (half_size - vec2<f32>(r));
+    let d = length(max(q, vec2<f32>(0.0))) - r;
+    let aa = fwidth(d);
+    let outer = 1.0 - smoothstep(-aa * 0.5, aa * 0.5, d);
+    let inner = 1.0 - smoothstep(-aa * 0.5, aa * 0.5, d + bw);
+
+    let a = (outer - inner) * 0.9 + inner * 0.15;
+    if a < 0.001 {
+        discard;
+    }
+    return vec4<f32>(in.color, a);
+}
@@ -0,0 +1,410 @@
+// 카드를 끌어 옮길 때의 맞춤: 주변 카드의 가장자리/중심 정렬, 같은 간격, 격자
+//
+// 같은 간격은 양옆 카드 사이 가운데, 또는 같은 줄에 이미 있는 카드 사이 간격과
+// 같은 거리만큼 바로 옆 카드에서 떨어진 자리다.
+// 선택 묶음의 경계 상자를 기준으로 축마다 가장 가까운 맞춤 하나를 고른다.
+// 정렬/간격 맞춤이 없는 축만 격자에 맞춘다.
+use crate::document::Document;
+use crate::types::{CARD_HALF_H, CARD_HALF_W};
+
+// 안내선 한 줄 (월드 좌표)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SnapGuide {
+    // 가장자리/중심이 맞은 카드들을 잇는 선
+    Align { from: [f32; 2], to: [f32; 2] },
+    // 같은 간격 표시 (카드 사이 빈 구간)
+    Spacing { from: [f32; 2], to: [f32; 2] },
+}
+
+pub struct SnapOptions {
+    // 이 거리(월드 단위) 안이면 맞춤
+    pub threshold: f32,
+    pub guides: bool,
+    // 격자 간격 (None이면 격자 맞춤 안 함)
+    pub grid: Option<f32>,
+    // 화면에 보이는 월드 영역 (min, max)
+    pub view: ([f32; 2], [f32; 2]),
+}
+
+pub struct SnapResult {
+    // 맞춤을 반영한 이동량
+    pub delta: [f32; 2],
+    pub guides: Vec<SnapGuide>,
+}
+
+#[derive(Copy, Clone)]
+struct Rect {
+    min: [f32; 2],
+    max: [f32; 2],
+}
+
+impl Rect {
+    fn of_card(center: [f32; 2]) -> Self {
+        Self {
+            min: [center[0] - CARD_HALF_W, center[1] - CARD_HALF_H],
+            max: [center[0] + CARD_HALF_W, center[1] + CARD_HALF_H],
+        }
+    }
+
+    fn offset(self, delta: [f32; 2]) -> Self {
+        Self {
+            min: [self.min[0] + delta[0], self.min[1] + delta[1]],
+            max: [self.max[0] + delta[0], self.max[1] + delta[1]],
+        }
+    }
+
+    fn union(self, other: Rect) -> Self {
+        Self {
+            min: [self.min[0].min(other.min[0]), self.min[1].min(other.min[1])],
+            max: [self.max[0].max(other.max[0]), self.max[1].max(other.max[1])],
+        }
+    }
+
+    /// axis 방향의 최소, 중심, 최대
+    fn features(&self, axis: usize) -> [f32; 3] {
+        [
+            self.min[axis],
+            (self.min[axis] + self.max[axis]) * 0.5,
+            self.max[axis],
+        ]
+    }
+
+    /// 다른 축에서 겹치는지 (같은 줄/열에 있는지)
+    fn overlaps_across(&self, other: &Rect, axis: usize) -> bool {
+        let across = 1 - axis;
+        self.min[across] <= other.max[across] && other.min[across] <= self.max[across]
+    }
+}
+
+// 한 축의 맞춤 후보
+#[derive(Copy, Clone)]
+enum AxisSnap {
+    // 이 좌표의 특징선에 맞춤
+    Align(f32),
+    // 양옆 카드 사이 가운데에 맞춤 (왼쪽/아래 카드, 오른쪽/위 카드)
+    Between(Rect, Rect),
+    // 같은 줄의 두 카드(앞, 뒤) 사이 간격만큼 바로 옆 카드에서 띄움.
+    // before면 옆 카드가 앞(왼쪽/아래)에 있음
+    Gap {
+        reference: (Rect, Rect),
+        neighbour: Rect,
+        before: bool,
+    },
+}
+
+fn point(axis: usize, along: f32, across: f32) -> [f32; 2] {
+    if axis == 0 {
+        [along, across]
+    } else {
+        [across, along]
+    }
+}
+
+/// origins: 옮기는 카드들의 원래 중심, anchor: 잡은 카드의 원래 중심 (격자 기준)
+pub fn snap_move(
+    doc: &Document,
+    moving: &[bool],
+    origins: &[[f32; 2]],
+    anchor: [f32; 2],
+    raw_delta: [f32; 2],
+    options: &SnapOptions,
+) -> SnapResult {
+    let mut result = SnapResult {
+        delta: raw_delta,
+        guides: Vec::new(),
+    };
+    let Some(bounds) = origins
+        .iter()
+        .map(|&c| Rect::of_card(c))
+        .reduce(Rect::union)
+    else {
+        return result;
+    };
+    let moved = bounds.offset(raw_delta);
+
+    // 화면에 보이는 다른 카드만 맞춤 대상
+    let others: Vec<Rect> = if options.guides {
+        let view = Rect {
+            min: options.view.0,
+            max: options.view.1,
+        };
+        doc.block_positions
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| !moving.get(i).copied().unwrap_or(false))
+            .map(|(_, inst)| Rect::of_card(inst.position))
+            .filter(|r| r.overlaps_across(&view, 0) && r.overlaps_across(&view, 1))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut chosen: [Option<AxisSnap>; 2] = [None, None];
+    for (axis, &anchor_at) in anchor.iter().enumerate() {
+        let mut best: Option<(f32, AxisSnap)> = None;
+        let mut consider = |shift: f32, snap: AxisSnap| {
+            if shift.abs() <= options.threshold
+                && best.is_none_or(|(current, _)| shift.abs() < current.abs())
+            {
+                best = Some((shift, snap));
+            }
+        };
+
+        let features = moved.features(axis);
+        let mut before: Option<Rect> = None;
+        let mut after: Option<Rect> = None;
+        let mut row: Vec<Rect> = Vec::new();
+        for other in &others {
+            for target in other.features(axis) {
+                for feature in features {
+                    consider(target - feature, AxisSnap::Align(target));
+                }
+            }
+            // 같은 줄에서 바로 앞/뒤 카드 (간격 맞춤용)
+            if !other.overlaps_across(&moved, axis) {
+                continue;
+            }
+            row.push(*other);
+            if other.max[axis] <= moved.min[axis] + options.threshold
+                && before.is_none_or(|b| other.max[axis] > b.max[axis])
+            {
+                before = Some(*other);
+            }
+            if other.min[axis] >= moved.max[axis] - options.threshold
+                && after.is_none_or(|a| other.min[axis] < a.min[axis])
+            {
+                after = Some(*other);
+            }
+        }
+        if let (Some(b), Some(a)) = (before, after) {
+            let gap_before = moved.min[axis] - b.max[axis];
+            let gap_after = a.min[axis] - moved.max[axis];
+            consider((gap_after - gap_before) * 0.5, AxisSnap::Between(b, a));
+        }
+        // 같은 줄에서 이웃한 두 카드 사이 간격을 바로 옆 카드와의 간격으로
+        row.sort_by(|a, b| a.min[axis].total_cmp(&b.min[axis]));
+        for pair in row.windows(2) {
+            let gap = pair[1].min[axis] - pair[0].max[axis];
+            if gap <= 0.0 {
+                continue;
+            }
+            let reference = (pair[0], pair[1]);
+            if let Some(b) = before {
+                let snap = AxisSnap::Gap {
+                    reference,
+                    neighbour: b,
+                    before: true,
+                };
+                consider(b.max[axis] + gap - moved.min[axis], snap);
+            }
+            if let Some(a) = after {
+                let snap = AxisSnap::Gap {
+                    reference,
+                    neighbour: a,
+                    before: false,
+                };
+                consider(a.min[axis] - gap - moved.max[axis], snap);
+            }
+        }
+
+        match best {
+            Some((shift, snap)) => {
+                result.delta[axis] += shift;
+                chosen[axis] = Some(snap);
+            }
+            None => {
+                if let Some(grid) = options.grid {
+                    let target = anchor_at + result.delta[axis];
+                    result.delta[axis] += (target / grid).round() * grid - target;
+                }
+            }
+        }
+    }
+
+    // 두 축의 맞춤이 모두 반영된 위치 기준으로 안내선을 그림
+    let snapped = bounds.offset(result.delta);
+    for (axis, snap) in chosen.into_iter().enumerate() {
+        match snap {
+            Some(AxisSnap::Align(at)) => {
+                result.guides.push(align_guide(axis, at, &snapped, &others));
+            }
+            Some(AxisSnap::Between(b, a)) => {
+                result.guides.push(spacing_guide(axis, &b, &snapped));
+                result.guides.push(spacing_guide(axis, &snapped, &a));
+            }
+            Some(AxisSnap::Gap {
+                reference: (first, second),
+                neighbour,
+                before,
+            }) => {
+                result.guides.push(spacing_guide(axis, &first, &second));
+                result.guides.push(if before {
+                    spacing_guide(axis, &neighbour, &snapped)
+                } else {
+                    spacing_guide(axis, &snapped, &neighbour)
+                });
+            }
+            None => {}
+        }
+    }
+    result
+}
+
+/// lo의 끝에서 hi의 시작까지 빈 구간. 두 카드가 겹치는 구간의 가운데 높이(또는 너비)에 표시
+fn spacing_guide(axis: usize, lo: &Rect, hi: &Rect) -> SnapGuide {
+    let across = 1 - axis;
+    let mid = (lo.min[across].max(hi.min[across]) + lo.max[across].min(hi.max[across])) * 0.5;
+    SnapGuide::Spacing {
+        from: point(axis, lo.max[axis], mid),
+        to: point(axis, hi.min[axis], mid),
+    }
+}
+
+/// at에 특징선이 맞은 모든 카드와 선택 묶음을 가로지르는 안내선
+fn align_guide(axis: usize, at: f32, snapped: &Rect, others: &[Rect]) -> SnapGuide {
+    const EPSILON: f32 = 1e-4;
+    let across = 1 - axis;
+    let mut span = [snapped.min[across], snapped.max[across]];
+    for other in others {
+        if other
+            .features(axis)
+            .iter()
+            .any(|f| (f - at).abs() < EPSILON)
+        {
+            span = [
+                span[0].min(other.min[across]),
+                span[1].max(other.max[across]),
+            ];
+        }
+    }
+    SnapGuide::Align {
+        from: point(axis, at, span[0]),
+        to: point(axis, at, span[1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::CardKind;
+
+    const THRESHOLD: f32 = 0.05;
+
+    /// 원점의 카드 한 장을 raw만큼 끌었을 때의 맞춤 (others는 움직이지 않는 카드 중심)
+    fn snap(others: &[[f32; 2]], raw: [f32; 2], grid: Option<f32>) -> SnapResult {
+        let mut doc = Document::default();
+        for &center in std::iter::once(&[0.0, 0.0]).chain(others) {
+            doc.push_card(center, [1.0; 3], CardKind::Text, "");
+        }
+        let mut moving = vec![false; doc.len()];
+        moving[0] = true;
+        let options = SnapOptions {
+            threshold: THRESHOLD,
+            guides: true,
+            grid,
+            view: ([-100.0; 2], [100.0; 2]),
+        };
+        snap_move(&doc, &moving, &[[0.0, 0.0]], [0.0, 0.0], raw, &options)
+    }
+
+    fn assert_close(actual: [f32; 2], expected: [f32; 2]) {
+        assert!(
+            (actual[0] - expected[0]).abs() < 1e-5 && (actual[1] - expected[1]).abs() < 1e-5,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_grid_only_on_axes_without_a_snap() {
+        let result = snap(&[], [0.37, -0.12], Some(0.5));
+        assert_close(result.delta, [0.5, 0.0]);
+        assert!(result.guides.is_empty());
+
+        // y는 멀리 있는 카드의 중심에 맞고 x만 격자로
+        let result = snap(&[[3.0, 2.02]], [0.37, 2.0], Some(0.5));
+        assert_close(result.delta, [0.5, 2.02]);
+        assert_eq!(result.guides.len(), 1);
+
+        // 격자가 꺼져 있으면 그대로
+        let result = snap(&[], [0.37, -0.12], None);
+        assert_close(result.delta, [0.37, -0.12]);
+    }
+
+    #[test]
+    fn picks_the_nearest_alignment() {
+        // 가까운 카드는 +0.02, 먼 카드는 -0.03만큼 어긋난 세로선
+        // (세로로는 두 카드 사이 간격과 같은 간격이 되지 않게 떨어뜨려 둠)
+        let result = snap(&[[1.02, 3.0], [0.97, 6.5]], [1.0, 0.0], None);
+        assert_close(result.delta, [1.02, 0.0]);
+        let [SnapGuide::Align { from, to }] = result.guides[..] else {
+            panic!("{:?}", result.guides);
+        };
+        // 안내선은 맞은 카드의 왼쪽 끝에서 옮긴 카드까지 가로지름
+        let left = 1.02 - CARD_HALF_W;
+        assert_close(from, [left, -CARD_HALF_H]);
+        assert_close(to, [left, 3.0 + CARD_HALF_H]);
+    }
+
+    #[test]
+    fn ignores_alignments_beyond_the_threshold() {
+        let result = snap(&[[1.0 + THRESHOLD * 1.5, 3.0]], [1.0, 0.0], None);
+        assert_close(result.delta, [1.0, 0.0]);
+        assert!(result.guides.is_empty());
+
+        let result = snap(&[[1.0 + THRESHOLD * 0.5, 3.0]], [1.0, 0.0], None);
+        assert_close(result.delta, [1.0 + THRESHOLD * 0.5, 0.0]);
+    }
+
+    /// 안내선 중 간격 표시의 길이 (x축 간격만)
+    fn spacing_widths(result: &SnapResult) -> Vec<f32> {
+        result
+            .guides
+            .iter()
+            .filter_map(|guide| match *guide {
+                SnapGuide::Spacing { from, to } => Some(to[0] - from[0]),
+                SnapGuide::Align { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn centers_between_neighbours_in_the_same_row() {
+        // 왼쪽 카드 오른쪽 끝 -0.75, 오른쪽 카드 왼쪽 끝 0.95 → 양쪽 간격 0.6이 되는 중심 0.1
+        let result = snap(&[[-1.0, 0.0], [1.2, 0.0]], [0.13, 0.0], None);
+        assert_close(result.delta, [0.1, 0.0]);
+        let spacing = spacing_widths(&result);
+        assert_eq!(spacing.len(), 2);
+        for gap in spacing {
+            assert!((gap - 0.6).abs() < 1e-5, "{}", gap);
+        }
+    }
+
+    #[test]
+    fn repeats_an_existing_gap_after_the_last_card_in_the_row() {
+        // 두 카드 사이 간격 0.5 → 오른쪽 카드(끝 -0.25)에서 0.5 띄운 중심 0.5
+        let result = snap(&[[-1.5, 0.0], [-0.5, 0.0]], [0.53, 0.0], None);
+        assert_close(result.delta, [0.5, 0.0]);
+        let spacing = spacing_widths(&result);
+        assert_eq!(spacing.len(), 2);
+        for gap in spacing {
+            assert!((gap - 0.5).abs() < 1e-5, "{}", gap);
+        }
+    }
+
+    #[test]
+    fn repeats_an_existing_gap_before_the_first_card_in_the_row() {
+        let result = snap(&[[0.5, 0.0], [1.5, 0.0]], [-0.47, 0.0], None);
+        assert_close(result.delta, [-0.5, 0.0]);
+        assert_eq!(spacing_widths(&result).len(), 2);
+    }
+
+    #[test]
+    fn gaps_in_other_rows_are_not_repeated() {
+        // 간격 0.5인 두 카드는 위쪽 줄에 있고, 같은 줄에는 카드 한 장뿐
+        let result = snap(&[[-1.5, 2.0], [-0.5, 2.0], [-0.5, 0.0]], [0.53, 0.0], None);
+        assert_close(result.delta, [0.53, 0.0]);
+        assert!(spacing_widths(&result).is_empty());
+    }
+}
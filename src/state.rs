@@ -1,28 +1,88 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
 use std::sync::Arc;
+use std::time::Instant;
 use wgpu::util::DeviceExt;
+use winit::keyboard::ModifiersState;
 use winit::window::Window;
 
-use crate::camera::Camera;
+use crate::camera::{Camera, CameraAnimation, PanGlide};
+use crate::document::{self, Document};
 use crate::egui_integration::EguiIntegration;
+use crate::history::{DocumentDiff, History};
+use crate::journal::{Journal, Recovery};
+use crate::keymap::{self, Keymap};
+use crate::paths;
 use crate::pipeline;
+use crate::selection::SelectionMode;
+use crate::session_layout::SessionLayout;
+use crate::settings::{self, Settings};
+use crate::snapping::SnapGuide;
+use crate::stats::BoardStats;
+use crate::tabs::BoardSession;
 use crate::types::*;
 use crate::ui;
+use crate::workspace::Preview;
 
 pub enum InteractionMode {
     Idle,
     Panning {
         start_ndc: [f32; 2],
         start_camera: [f32; 2],
+        // 가운데 버튼으로 시작했으면 그 버튼을 놓을 때만 끝남 (아니면 Space+왼쪽 버튼)
+        middle_button: bool,
     },
+    // 놓을 때 사각형에 걸린 카드를 mode에 따라 기존 선택과 합성
     DragSelecting {
         start: [f32; 2],
         end: [f32; 2],
+        mode: SelectionMode,
     },
+    // 잡은 위치에서 커서까지의 이동량을 맞춤 보정해 원래 위치에 더함
     MovingSelection {
-        last_world: [f32; 2],
+        grab_world: [f32; 2],
+        // 선택한 카드들의 원래 중심 (selected_indices 순서)
+        origins: Vec<[f32; 2]>,
+        // 잡은 카드의 원래 중심 (격자 맞춤 기준)
+        anchor: [f32; 2],
+        // 문서 인덱스별 옮기는 중인지
+        moving: Vec<bool>,
     },
+    // Alt+드래그 올가미. 지나간 자리를 월드 좌표로 기록
+    Lasso {
+        points: Vec<[f32; 2]>,
+        mode: SelectionMode,
+    },
+}
+
+// 비교 오버레이 버퍼 초기 용량 (요소 수)
+const OVERLAY_INITIAL_CAPACITY: usize = 256;
+
+// GPU 초기화 실패 (어댑터/디바이스를 얻지 못한 환경 등)
+#[derive(Debug)]
+pub enum InitError {
+    Surface(wgpu::CreateSurfaceError),
+    NoAdapter,
+    Device(wgpu::RequestDeviceError),
+    UnsupportedSurface,
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitError::Surface(err) => write!(f, "서피스 생성 실패: {}", err),
+            InitError::NoAdapter => write!(f, "사용할 수 있는 GPU 어댑터가 없습니다"),
+            InitError::Device(err) => write!(f, "GPU 디바이스 요청 실패: {}", err),
+            InitError::UnsupportedSurface => {
+                write!(f, "어댑터가 이 창의 서피스를 지원하지 않습니다")
+            }
+        }
+    }
 }
 
+impl std::error::Error for InitError {}
+
 pub struct AppState {
     pub surface: wgpu::Surface<'static>,
     pub device: wgpu::Device,
@@ -33,9 +93,17 @@ pub struct AppState {
     // 파이프라인
     pub card_pipeline: wgpu::RenderPipeline,
     pub line_pipeline: wgpu::RenderPipeline,
+    pub diff_pipeline: wgpu::RenderPipeline,
 
     // 카메라
     pub camera: Camera,
+    // 진행 중인 카메라 전환과 마지막으로 진행시킨 시각
+    pub camera_animation: Option<CameraAnimation>,
+    pub camera_animation_tick: Instant,
+    // 끌어 이동을 놓은 뒤의 관성 이동 (전환과 같은 시각으로 진행)
+    pub camera_glide: Option<PanGlide>,
+    // 끌어 이동 중 최근 카메라 위치 기록 (놓을 때 속도 계산용)
+    pub pan_samples: Vec<(Instant, [f32; 2])>,
     pub camera_buffer: wgpu::Buffer,
     pub camera_bind_group: wgpu::BindGroup,
 
@@ -51,8 +119,29 @@ pub struct AppState {
     pub positions_dirty: bool,
     pub cached_line_verts: Vec<Vertex>,
 
+    // 버전 비교 오버레이 버퍼 (비교 중일 때만 채워짐)
+    pub overlay_instance_buffer: wgpu::Buffer,
+    pub overlay_instance_capacity: usize,
+    pub overlay_instance_count: u32,
+    pub overlay_line_buffer: wgpu::Buffer,
+    pub overlay_line_capacity: usize,
+    pub overlay_line_count: u32,
+
     // 데이터
-    pub block_positions: Vec<InstanceRaw>,
+    pub document: Document,
+    pub history: History,
+    // 활성 보드의 ID (자동 저장 디렉터리 이름)와 저장하지 않은 변경 여부
+    pub board_id: u64,
+    pub unsaved_changes: bool,
+    // 활성 보드를 작업 공간 파일에서 열었거나 저장했으면 그 경로
+    pub board_source: Option<PathBuf>,
+    // 탭별 보드 (top_bar_state.tabs와 같은 인덱스, 활성 탭 자리는 비어 있음)
+    pub boards: Vec<BoardSession>,
+    // 비교 중인 (기준 스냅샷, 대상 스냅샷 — None이면 현재 문서)와 그 결과
+    pub diff_compare: Option<(usize, Option<usize>)>,
+    pub diff: Option<DocumentDiff>,
+    // 대시보드가 열려 있을 때만 계산 (문서가 바뀌면 다시 계산)
+    pub board_stats: Option<BoardStats>,
     pub mouse_ndc: [f32; 2],
     pub mouse_pixel: [f32; 2],
 
@@ -61,7 +150,12 @@ pub struct AppState {
 
     // 상호작용 모드
     pub interaction: InteractionMode,
+    // 카드를 옮기는 동안 보여 줄 정렬/간격 안내선
+    pub snap_guides: Vec<SnapGuide>,
     pub space_pressed: bool,
+    pub modifiers: ModifiersState,
+    // 키보드 탐색 모드: 방향키가 선택 대신 초점(선택한 카드 한 장)을 옮김
+    pub navigation_mode: bool,
 
     // egui
     pub egui: EguiIntegration,
@@ -70,27 +164,60 @@ pub struct AppState {
     pub top_bar_state: ui::top_bar::TopBarState,
     pub left_tab_state: ui::left_tab::LeftTabState,
     pub inspector_state: ui::inspector::InspectorState,
+    pub file_dialog_state: ui::file_dialog::FileDialogState,
+    pub project_browser_state: ui::project_browser::ProjectBrowserState,
+
+    // 사용자 설정 (settings_dirty면 다음 기회에 파일로 저장)
+    pub settings: Settings,
+    pub settings_path: Option<PathBuf>,
+    pub settings_dirty: bool,
+    pub settings_panel_state: ui::settings_panel::SettingsPanelState,
+    pub context_menu_state: ui::context_menu::ContextMenuState,
+    pub command_palette_state: ui::command_palette::CommandPaletteState,
+
+    // 단축키 (키맵 파일에서 읽음)
+    pub keymap: Keymap,
+    pub keymap_path: Option<PathBuf>,
+
+    // 창/패널 배치와 보드별 카메라 (종료 시 저장)
+    pub session_layout: SessionLayout,
+    pub session_layout_path: Option<PathBuf>,
+
+    // 자동 저장 (복구 안내가 떠 있는 동안은 시작하지 않음)
+    pub autosave_dir: Option<PathBuf>,
+    pub journal: Option<Journal>,
+    pub recovery_prompt: Option<ui::recovery_dialog::RecoveryPrompt>,
+    // 종료가 확정됨 (저장하지 않은 변경이 없거나 버리기로 확인, 이벤트 루프가 창을 닫음)
+    pub quit_confirmed: bool,
+    // 개요로 인식했지만 읽지 못한 붙여넣기 오류
+    pub paste_error: Option<String>,
+    // 작업 스레드에서 도는 포맷 비교 측정 (결과 문자열 대기)
+    pub benchmark_job: Option<Receiver<String>>,
+    // 작업 공간 썸네일을 읽는 작업 스레드 (파일 경로, 결과)
+    pub preview_job: Option<Receiver<(PathBuf, Preview)>>,
 }
 
 impl AppState {
-    pub fn new(window: Arc<Window>) -> Self {
+    pub fn new(window: Arc<Window>) -> Result<Self, InitError> {
         let instance = wgpu::Instance::default();
-        let surface = instance.create_surface(Arc::clone(&window)).unwrap();
+        let surface = instance
+            .create_surface(Arc::clone(&window))
+            .map_err(InitError::Surface)?;
         let adapter =
             pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
-                .unwrap();
+                .ok_or(InitError::NoAdapter)?;
         let (device, queue) =
             pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
-                .unwrap();
+                .map_err(InitError::Device)?;
 
         let size = window.inner_size();
         let config = surface
             .get_default_config(&adapter, size.width, size.height)
-            .unwrap();
+            .ok_or(InitError::UnsupportedSurface)?;
         surface.configure(&device, &config);
 
         // 카메라
-        let camera = Camera::new([3.15, 2.25], 0.3);
+        let camera = Camera::default();
         let aspect = config.width as f32 / config.height as f32;
         let camera_uniform = camera.build_view_proj(aspect);
         let camera_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
@@ -135,6 +262,8 @@ impl AppState {
             pipeline::create_card_pipeline(&device, &shader, config.format, &pipeline_layout);
         let line_pipeline =
             pipeline::create_line_pipeline(&device, &shader, config.format, &pipeline_layout);
+        let diff_pipeline =
+            pipeline::create_diff_pipeline(&device, &shader, config.format, &pipeline_layout);
 
         // 정적 버퍼: 카드 쿼드
         let card_quad_buffer = Self::create_card_quad_buffer(&device);
@@ -143,10 +272,10 @@ impl AppState {
         let egui = EguiIntegration::new(&device, config.format, &window);
 
         // 블록 초기 데이터
-        let block_positions = create_default_grid();
+        let document = Document::default_grid();
 
         // 영속 GPU 버퍼 사전 할당
-        let initial_capacity = block_positions.len().max(1024) * 2;
+        let initial_capacity = document.len().max(1024) * 2;
         let instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("Instance Buffer"),
             size: (initial_capacity * std::mem::size_of::<InstanceRaw>()) as u64,
@@ -161,7 +290,64 @@ impl AppState {
             mapped_at_creation: false,
         });
 
-        Self {
+        let overlay_instance_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Overlay Instance Buffer"),
+            size: (OVERLAY_INITIAL_CAPACITY * std::mem::size_of::<InstanceRaw>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let overlay_line_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Overlay Line Buffer"),
+            size: (OVERLAY_INITIAL_CAPACITY * std::mem::size_of::<Vertex>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // 첫 탭이 활성, 나머지 탭도 기본 그리드로 시작
+        let top_bar_state = ui::top_bar::TopBarState::default();
+        let boards = (0..top_bar_state.tabs.len())
+            .map(|i| match i {
+                0 => BoardSession::default(),
+                _ => BoardSession::with_document(Document::default_grid()),
+            })
+            .collect();
+
+        let settings_path = settings::default_path();
+        let (settings, warnings) = match &settings_path {
+            Some(path) => Settings::load(path),
+            None => (Settings::default(), Vec::new()),
+        };
+        for warning in &warnings {
+            eprintln!("설정: {}", warning);
+        }
+        let keymap_path = keymap::default_path();
+        let (keymap, keymap_warnings) = match &keymap_path {
+            Some(path) => Keymap::load(path),
+            None => (Keymap::default(), Vec::new()),
+        };
+        for warning in &keymap_warnings {
+            eprintln!("키맵: {}", warning);
+        }
+        let settings_panel_state = ui::settings_panel::SettingsPanelState {
+            draft: settings.clone(),
+            warnings,
+            path: settings_path.as_ref().map(|p| p.display().to_string()),
+            keymap_warnings,
+            keymap_path: keymap_path.as_ref().map(|p| p.display().to_string()),
+        };
+
+        // 이전 세션의 자동 저장 파일이 남아 있으면 복구 여부를 먼저 묻는다
+        let autosave_dir = paths::data_dir().map(|dir| dir.join("autosave"));
+        let recovery_prompt = autosave_dir
+            .as_deref()
+            .map(Recovery::find_all)
+            .filter(|recoveries| !recoveries.is_empty())
+            .map(|recoveries| ui::recovery_dialog::RecoveryPrompt {
+                recoveries,
+                error: None,
+            });
+
+        let mut state = Self {
             surface,
             device,
             queue,
@@ -169,7 +355,12 @@ impl AppState {
             window,
             card_pipeline,
             line_pipeline,
+            diff_pipeline,
             camera,
+            camera_animation: None,
+            camera_animation_tick: Instant::now(),
+            camera_glide: None,
+            pan_samples: Vec::new(),
             camera_buffer,
             camera_bind_group,
             card_quad_buffer,
@@ -180,17 +371,57 @@ impl AppState {
             line_vertex_count: 0,
             positions_dirty: true,
             cached_line_verts: Vec::new(),
-            block_positions,
+            overlay_instance_buffer,
+            overlay_instance_capacity: OVERLAY_INITIAL_CAPACITY,
+            overlay_instance_count: 0,
+            overlay_line_buffer,
+            overlay_line_capacity: OVERLAY_INITIAL_CAPACITY,
+            overlay_line_count: 0,
+            document,
+            history: History::default(),
+            board_id: document::random_id(),
+            unsaved_changes: false,
+            board_source: None,
+            boards,
+            diff_compare: None,
+            diff: None,
+            board_stats: None,
             mouse_ndc: [0.0, 0.0],
             mouse_pixel: [0.0, 0.0],
             selected_indices: Vec::new(),
             interaction: InteractionMode::Idle,
+            snap_guides: Vec::new(),
             space_pressed: false,
+            modifiers: ModifiersState::empty(),
+            navigation_mode: false,
             egui,
-            top_bar_state: Default::default(),
+            top_bar_state,
             left_tab_state: Default::default(),
+            project_browser_state: Default::default(),
             inspector_state: Default::default(),
-        }
+            file_dialog_state: Default::default(),
+            settings,
+            settings_path,
+            settings_dirty: false,
+            settings_panel_state,
+            context_menu_state: Default::default(),
+            command_palette_state: Default::default(),
+            keymap,
+            keymap_path,
+            session_layout: SessionLayout::default(),
+            session_layout_path: None,
+            autosave_dir,
+            journal: None,
+            recovery_prompt,
+            quit_confirmed: false,
+            paste_error: None,
+            benchmark_job: None,
+            preview_job: None,
+        };
+        state.apply_settings();
+        state.inspector_state.open = state.settings.show_inspector;
+        state.start_autosave();
+        Ok(state)
     }
 
     pub fn canvas_aspect(&self) -> f32 {
@@ -206,6 +437,20 @@ impl AppState {
         self.positions_dirty = true;
     }
 
+    /// 대시보드 통계 갱신. update_gpu_buffers가 변경 표시를 지우기 전에 호출
+    pub fn refresh_stats(&mut self) {
+        if self.left_tab_state.active_section != Some(ui::left_tab::LeftSection::Dashboard) {
+            self.board_stats = None;
+            return;
+        }
+        match &mut self.board_stats {
+            Some(stats) if self.positions_dirty => stats.refresh(&self.document),
+            Some(_) => return,
+            None => self.board_stats = Some(BoardStats::of(&self.document)),
+        }
+        self.window.request_redraw();
+    }
+
     pub fn update_gpu_buffers(&mut self) {
         if !self.positions_dirty {
             return;
@@ -213,7 +458,7 @@ impl AppState {
         self.positions_dirty = false;
 
         // 용량 부족 시 버퍼 재할당 (2배 확장)
-        let needed = self.block_positions.len();
+        let needed = self.document.len();
         if needed > self.instance_buffer_capacity {
             let new_cap = (needed * 2).max(1024);
             self.instance_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
@@ -223,8 +468,11 @@ impl AppState {
                 mapped_at_creation: false,
             });
             self.instance_buffer_capacity = new_cap;
+        }
 
-            let line_cap = new_cap * 2;
+        let needed_lines = self.document.edges.len() * 2;
+        if needed_lines > self.line_buffer_capacity {
+            let line_cap = (needed_lines * 2).max(self.instance_buffer_capacity * 2);
             self.line_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
                 label: Some("Line Buffer"),
                 size: (line_cap * std::mem::size_of::<Vertex>()) as u64,
@@ -234,27 +482,28 @@ impl AppState {
             self.line_buffer_capacity = line_cap;
         }
 
-        // 라인 버텍스 캐시 재생성
+        // 라인 버텍스 캐시 재생성 (시작 카드 색상 사용)
+        let positions = &self.document.block_positions;
         self.cached_line_verts.clear();
-        for i in 0..self.block_positions.len().saturating_sub(1) {
-            let color = self.block_positions[i].color;
+        for edge in &self.document.edges {
+            let color = positions[edge.from].color;
             self.cached_line_verts.push(Vertex {
-                position: self.block_positions[i].position,
+                position: positions[edge.from].position,
                 color,
             });
             self.cached_line_verts.push(Vertex {
-                position: self.block_positions[i + 1].position,
+                position: positions[edge.to].position,
                 color,
             });
         }
         self.line_vertex_count = self.cached_line_verts.len() as u32;
 
         // GPU에 업로드
-        if !self.block_positions.is_empty() {
+        if !self.document.is_empty() {
             self.queue.write_buffer(
                 &self.instance_buffer,
                 0,
-                bytemuck::cast_slice(&self.block_positions),
+                bytemuck::cast_slice(&self.document.block_positions),
             );
         }
         if !self.cached_line_verts.is_empty() {
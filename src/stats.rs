@@ -0,0 +1,244 @@
+// 대시보드용 보드 통계 (문서 모델만 읽고 GPU는 건드리지 않음)
+//
+// 연결 요소/깊이는 카드를 끄는 동안 바뀌지 않으므로, 연결 구조가 바뀔 때만 다시 계산한다.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
+
+use crate::document::{CardKind, Document};
+
+// 밀도 히트맵 격자 크기
+pub const HEATMAP_COLS: usize = 24;
+pub const HEATMAP_ROWS: usize = 16;
+
+/// 카드 중심 분포를 경계 상자 위에 나눈 격자 (행 우선, 위쪽 행부터)
+pub struct Heatmap {
+    pub cells: Vec<u32>,
+    pub max: u32,
+}
+
+pub struct BoardStats {
+    pub cards: usize,
+    pub edges: usize,
+    pub groups: usize,
+    // (색상, 카드 수), 많은 순
+    pub colors: Vec<([f32; 3], usize)>,
+    pub components: usize,
+    pub largest_component: usize,
+    // 연결이 하나도 없는 카드
+    pub isolated: usize,
+    // 루트(들어오는 연결 없음)에서 내려가는 가장 깊은 단계 수
+    pub max_depth: usize,
+    pub heatmap: Heatmap,
+    // 연결 요소/깊이를 계산한 연결 구조의 지문
+    topology: u64,
+}
+
+impl BoardStats {
+    pub fn of(doc: &Document) -> Self {
+        let mut stats = BoardStats {
+            cards: 0,
+            edges: 0,
+            groups: 0,
+            colors: Vec::new(),
+            components: 0,
+            largest_component: 0,
+            isolated: 0,
+            max_depth: 0,
+            heatmap: Heatmap {
+                cells: Vec::new(),
+                max: 0,
+            },
+            topology: topology_key(doc),
+        };
+        stats.update_graph(doc);
+        stats.update_layout(doc);
+        stats
+    }
+
+    /// 문서가 바뀐 뒤 갱신. 연결 구조가 그대로면 배치/색 통계만 다시 계산
+    pub fn refresh(&mut self, doc: &Document) {
+        let topology = topology_key(doc);
+        if topology != self.topology {
+            self.topology = topology;
+            self.update_graph(doc);
+        }
+        self.update_layout(doc);
+    }
+
+    fn update_graph(&mut self, doc: &Document) {
+        let n = doc.len();
+        let mut degree = vec![0usize; n];
+        for edge in &doc.edges {
+            degree[edge.from] += 1;
+            degree[edge.to] += 1;
+        }
+        (self.components, self.largest_component) = components(doc);
+        self.cards = n;
+        self.edges = doc.edges.len();
+        self.isolated = degree.iter().filter(|&&d| d == 0).count();
+        self.max_depth = max_depth(doc);
+    }
+
+    fn update_layout(&mut self, doc: &Document) {
+        let mut colors: Vec<([f32; 3], usize)> = Vec::new();
+        for inst in &doc.block_positions {
+            match colors.iter_mut().find(|(c, _)| *c == inst.color) {
+                Some((_, count)) => *count += 1,
+                None => colors.push((inst.color, 1)),
+            }
+        }
+        colors.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        self.colors = colors;
+        self.groups = doc
+            .cards
+            .iter()
+            .filter(|c| c.kind == CardKind::Group)
+            .count();
+        self.heatmap = heatmap(doc);
+    }
+}
+
+/// 카드 수와 엣지 목록의 해시
+fn topology_key(doc: &Document) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    doc.len().hash(&mut hasher);
+    for edge in &doc.edges {
+        (edge.from, edge.to).hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// 방향을 무시한 연결 요소 수와 가장 큰 요소의 카드 수
+fn components(doc: &Document) -> (usize, usize) {
+    let n = doc.len();
+    let mut parent: Vec<usize> = (0..n).collect();
+    for edge in &doc.edges {
+        let (a, b) = (find(&mut parent, edge.from), find(&mut parent, edge.to));
+        if a != b {
+            parent[a] = b;
+        }
+    }
+    let mut sizes = vec![0usize; n];
+    for i in 0..n {
+        let root = find(&mut parent, i);
+        sizes[root] += 1;
+    }
+    let count = sizes.iter().filter(|&&s| s > 0).count();
+    (count, sizes.into_iter().max().unwrap_or(0))
+}
+
+/// 루트들에서 연결 방향으로 너비 우선 탐색한 최대 단계 수 (루트만 있으면 1).
+/// 순환만으로 이루어져 루트가 없는 부분은 남은 카드 하나를 루트로 삼음
+fn max_depth(doc: &Document) -> usize {
+    let n = doc.len();
+    let mut children = vec![Vec::new(); n];
+    let mut indegree = vec![0usize; n];
+    for edge in &doc.edges {
+        children[edge.from].push(edge.to);
+        indegree[edge.to] += 1;
+    }
+
+    let mut level = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+    let mut deepest = 0;
+    let mut visit = |roots: &mut dyn Iterator<Item = usize>, level: &mut Vec<usize>| {
+        for root in roots {
+            if level[root] == usize::MAX {
+                level[root] = 0;
+                queue.push_back(root);
+            }
+        }
+        while let Some(i) = queue.pop_front() {
+            deepest = deepest.max(level[i] + 1);
+            for &child in &children[i] {
+                if level[child] == usize::MAX {
+                    level[child] = level[i] + 1;
+                    queue.push_back(child);
+                }
+            }
+        }
+    };
+
+    visit(&mut (0..n).filter(|&i| indegree[i] == 0), &mut level);
+    for i in 0..n {
+        if level[i] == usize::MAX {
+            visit(&mut std::iter::once(i), &mut level);
+        }
+    }
+    deepest
+}
+
+fn heatmap(doc: &Document) -> Heatmap {
+    let mut cells = vec![0u32; HEATMAP_COLS * HEATMAP_ROWS];
+    if let Some((min, max)) = doc.bounds() {
+        let span = [
+            (max[0] - min[0]).max(f32::EPSILON),
+            (max[1] - min[1]).max(f32::EPSILON),
+        ];
+        for inst in &doc.block_positions {
+            let fx = (inst.position[0] - min[0]) / span[0];
+            let fy = (max[1] - inst.position[1]) / span[1];
+            let col = ((fx * HEATMAP_COLS as f32) as usize).min(HEATMAP_COLS - 1);
+            let row = ((fy * HEATMAP_ROWS as f32) as usize).min(HEATMAP_ROWS - 1);
+            cells[row * HEATMAP_COLS + col] += 1;
+        }
+    }
+    let max = cells.iter().copied().max().unwrap_or(0);
+    Heatmap { cells, max }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chain(n: usize) -> Document {
+        let mut doc = Document::default();
+        for i in 0..n {
+            let idx = doc.push_card([i as f32, 0.0], [1.0; 3], CardKind::Text, "");
+            if idx > 0 {
+                doc.connect(idx - 1, idx);
+            }
+        }
+        doc
+    }
+
+    #[test]
+    fn moving_cards_keeps_the_graph_stats_and_updates_the_heatmap() {
+        let mut doc = chain(3);
+        let mut stats = BoardStats::of(&doc);
+        let (topology, cells) = (stats.topology, stats.heatmap.cells.clone());
+        doc.block_positions[2].position = [0.0, 5.0];
+        stats.refresh(&doc);
+        assert_eq!(stats.topology, topology);
+        assert_ne!(stats.heatmap.cells, cells);
+        assert_eq!((stats.components, stats.max_depth), (1, 3));
+    }
+
+    #[test]
+    fn refresh_matches_a_full_recount_after_topology_changes() {
+        let mut doc = chain(4);
+        let mut stats = BoardStats::of(&doc);
+        doc.edges.remove(1);
+        doc.push_card([9.0, 9.0], [0.5; 3], CardKind::Group, "");
+        stats.refresh(&doc);
+        let fresh = BoardStats::of(&doc);
+        assert_eq!(
+            (stats.cards, stats.edges, stats.groups, stats.components),
+            (fresh.cards, fresh.edges, fresh.groups, fresh.components)
+        );
+        assert_eq!(
+            (stats.largest_component, stats.isolated, stats.max_depth),
+            (fresh.largest_component, fresh.isolated, fresh.max_depth)
+        );
+        assert_eq!((stats.components, stats.isolated), (3, 1));
+    }
+}
@@ -0,0 +1,246 @@
+// 상단 탭마다 독립된 보드 (문서, 카메라, 선택, 스냅샷 기록).
+// 활성 탭의 상태는 AppState 필드에 두고, 비활성 탭은 BoardSession으로 보관한다.
+// GPU 버퍼는 탭끼리 공유하며 전환 시 내용만 다시 올린다 (용량이 부족할 때만 재할당).
+use std::path::PathBuf;
+
+use crate::camera::Camera;
+use crate::document::{self, Document};
+use crate::history::History;
+use crate::journal::Journal;
+use crate::session_layout::{self, SavedCamera};
+use crate::state::{AppState, InteractionMode};
+use crate::ui::top_bar::TopBarAction;
+
+pub struct BoardSession {
+    pub id: u64,
+    pub document: Document,
+    pub camera: Camera,
+    pub selected_indices: Vec<usize>,
+    pub history: History,
+    pub unsaved_changes: bool,
+    // 작업 공간 파일에서 열었거나 저장한 보드의 파일 경로
+    pub source: Option<PathBuf>,
+    // 이 보드의 자동 저장 (비활성 탭도 닫기 전까지 파일을 남겨 둠)
+    pub journal: Option<Journal>,
+}
+
+impl Default for BoardSession {
+    fn default() -> Self {
+        Self {
+            id: document::random_id(),
+            document: Document::default(),
+            camera: Camera::default(),
+            selected_indices: Vec::new(),
+            history: History::default(),
+            unsaved_changes: false,
+            source: None,
+            journal: None,
+        }
+    }
+}
+
+impl BoardSession {
+    pub fn with_document(document: Document) -> Self {
+        Self {
+            document,
+            ..Default::default()
+        }
+    }
+}
+
+/// from 위치의 항목을 to로 옮긴 뒤 index 항목의 새 위치
+fn moved_index(index: usize, from: usize, to: usize) -> usize {
+    if index == from {
+        to
+    } else if from < index && index <= to {
+        index - 1
+    } else if to <= index && index < from {
+        index + 1
+    } else {
+        index
+    }
+}
+
+impl AppState {
+    pub fn apply_top_bar_action(&mut self, action: &TopBarAction) {
+        if let Some((idx, name)) = &action.rename {
+            let name = name.trim();
+            if let (Some(tab), false) = (self.top_bar_state.tabs.get_mut(*idx), name.is_empty()) {
+                *tab = name.to_string();
+                let journal = if *idx == self.top_bar_state.active_tab {
+                    self.journal.as_ref()
+                } else {
+                    self.boards.get(*idx).and_then(|b| b.journal.as_ref())
+                };
+                if let Some(journal) = journal {
+                    journal.rename(name);
+                }
+            }
+        }
+        if let Some((from, to)) = action.move_tab {
+            self.move_tab(from, to);
+        }
+        if let Some(idx) = action.discard_close {
+            self.close_tab(idx);
+        }
+        if action.quit {
+            self.quit_confirmed = true;
+        }
+        if let Some(command) = action.command {
+            self.run_command(command);
+        }
+        if let Some(idx) = action.select {
+            self.switch_tab(idx);
+        }
+    }
+
+    pub fn switch_tab(&mut self, idx: usize) {
+        let active = self.top_bar_state.active_tab;
+        if idx == active || idx >= self.boards.len() {
+            return;
+        }
+
+        let parked = BoardSession {
+            id: self.board_id,
+            document: std::mem::take(&mut self.document),
+            camera: self.camera,
+            selected_indices: std::mem::take(&mut self.selected_indices),
+            history: std::mem::take(&mut self.history),
+            unsaved_changes: self.unsaved_changes,
+            source: self.board_source.take(),
+            journal: self.journal.take(),
+        };
+        self.boards[active] = parked;
+        self.stop_camera_animation();
+        let next = std::mem::take(&mut self.boards[idx]);
+        self.board_id = next.id;
+        self.document = next.document;
+        self.camera = next.camera;
+        self.selected_indices = next.selected_indices;
+        self.history = next.history;
+        self.unsaved_changes = next.unsaved_changes;
+        self.board_source = next.source;
+        self.journal = next.journal;
+        self.top_bar_state.active_tab = idx;
+
+        // 탭에 묶인 상호작용/비교 선택은 초기화
+        self.interaction = InteractionMode::Idle;
+        self.context_menu_state.open = None;
+        self.snap_guides.clear();
+        self.inspector_state.history = Default::default();
+        self.mark_positions_dirty();
+        self.window.request_redraw();
+    }
+
+    /// i번째 탭 보드의 카메라 저장 키
+    pub fn tab_camera_key(&self, i: usize) -> String {
+        if i == self.top_bar_state.active_tab {
+            session_layout::camera_key(self.board_id, self.board_source.as_deref())
+        } else {
+            let board = &self.boards[i];
+            session_layout::camera_key(board.id, board.source.as_deref())
+        }
+    }
+
+    pub fn new_tab(&mut self) {
+        // 닫은 탭의 번호를 다시 쓰더라도 열린 탭과는 겹치지 않게
+        let tabs = &self.top_bar_state.tabs;
+        let name = (1..)
+            .map(|n| format!("새 보드 {}", n))
+            .find(|name| !tabs.contains(name))
+            .unwrap_or_default();
+        self.open_tab(
+            name,
+            BoardSession {
+                camera: Camera::new([0.0, 0.0], Camera::default().zoom),
+                ..Default::default()
+            },
+        );
+    }
+
+    /// 맨 끝에 탭을 추가하고 전환
+    pub fn open_tab(&mut self, name: String, session: BoardSession) {
+        self.top_bar_state.tabs.push(name);
+        self.boards.push(session);
+        self.switch_tab(self.boards.len() - 1);
+        self.start_autosave();
+    }
+
+    /// 저장하지 않은 변경이 있으면 먼저 확인을 받고 닫음
+    pub fn request_close_tab(&mut self, idx: usize) {
+        let unsaved = if idx == self.top_bar_state.active_tab {
+            self.unsaved_changes
+        } else {
+            self.boards.get(idx).is_some_and(|b| b.unsaved_changes)
+        };
+        if unsaved {
+            self.top_bar_state.confirm_close = Some(idx);
+            self.window.request_redraw();
+        } else {
+            self.close_tab(idx);
+        }
+    }
+
+    /// 창 닫기 요청. 저장하지 않은 변경이 있는 탭이 있으면 먼저 확인을 받음
+    pub fn request_quit(&mut self) {
+        let active = self.top_bar_state.active_tab;
+        let unsaved: Vec<usize> = (0..self.boards.len())
+            .filter(|&i| {
+                if i == active {
+                    self.unsaved_changes
+                } else {
+                    self.boards[i].unsaved_changes
+                }
+            })
+            .collect();
+        if unsaved.is_empty() {
+            self.quit_confirmed = true;
+        } else {
+            self.top_bar_state.confirm_quit = Some(unsaved);
+            self.window.request_redraw();
+        }
+    }
+
+    /// 마지막 남은 탭은 닫지 않음
+    pub fn close_tab(&mut self, idx: usize) {
+        let count = self.boards.len();
+        self.top_bar_state.confirm_close = None;
+        if count <= 1 || idx >= count {
+            return;
+        }
+        if idx == self.top_bar_state.active_tab {
+            self.switch_tab(if idx + 1 < count { idx + 1 } else { idx - 1 });
+        }
+        let key = self.tab_camera_key(idx);
+        let mut board = self.boards.remove(idx);
+        self.top_bar_state.tabs.remove(idx);
+        if let Some(journal) = board.journal.take() {
+            journal.finish();
+        }
+        // 같은 파일을 다시 열 때 카메라를 되살림
+        self.session_layout
+            .cameras
+            .insert(key, SavedCamera::of(&board.camera));
+        self.top_bar_state.renaming = None;
+        if self.top_bar_state.active_tab > idx {
+            self.top_bar_state.active_tab -= 1;
+        }
+        self.window.request_redraw();
+    }
+
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        let count = self.boards.len();
+        if from >= count || to >= count || from == to {
+            return;
+        }
+        let board = self.boards.remove(from);
+        self.boards.insert(to, board);
+        let name = self.top_bar_state.tabs.remove(from);
+        self.top_bar_state.tabs.insert(to, name);
+        self.top_bar_state.active_tab = moved_index(self.top_bar_state.active_tab, from, to);
+        self.top_bar_state.renaming = None;
+        self.top_bar_state.confirm_close = None;
+        self.top_bar_state.confirm_quit = None;
+        self.window.request_redraw();
+    }
+}
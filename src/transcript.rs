@@ -0,0 +1,87 @@
+// 회의 전사문 데이터 (인스펙터 표시, 회의록 내보내기에서 공용)
+pub struct TranscriptEntry {
+    pub speaker: &'static str,
+    pub text: &'static str,
+    pub timestamp: &'static str,
+}
+
+pub const SAMPLE_TRANSCRIPT: [TranscriptEntry; 6] = [
+    TranscriptEntry {
+        speaker: "김팀장",
+        text: "안녕하세요, 여러분. 오늘은 2026년 상반기 팀 워크샵 기획에 대해 논의하려고 합니다. 팀 결속력 강화와 전략 공유가 주요 목표입니다.",
+        timestamp: "00:00",
+    },
+    TranscriptEntry {
+        speaker: "이과장",
+        text: "좋습니다. 먼저 워크샵 장소부터 정해야 할 것 같은데요, 참여 인원이 20명 정도 되니까 예산과 접근성을 고려해야 합니다.",
+        timestamp: "00:13",
+    },
+    TranscriptEntry {
+        speaker: "김팀장",
+        text: "맞습니다. 그리고 어떤 프로그램을 진행할지도 중요하죠. 팀 빌딩 활동, 교육, 전략 회의 등을 어떻게 구성할지 고민이 필요합니다.",
+        timestamp: "00:26",
+    },
+    TranscriptEntry {
+        speaker: "박대리",
+        text: "제 생각에는 게임형 팀 챌린지를 도입하면 어떨까 싶습니다. 방탈출이나 미션 수행 같은 게임 요소를 넣으면 참여도가 훨씬 높아질 것 같아요.",
+        timestamp: "00:39",
+    },
+    TranscriptEntry {
+        speaker: "이과장",
+        text: "장소 관련해서는 제주도 리조트를 검토해봤는데요, 3박 4일 패키지로 진행할 수 있습니다. 시설은 정말 좋은데... 문제는 1인당 비용이 35만원 정도로 예산을 초과한다는 점이에요.",
+        timestamp: "00:51",
+    },
+    TranscriptEntry {
+        speaker: "최주임",
+        text: "제주도면 이동 시간도 문제인 것 같아요. 비행기로 3시간 이상 걸리니까 이동만으로도 하루가 소비되고, 실제 워크숍 시간이 줄어들 것 같습니다.",
+        timestamp: "01:09",
+    },
+];
+
+/// 처음 등장한 순서대로 화자 목록
+pub fn speakers(entries: &[TranscriptEntry]) -> Vec<&'static str> {
+    let mut speakers = Vec::new();
+    for entry in entries {
+        if !speakers.contains(&entry.speaker) {
+            speakers.push(entry.speaker);
+        }
+    }
+    speakers
+}
+
+/// "mm:ss" 또는 "hh:mm:ss" → 초
+fn parse_timestamp(timestamp: &str) -> Option<u32> {
+    timestamp
+        .split(':')
+        .try_fold(0u32, |acc, part| Some(acc * 60 + part.trim().parse::<u32>().ok()?))
+}
+
+/// 화자별 발화 시간(초), 등장 순서대로.
+/// 각 발화는 다음 발화 시작까지로 보고, 마지막 발화는 앞선 발화들의 글자당 속도로 추정
+pub fn talk_time(entries: &[TranscriptEntry]) -> Vec<(&'static str, u32)> {
+    let starts: Vec<Option<u32>> = entries.iter().map(|e| parse_timestamp(e.timestamp)).collect();
+    let mut durations: Vec<Option<u32>> = starts
+        .windows(2)
+        .map(|w| Some(w[1]?.saturating_sub(w[0]?)))
+        .collect();
+
+    let (secs, chars) = durations
+        .iter()
+        .zip(entries)
+        .filter_map(|(d, e)| Some((d.as_ref()?, e.text.chars().count())))
+        .fold((0u32, 0usize), |(s, c), (d, n)| (s + d, c + n));
+    if let Some(last) = entries.last() {
+        let estimate = (chars > 0)
+            .then(|| (last.text.chars().count() as f32 * secs as f32 / chars as f32).round() as u32);
+        durations.push(estimate);
+    }
+
+    let mut totals: Vec<(&'static str, u32)> =
+        speakers(entries).into_iter().map(|s| (s, 0)).collect();
+    for (entry, duration) in entries.iter().zip(durations) {
+        if let Some(total) = totals.iter_mut().find(|(s, _)| *s == entry.speaker) {
+            total.1 += duration.unwrap_or(0);
+        }
+    }
+    totals
+}
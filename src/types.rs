@@ -14,7 +14,10 @@ pub const DEFAULT_GRID_COUNT: usize = 100;
 pub const TOP_BAR_HEIGHT: f32 = 55.0;
 pub const LEFT_TAB_WIDTH: f32 = 75.0;
 pub const INSPECTOR_WIDTH: f32 = 351.0;
-pub const TOOLBAR_HALF_WIDTH: f32 = 380.0;
+pub const INSPECTOR_MIN_WIDTH: f32 = 260.0;
+pub const INSPECTOR_MAX_WIDTH: f32 = 640.0;
+pub const SECTION_PANEL_WIDTH: f32 = 280.0;
+pub const TOOLBAR_HALF_WIDTH: f32 = 475.0;
 pub const TOOLBAR_BOTTOM_OFFSET: f32 = 55.0;
 pub const AI_BUTTON_OFFSET: f32 = 60.0;
 
@@ -28,6 +31,16 @@ pub const CARD_COLORS: [[f32; 3]; 6] = [
     [0.25, 0.25, 0.30], // 다크
 ];
 
+/// 가장 가까운 팔레트 색상의 인덱스
+pub fn nearest_palette_index(color: [f32; 3]) -> usize {
+    let dist = |c: &[f32; 3]| {
+        (c[0] - color[0]).powi(2) + (c[1] - color[1]).powi(2) + (c[2] - color[2]).powi(2)
+    };
+    (0..CARD_COLORS.len())
+        .min_by(|&a, &b| dist(&CARD_COLORS[a]).total_cmp(&dist(&CARD_COLORS[b])))
+        .unwrap_or(0)
+}
+
 pub fn create_default_grid() -> Vec<InstanceRaw> {
     (0..DEFAULT_GRID_COUNT)
         .map(|i| {
@@ -0,0 +1,145 @@
+use egui::{self, Color32, RichText};
+
+use crate::commands::{fuzzy_score, Command};
+
+// 한 번에 보여 주는 결과 수
+const MAX_RESULTS: usize = 12;
+const PALETTE_WIDTH: f32 = 420.0;
+
+#[derive(Default)]
+pub struct CommandPaletteState {
+    pub open: bool,
+    pub query: String,
+    // 결과 목록에서 강조된 항목
+    pub selected: usize,
+    // 연 직후 입력란에 포커스
+    focus_pending: bool,
+}
+
+impl CommandPaletteState {
+    pub fn open(&mut self) {
+        self.open = true;
+        self.query.clear();
+        self.selected = 0;
+        self.focus_pending = true;
+    }
+}
+
+pub struct PaletteEntry {
+    pub command: Command,
+    pub title: String,
+    pub shortcut: String,
+}
+
+/// 이름(한글)이나 키맵 ID로 찾아 점수순 정렬. 검색어가 없으면 원래 순서
+fn search<'a>(query: &str, entries: &'a [PaletteEntry]) -> Vec<&'a PaletteEntry> {
+    if query.trim().is_empty() {
+        return entries.iter().take(MAX_RESULTS).collect();
+    }
+    let mut scored: Vec<(i32, &PaletteEntry)> = entries
+        .iter()
+        .filter_map(|entry| {
+            let id = entry.command.id().replace('_', " ");
+            let score = fuzzy_score(query, &entry.title).max(fuzzy_score(query, &id))?;
+            Some((score, entry))
+        })
+        .collect();
+    // 안정 정렬이라 같은 점수는 원래 순서 유지
+    scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+    scored
+        .into_iter()
+        .take(MAX_RESULTS)
+        .map(|(_, entry)| entry)
+        .collect()
+}
+
+/// 고른 명령을 반환. Enter/클릭으로 실행, Esc나 바깥 클릭으로 닫힘
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut CommandPaletteState,
+    entries: &[PaletteEntry],
+) -> Option<Command> {
+    if !state.open {
+        return None;
+    }
+    let mut chosen = None;
+
+    let (up, down, enter, escape) = ctx.input_mut(|i| {
+        (
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Enter),
+            i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+        )
+    });
+
+    let area = egui::Area::new(egui::Id::new("command_palette"))
+        .order(egui::Order::Foreground)
+        .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 90.0))
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.set_width(PALETTE_WIDTH);
+                let input = ui.add(
+                    egui::TextEdit::singleline(&mut state.query)
+                        .hint_text("명령 검색…")
+                        .desired_width(f32::INFINITY),
+                );
+                if state.focus_pending {
+                    input.request_focus();
+                    state.focus_pending = false;
+                }
+                if input.changed() {
+                    state.selected = 0;
+                }
+
+                let results = search(&state.query, entries);
+                if results.is_empty() {
+                    ui.label(
+                        RichText::new("일치하는 명령이 없습니다")
+                            .size(11.0)
+                            .color(Color32::from_gray(140)),
+                    );
+                    return;
+                }
+                if down {
+                    state.selected = (state.selected + 1) % results.len();
+                }
+                if up {
+                    state.selected = (state.selected + results.len() - 1) % results.len();
+                }
+                state.selected = state.selected.min(results.len() - 1);
+
+                ui.add_space(4.0);
+                for (i, entry) in results.iter().enumerate() {
+                    let row = ui.horizontal(|ui| {
+                        let title = ui.selectable_label(i == state.selected, &entry.title);
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(
+                                RichText::new(&entry.shortcut)
+                                    .size(11.0)
+                                    .color(Color32::from_gray(130)),
+                            );
+                        });
+                        title
+                    });
+                    if row.inner.clicked() {
+                        chosen = Some(entry.command);
+                    }
+                }
+                if enter {
+                    chosen = Some(results[state.selected].command);
+                }
+            });
+        });
+
+    let clicked_outside = ctx.input(|i| {
+        i.pointer.any_pressed()
+            && i.pointer
+                .interact_pos()
+                .is_some_and(|p| !area.response.rect.contains(p))
+    });
+    if chosen.is_some() || escape || clicked_outside {
+        state.open = false;
+    }
+    chosen
+}
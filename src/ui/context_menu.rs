@@ -0,0 +1,120 @@
+use egui::{self, Color32, RichText};
+
+use super::color_swatch;
+use crate::commands::Command;
+use crate::keymap::Keymap;
+use crate::types::CARD_COLORS;
+
+// 우클릭한 대상
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ContextTarget {
+    Canvas,
+    // 카드 (문서 인덱스). 메뉴 동작은 이 카드가 포함된 선택 전체에 적용
+    Card { index: usize },
+}
+
+// 열린 메뉴
+#[derive(Copy, Clone, Debug)]
+pub struct ContextMenu {
+    pub screen: egui::Pos2,
+    // 우클릭한 월드 좌표 (추가/붙여넣기/연결 기준)
+    pub world: [f32; 2],
+    pub target: ContextTarget,
+}
+
+#[derive(Default)]
+pub struct ContextMenuState {
+    pub open: Option<ContextMenu>,
+}
+
+#[derive(Default)]
+pub struct ContextMenuAction {
+    pub command: Option<Command>,
+    // 명령의 기준 위치 (월드 좌표)
+    pub anchor: [f32; 2],
+}
+
+/// 메뉴 항목. 누르면 chosen에 명령 기록
+fn pick(ui: &mut egui::Ui, keymap: &Keymap, command: Command, chosen: &mut Option<Command>) {
+    let shortcut = keymap.label(command);
+    let label = if shortcut.is_empty() {
+        command.title()
+    } else {
+        format!("{}    {}", command.title(), shortcut)
+    };
+    let button = egui::Button::new(label)
+        .frame(false)
+        .min_size(egui::vec2(160.0, 22.0));
+    if ui.add(button).clicked() {
+        *chosen = Some(command);
+    }
+}
+
+/// selection_len: 현재 선택한 카드 수 (카드 메뉴에서 연결 가능 여부 판단)
+pub fn show(
+    ctx: &egui::Context,
+    state: &mut ContextMenuState,
+    keymap: &Keymap,
+    selection_len: usize,
+) -> ContextMenuAction {
+    let Some(menu) = state.open else {
+        return ContextMenuAction::default();
+    };
+    let mut command = None;
+
+    let area = egui::Area::new(egui::Id::new("canvas_context_menu"))
+        .order(egui::Order::Foreground)
+        .fixed_pos(menu.screen)
+        .constrain(true)
+        .show(ctx, |ui| {
+            egui::Frame::popup(ui.style()).show(ui, |ui| {
+                ui.spacing_mut().item_spacing.y = 2.0;
+                match menu.target {
+                    ContextTarget::Canvas => {
+                        pick(ui, keymap, Command::AddCard, &mut command);
+                        pick(ui, keymap, Command::Paste, &mut command);
+                        ui.separator();
+                        pick(ui, keymap, Command::ZoomToFit, &mut command);
+                    }
+                    ContextTarget::Card { .. } => {
+                        ui.label(
+                            RichText::new("색")
+                                .size(11.0)
+                                .color(Color32::from_gray(110)),
+                        );
+                        ui.horizontal(|ui| {
+                            for (n, rgb) in CARD_COLORS.into_iter().enumerate() {
+                                if color_swatch(ui, rgb, false) {
+                                    command = Some(Command::CardColor(n));
+                                }
+                            }
+                        });
+                        ui.separator();
+                        pick(ui, keymap, Command::Duplicate, &mut command);
+                        ui.add_enabled_ui(selection_len > 1, |ui| {
+                            pick(ui, keymap, Command::ConnectToCard, &mut command);
+                        });
+                        pick(ui, keymap, Command::BringToFront, &mut command);
+                        ui.separator();
+                        pick(ui, keymap, Command::Delete, &mut command);
+                    }
+                }
+            });
+        });
+
+    // 항목을 고르거나, 메뉴 밖을 누르거나, Esc를 누르면 닫힘
+    let dismissed = ctx.input(|i| {
+        i.key_pressed(egui::Key::Escape)
+            || (i.pointer.primary_pressed() || i.pointer.middle_down())
+                && i.pointer
+                    .interact_pos()
+                    .is_some_and(|p| !area.response.rect.contains(p))
+    });
+    if command.is_some() || dismissed {
+        state.open = None;
+    }
+    ContextMenuAction {
+        command,
+        anchor: menu.world,
+    }
+}
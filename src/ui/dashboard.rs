@@ -0,0 +1,133 @@
+use egui::{self, Color32, CornerRadius, RichText, Vec2};
+
+use super::{heading, rgb_color};
+use crate::stats::{BoardStats, HEATMAP_COLS, HEATMAP_ROWS};
+use crate::transcript::{self, SAMPLE_TRANSCRIPT};
+
+// 색상별 목록 최대 줄 수
+const MAX_COLOR_ROWS: usize = 8;
+const BAR_COLOR: Color32 = Color32::from_rgb(79, 57, 246);
+
+fn metric(ui: &mut egui::Ui, label: &str, value: String) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(label).size(12.0));
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            ui.label(RichText::new(value).size(12.0).strong());
+        });
+    });
+}
+
+/// 전체 폭 대비 비율만큼 채운 막대
+fn bar(ui: &mut egui::Ui, fraction: f32, color: Color32) {
+    let (rect, _) =
+        ui.allocate_exact_size(Vec2::new(ui.available_width(), 6.0), egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, CornerRadius::same(3), Color32::from_gray(235));
+    let mut filled = rect;
+    filled.set_width(rect.width() * fraction.clamp(0.0, 1.0));
+    painter.rect_filled(filled, CornerRadius::same(3), color);
+}
+
+pub fn show(ui: &mut egui::Ui, stats: Option<&BoardStats>) {
+    ui.add_space(8.0);
+    ui.label(RichText::new("📊 대시보드").size(15.0).strong());
+
+    let Some(stats) = stats else {
+        ui.label(
+            RichText::new("계산 중…")
+                .size(11.0)
+                .color(Color32::from_gray(140)),
+        );
+        return;
+    };
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            heading(ui, "보드");
+            metric(ui, "카드", stats.cards.to_string());
+            metric(ui, "연결", stats.edges.to_string());
+            metric(ui, "그룹", stats.groups.to_string());
+            metric(ui, "연결 요소", stats.components.to_string());
+            metric(
+                ui,
+                "가장 큰 요소",
+                format!("카드 {}", stats.largest_component),
+            );
+            metric(ui, "고립된 카드", stats.isolated.to_string());
+            metric(ui, "최대 트리 깊이", format!("{}단계", stats.max_depth));
+
+            heading(ui, "색상별 카드");
+            for &(color, count) in stats.colors.iter().take(MAX_COLOR_ROWS) {
+                ui.horizontal(|ui| {
+                    let (swatch, _) =
+                        ui.allocate_exact_size(Vec2::splat(10.0), egui::Sense::hover());
+                    ui.painter()
+                        .rect_filled(swatch, CornerRadius::same(2), rgb_color(color));
+                    ui.label(RichText::new(count.to_string()).size(11.0));
+                    bar(
+                        ui,
+                        count as f32 / stats.cards.max(1) as f32,
+                        rgb_color(color),
+                    );
+                });
+            }
+            if stats.colors.len() > MAX_COLOR_ROWS {
+                ui.label(
+                    RichText::new(format!("외 {}색", stats.colors.len() - MAX_COLOR_ROWS))
+                        .size(10.0)
+                        .color(Color32::from_gray(140)),
+                );
+            }
+
+            heading(ui, "카드 밀도");
+            show_heatmap(ui, stats);
+
+            heading(ui, "화자별 발화 시간");
+            let talk = transcript::talk_time(&SAMPLE_TRANSCRIPT);
+            let total = talk.iter().map(|(_, secs)| secs).sum::<u32>().max(1);
+            for (speaker, secs) in talk {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(speaker).size(11.0));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.label(
+                            RichText::new(format!("{}:{:02}", secs / 60, secs % 60))
+                                .size(11.0)
+                                .color(Color32::from_gray(110)),
+                        );
+                    });
+                });
+                bar(ui, secs as f32 / total as f32, BAR_COLOR);
+                ui.add_space(2.0);
+            }
+        });
+}
+
+fn show_heatmap(ui: &mut egui::Ui, stats: &BoardStats) {
+    let width = ui.available_width();
+    let cell = width / HEATMAP_COLS as f32;
+    let (rect, _) = ui.allocate_exact_size(
+        Vec2::new(width, cell * HEATMAP_ROWS as f32),
+        egui::Sense::hover(),
+    );
+    let painter = ui.painter();
+    painter.rect_filled(rect, CornerRadius::same(4), Color32::from_gray(245));
+
+    let heatmap = &stats.heatmap;
+    if heatmap.max == 0 {
+        return;
+    }
+    for (i, &count) in heatmap.cells.iter().enumerate() {
+        if count == 0 {
+            continue;
+        }
+        let (row, col) = (i / HEATMAP_COLS, i % HEATMAP_COLS);
+        let min = rect.min + Vec2::new(col as f32 * cell, row as f32 * cell);
+        let alpha = 0.15 + 0.85 * count as f32 / heatmap.max as f32;
+        painter.rect_filled(
+            egui::Rect::from_min_size(min, Vec2::splat(cell)),
+            0.0,
+            BAR_COLOR.gamma_multiply(alpha),
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use egui::{self, Color32, RichText};
+
+/// 확인을 누를 때까지 오류를 보여 주는 창. 닫으면 message를 비움
+pub fn show(ctx: &egui::Context, title: &str, message: &mut Option<String>) {
+    let Some(text) = message.as_deref() else {
+        return;
+    };
+    let mut close = false;
+
+    egui::Window::new(title)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(
+                RichText::new(text)
+                    .size(11.0)
+                    .color(Color32::from_rgb(220, 38, 38)),
+            );
+            ui.add_space(6.0);
+            let enter = ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button("확인").clicked() || enter {
+                close = true;
+            }
+        });
+
+    if close {
+        *message = None;
+    }
+}
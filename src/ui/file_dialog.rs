@@ -0,0 +1,139 @@
+use egui::{self, Color32, RichText};
+
+use crate::formats::Format;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FileDialogMode {
+    Import,
+    Export,
+}
+
+pub struct FileDialogState {
+    pub mode: Option<FileDialogMode>,
+    pub path: String,
+    pub replace: bool,
+    pub include_transcript: bool,
+    pub error: Option<String>,
+    // 텍스트/바이너리 포맷 왕복 시간 비교 결과
+    pub benchmark: Option<String>,
+}
+
+impl Default for FileDialogState {
+    fn default() -> Self {
+        Self {
+            mode: None,
+            path: format!("board.{}", Format::JsonCanvas.extension()),
+            replace: false,
+            include_transcript: true,
+            error: None,
+            benchmark: None,
+        }
+    }
+}
+
+impl FileDialogState {
+    pub fn open(&mut self, mode: FileDialogMode) {
+        self.mode = Some(mode);
+        self.error = None;
+    }
+}
+
+pub struct FileDialogRequest {
+    pub mode: FileDialogMode,
+    pub path: String,
+    pub replace: bool,
+    pub include_transcript: bool,
+    pub benchmark: bool,
+}
+
+pub fn show(ctx: &egui::Context, state: &mut FileDialogState) -> Option<FileDialogRequest> {
+    let mode = state.mode?;
+    let title = match mode {
+        FileDialogMode::Import => "📥 가져오기",
+        FileDialogMode::Export => "📤 내보내기",
+    };
+
+    let mut request = None;
+    let mut close = false;
+
+    egui::Window::new(title)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(RichText::new("파일 경로").size(12.0));
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut state.path)
+                    .desired_width(320.0)
+                    .hint_text("board.canvas"),
+            );
+
+            // 확장자 빠른 선택
+            ui.horizontal_wrapped(|ui| {
+                for format in Format::ALL {
+                    if mode == FileDialogMode::Export && !format.can_export() {
+                        continue;
+                    }
+                    if ui.small_button(format.label()).clicked() {
+                        let stem =
+                            std::path::Path::new(&state.path).with_extension(format.extension());
+                        state.path = stem.display().to_string();
+                    }
+                }
+            });
+
+            if mode == FileDialogMode::Import {
+                ui.checkbox(&mut state.replace, "현재 보드 대체");
+            }
+            let is_markdown =
+                Format::from_path(std::path::Path::new(&state.path)) == Some(Format::Markdown);
+            if mode == FileDialogMode::Export && is_markdown {
+                ui.checkbox(&mut state.include_transcript, "전사문 포함 (회의록)");
+            }
+
+            if mode == FileDialogMode::Export {
+                if ui.small_button("⏱ 텍스트 대비 바이너리 벤치마크").clicked() {
+                    request = Some(FileDialogRequest {
+                        mode,
+                        path: state.path.trim().to_string(),
+                        replace: state.replace,
+                        include_transcript: state.include_transcript,
+                        benchmark: true,
+                    });
+                }
+                if let Some(report) = &state.benchmark {
+                    ui.label(RichText::new(report).size(11.0).monospace());
+                }
+            }
+
+            if let Some(err) = &state.error {
+                ui.label(
+                    RichText::new(err)
+                        .size(11.0)
+                        .color(Color32::from_rgb(220, 38, 38)),
+                );
+            }
+
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                let submit = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                if ui.button("확인").clicked() || submit {
+                    request = Some(FileDialogRequest {
+                        mode,
+                        path: state.path.trim().to_string(),
+                        replace: state.replace,
+                        include_transcript: state.include_transcript,
+                        benchmark: false,
+                    });
+                }
+                if ui.button("취소").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+    if close {
+        state.mode = None;
+    }
+    request
+}
@@ -1,27 +1,70 @@
 use egui::{self, Color32, CornerRadius, RichText, Vec2};
 
+use crate::history::{
+    DocumentDiff, History, DIFF_ADDED_COLOR, DIFF_MOVED_COLOR, DIFF_REMOVED_COLOR,
+};
+use crate::transcript::{self, TranscriptEntry, SAMPLE_TRANSCRIPT};
+use crate::types::INSPECTOR_WIDTH;
+
 pub struct InspectorState {
     pub open: bool,
-    pub active_tab: usize, // 0: 속성, 1: 전사문
+    // 사용자가 끌어서 바꾼 패널 폭
+    pub width: f32,
+    pub active_tab: usize, // 0: 속성, 1: 전사문, 2: 기록
+    pub history: HistoryPanelState,
 }
 
 impl Default for InspectorState {
     fn default() -> Self {
         Self {
             open: true,
+            width: INSPECTOR_WIDTH,
             active_tab: 1, // 전사문 탭 활성
+            history: HistoryPanelState::default(),
         }
     }
 }
 
-struct TranscriptEntry {
-    speaker: &'static str,
-    text: &'static str,
-    timestamp: &'static str,
-    badge_bg: Color32,
-    badge_text: Color32,
+// 기록 탭: 새 스냅샷 이름과 비교 선택
+#[derive(Default)]
+pub struct HistoryPanelState {
+    pub new_name: String,
+    // 비교 기준 스냅샷 (None이면 비교 안 함)
+    pub base: Option<usize>,
+    // 비교 대상 스냅샷 (None이면 현재 문서)
+    pub target: Option<usize>,
+}
+
+impl HistoryPanelState {
+    /// 스냅샷이 삭제되면 선택 인덱스를 맞춤
+    pub fn forget_snapshot(&mut self, removed: usize) {
+        let shift = |slot: &mut Option<usize>| match *slot {
+            Some(i) if i == removed => *slot = None,
+            Some(i) if i > removed => *slot = Some(i - 1),
+            _ => {}
+        };
+        shift(&mut self.base);
+        shift(&mut self.target);
+    }
+}
+
+#[derive(Default)]
+pub struct InspectorAction {
+    pub take_snapshot: Option<String>,
+    pub delete_snapshot: Option<usize>,
 }
 
+// 변경 내역 목록 최대 줄 수
+const MAX_DIFF_DETAILS: usize = 200;
+
+// 화자 뱃지 색상 (배경, 글자) — 화자 등장 순서대로 사용
+const BADGE_COLORS: [(Color32, Color32); 4] = [
+    (Color32::from_rgb(219, 234, 254), Color32::from_rgb(25, 60, 184)),
+    (Color32::from_rgb(220, 252, 231), Color32::from_rgb(1, 102, 48)),
+    (Color32::from_rgb(243, 232, 255), Color32::from_rgb(110, 17, 176)),
+    (Color32::from_rgb(255, 237, 212), Color32::from_rgb(159, 45, 0)),
+];
+
 pub fn frame() -> egui::Frame {
     egui::Frame::new()
         .fill(Color32::from_rgb(252, 252, 252))
@@ -32,48 +75,35 @@ pub fn frame() -> egui::Frame {
         .inner_margin(egui::Margin::ZERO)
 }
 
-pub fn show(ui: &mut egui::Ui, state: &mut InspectorState) {
+fn tab_button(ui: &mut egui::Ui, label: &str, active: bool) -> bool {
+    let button = egui::Button::new(RichText::new(label).size(10.0).color(if active {
+        Color32::BLACK
+    } else {
+        Color32::from_rgb(174, 174, 174)
+    }))
+    .fill(if active {
+        Color32::from_rgb(229, 229, 234)
+    } else {
+        Color32::TRANSPARENT
+    })
+    .corner_radius(CornerRadius::same(13))
+    .min_size(Vec2::new(0.0, 32.0));
+    ui.add(button).clicked()
+}
+
+pub fn show(
+    ui: &mut egui::Ui,
+    state: &mut InspectorState,
+    history: &History,
+    diff: Option<&DocumentDiff>,
+) -> InspectorAction {
     // 탭 바
     ui.horizontal(|ui| {
         ui.add_space(12.0);
-
-        // 속성 탭
-        let attr_active = state.active_tab == 0;
-        let attr_btn =
-            egui::Button::new(RichText::new("🔧 속성").size(10.0).color(if attr_active {
-                Color32::BLACK
-            } else {
-                Color32::from_rgb(174, 174, 174)
-            }))
-            .fill(if attr_active {
-                Color32::from_rgb(229, 229, 234)
-            } else {
-                Color32::TRANSPARENT
-            })
-            .corner_radius(CornerRadius::same(13))
-            .min_size(Vec2::new(0.0, 32.0));
-        if ui.add(attr_btn).clicked() {
-            state.active_tab = 0;
-        }
-
-        // 전사문 탭
-        let trans_active = state.active_tab == 1;
-        let trans_btn = egui::Button::new(RichText::new("📝 전사문").size(10.0).color(
-            if trans_active {
-                Color32::BLACK
-            } else {
-                Color32::from_rgb(174, 174, 174)
-            },
-        ))
-        .fill(if trans_active {
-            Color32::from_rgb(229, 229, 234)
-        } else {
-            Color32::TRANSPARENT
-        })
-        .corner_radius(CornerRadius::same(13))
-        .min_size(Vec2::new(0.0, 32.0));
-        if ui.add(trans_btn).clicked() {
-            state.active_tab = 1;
+        for (idx, label) in ["🔧 속성", "📝 전사문", "🕘 기록"].into_iter().enumerate() {
+            if tab_button(ui, label, state.active_tab == idx) {
+                state.active_tab = idx;
+            }
         }
     });
 
@@ -82,8 +112,10 @@ pub fn show(ui: &mut egui::Ui, state: &mut InspectorState) {
     match state.active_tab {
         0 => show_properties(ui),
         1 => show_transcript(ui),
+        2 => return show_history(ui, &mut state.history, history, diff),
         _ => {}
     }
+    InspectorAction::default()
 }
 
 fn show_properties(ui: &mut egui::Ui) {
@@ -98,63 +130,27 @@ fn show_properties(ui: &mut egui::Ui) {
 }
 
 fn show_transcript(ui: &mut egui::Ui) {
-    let entries = [
-        TranscriptEntry {
-            speaker: "김팀장",
-            text: "안녕하세요, 여러분. 오늘은 2026년 상반기 팀 워크샵 기획에 대해 논의하려고 합니다. 팀 결속력 강화와 전략 공유가 주요 목표입니다.",
-            timestamp: "00:00",
-            badge_bg: Color32::from_rgb(219, 234, 254),
-            badge_text: Color32::from_rgb(25, 60, 184),
-        },
-        TranscriptEntry {
-            speaker: "이과장",
-            text: "좋습니다. 먼저 워크샵 장소부터 정해야 할 것 같은데요, 참여 인원이 20명 정도 되니까 예산과 접근성을 고려해야 합니다.",
-            timestamp: "00:13",
-            badge_bg: Color32::from_rgb(220, 252, 231),
-            badge_text: Color32::from_rgb(1, 102, 48),
-        },
-        TranscriptEntry {
-            speaker: "김팀장",
-            text: "맞습니다. 그리고 어떤 프로그램을 진행할지도 중요하죠. 팀 빌딩 활동, 교육, 전략 회의 등을 어떻게 구성할지 고민이 필요합니다.",
-            timestamp: "00:26",
-            badge_bg: Color32::from_rgb(219, 234, 254),
-            badge_text: Color32::from_rgb(25, 60, 184),
-        },
-        TranscriptEntry {
-            speaker: "박대리",
-            text: "제 생각에는 게임형 팀 챌린지를 도입하면 어떨까 싶습니다. 방탈출이나 미션 수행 같은 게임 요소를 넣으면 참여도가 훨씬 높아질 것 같아요.",
-            timestamp: "00:39",
-            badge_bg: Color32::from_rgb(243, 232, 255),
-            badge_text: Color32::from_rgb(110, 17, 176),
-        },
-        TranscriptEntry {
-            speaker: "이과장",
-            text: "장소 관련해서는 제주도 리조트를 검토해봤는데요, 3박 4일 패키지로 진행할 수 있습니다. 시설은 정말 좋은데... 문제는 1인당 비용이 35만원 정도로 예산을 초과한다는 점이에요.",
-            timestamp: "00:51",
-            badge_bg: Color32::from_rgb(220, 252, 231),
-            badge_text: Color32::from_rgb(1, 102, 48),
-        },
-        TranscriptEntry {
-            speaker: "최주임",
-            text: "제주도면 이동 시간도 문제인 것 같아요. 비행기로 3시간 이상 걸리니까 이동만으로도 하루가 소비되고, 실제 워크숍 시간이 줄어들 것 같습니다.",
-            timestamp: "01:09",
-            badge_bg: Color32::from_rgb(255, 237, 212),
-            badge_text: Color32::from_rgb(159, 45, 0),
-        },
-    ];
+    let entries = &SAMPLE_TRANSCRIPT;
+    let speakers = transcript::speakers(entries);
 
     egui::ScrollArea::vertical()
         .auto_shrink([false, false])
         .show(ui, |ui| {
             ui.add_space(7.0);
-            for entry in &entries {
-                show_transcript_card(ui, entry);
+            for entry in entries {
+                let speaker_idx = speakers.iter().position(|s| *s == entry.speaker);
+                let badge = BADGE_COLORS[speaker_idx.unwrap_or(0) % BADGE_COLORS.len()];
+                show_transcript_card(ui, entry, badge);
                 ui.add_space(10.0);
             }
         });
 }
 
-fn show_transcript_card(ui: &mut egui::Ui, entry: &TranscriptEntry) {
+fn show_transcript_card(
+    ui: &mut egui::Ui,
+    entry: &TranscriptEntry,
+    (badge_bg, badge_text): (Color32, Color32),
+) {
     let frame = egui::Frame::new()
         .stroke(egui::Stroke::new(1.0, Color32::from_rgb(229, 231, 235)))
         .corner_radius(CornerRadius::same(20))
@@ -166,14 +162,14 @@ fn show_transcript_card(ui: &mut egui::Ui, entry: &TranscriptEntry) {
         // 헤더: 화자 뱃지 + 타임스탬프
         ui.horizontal(|ui| {
             let badge_frame = egui::Frame::new()
-                .fill(entry.badge_bg)
+                .fill(badge_bg)
                 .corner_radius(CornerRadius::same(4))
                 .inner_margin(egui::Margin::symmetric(8, 2));
             badge_frame.show(ui, |ui| {
                 ui.label(
                     RichText::new(entry.speaker)
                         .size(12.0)
-                        .color(entry.badge_text),
+                        .color(badge_text),
                 );
             });
 
@@ -200,3 +196,116 @@ fn show_transcript_card(ui: &mut egui::Ui, entry: &TranscriptEntry) {
         );
     });
 }
+
+fn diff_color(color: [f32; 3]) -> Color32 {
+    let c = |v: f32| (v * 255.0) as u8;
+    Color32::from_rgb(c(color[0]), c(color[1]), c(color[2]))
+}
+
+fn show_history(
+    ui: &mut egui::Ui,
+    panel: &mut HistoryPanelState,
+    history: &History,
+    diff: Option<&DocumentDiff>,
+) -> InspectorAction {
+    let mut action = InspectorAction::default();
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            ui.add_space(8.0);
+            ui.horizontal(|ui| {
+                ui.add_space(12.0);
+                ui.add(
+                    egui::TextEdit::singleline(&mut panel.new_name)
+                        .desired_width(180.0)
+                        .hint_text("스냅샷 이름"),
+                );
+                if ui.button("📸 스냅샷").clicked() {
+                    action.take_snapshot = Some(std::mem::take(&mut panel.new_name));
+                }
+            });
+            ui.add_space(8.0);
+
+            if history.snapshots.is_empty() {
+                ui.horizontal(|ui| {
+                    ui.add_space(12.0);
+                    ui.label(
+                        RichText::new("저장된 스냅샷이 없습니다")
+                            .size(12.0)
+                            .color(Color32::from_rgb(174, 174, 174)),
+                    );
+                });
+                return;
+            }
+
+            // 스냅샷 목록: 기준(A) / 대상(B) 선택
+            for (idx, snapshot) in history.snapshots.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.add_space(12.0);
+                    if ui.selectable_label(panel.base == Some(idx), "A").clicked() {
+                        panel.base = (panel.base != Some(idx)).then_some(idx);
+                    }
+                    if ui.selectable_label(panel.target == Some(idx), "B").clicked() {
+                        panel.target = (panel.target != Some(idx)).then_some(idx);
+                    }
+                    ui.label(
+                        RichText::new(format!(
+                            "{} ({}개)",
+                            snapshot.name,
+                            snapshot.document.len()
+                        ))
+                        .size(12.0),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.add_space(12.0);
+                        if ui.small_button("✕").clicked() {
+                            action.delete_snapshot = Some(idx);
+                        }
+                    });
+                });
+            }
+
+            ui.add_space(6.0);
+            ui.separator();
+            ui.horizontal(|ui| {
+                ui.add_space(12.0);
+                let describe = |slot: Option<usize>, fallback: &str| {
+                    slot.and_then(|i| history.snapshots.get(i))
+                        .map_or(fallback.to_string(), |s| s.name.clone())
+                };
+                ui.label(
+                    RichText::new(format!(
+                        "A: {}  →  B: {}",
+                        describe(panel.base, "선택 안 함"),
+                        describe(panel.target, "현재 보드")
+                    ))
+                    .size(11.0)
+                    .color(Color32::from_rgb(106, 114, 130)),
+                );
+            });
+
+            let Some(diff) = diff else {
+                return;
+            };
+            ui.horizontal(|ui| {
+                ui.add_space(12.0);
+                ui.label(RichText::new(diff.headline()).size(12.0).strong());
+            });
+            ui.add_space(4.0);
+            for line in diff.details(MAX_DIFF_DETAILS) {
+                let color = match line.chars().next() {
+                    Some('+') => diff_color(DIFF_ADDED_COLOR),
+                    Some('−') => diff_color(DIFF_REMOVED_COLOR),
+                    Some('↗') => diff_color(DIFF_MOVED_COLOR),
+                    _ => Color32::from_rgb(54, 65, 83),
+                };
+                ui.horizontal(|ui| {
+                    ui.add_space(12.0);
+                    ui.label(RichText::new(line).size(11.5).color(color));
+                });
+            }
+        });
+
+    action
+}
@@ -1,18 +1,38 @@
 use egui::{self, Color32, RichText, Vec2};
+use serde::{Deserialize, Serialize};
 
+use crate::commands::Command;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LeftSection {
+    Projects,
+    Dashboard,
+    Settings,
+}
+
+#[derive(Default)]
 pub struct LeftTabState {
-    pub active_section: usize,
+    // 펼쳐진 섹션 패널 (None이면 캔버스만)
+    pub active_section: Option<LeftSection>,
 }
 
-impl Default for LeftTabState {
-    fn default() -> Self {
-        Self { active_section: 0 }
+impl LeftTabState {
+    /// 해당 섹션 패널을 열고, 이미 열려 있으면 닫음
+    pub fn toggle(&mut self, section: LeftSection) {
+        self.active_section = if self.active_section == Some(section) {
+            None
+        } else {
+            Some(section)
+        };
     }
 }
 
 struct TabButton {
     icon: &'static str,
     label: &'static str,
+    section: LeftSection,
+    // 누르면 섹션을 열고 닫는 명령
+    command: Command,
 }
 
 pub fn frame() -> egui::Frame {
@@ -21,7 +41,20 @@ pub fn frame() -> egui::Frame {
         .inner_margin(egui::Margin::ZERO)
 }
 
-pub fn show(ui: &mut egui::Ui, state: &mut LeftTabState) {
+/// 섹션을 펼쳤을 때 탭 옆에 붙는 패널
+pub fn section_frame() -> egui::Frame {
+    egui::Frame::new()
+        .fill(Color32::from_rgb(252, 252, 252))
+        .stroke(egui::Stroke::new(
+            1.0,
+            Color32::from_rgba_premultiplied(0, 0, 0, 38),
+        ))
+        .inner_margin(egui::Margin::same(12))
+}
+
+/// 누른 섹션 버튼의 명령 (열고 닫기는 AppState가 명령으로 처리)
+pub fn show(ui: &mut egui::Ui, state: &LeftTabState) -> Option<Command> {
+    let mut command = None;
     ui.vertical_centered(|ui| {
         ui.add_space(50.0);
 
@@ -51,16 +84,21 @@ pub fn show(ui: &mut egui::Ui, state: &mut LeftTabState) {
             TabButton {
                 icon: "📁",
                 label: "프로젝트",
+                section: LeftSection::Projects,
+                command: Command::ShowProjects,
             },
             TabButton {
                 icon: "📊",
                 label: "대시보드",
+                section: LeftSection::Dashboard,
+                command: Command::ShowDashboard,
             },
         ];
 
-        for (i, btn) in section1.iter().enumerate() {
-            let is_active = state.active_section == i;
-            show_tab_button(ui, btn, is_active);
+        for btn in &section1 {
+            if show_tab_button(ui, btn, state) {
+                command = Some(btn.command);
+            }
             ui.add_space(12.0);
         }
 
@@ -74,36 +112,38 @@ pub fn show(ui: &mut egui::Ui, state: &mut LeftTabState) {
         );
         ui.add_space(12.0);
 
-        let section2 = [
-            TabButton {
-                icon: "📁",
-                label: "프로젝트",
-            },
-            TabButton {
-                icon: "📊",
-                label: "대시보드",
-            },
-            TabButton {
-                icon: "⚙",
-                label: "설정",
-            },
-        ];
+        let section2 = [TabButton {
+            icon: "⚙",
+            label: "설정",
+            section: LeftSection::Settings,
+            command: Command::ShowSettings,
+        }];
 
         for btn in &section2 {
-            show_tab_button(ui, btn, false);
+            if show_tab_button(ui, btn, state) {
+                command = Some(btn.command);
+            }
             ui.add_space(12.0);
         }
     });
+    command
 }
 
-fn show_tab_button(ui: &mut egui::Ui, btn: &TabButton, is_active: bool) {
+/// 눌렸으면 true
+fn show_tab_button(ui: &mut egui::Ui, btn: &TabButton, state: &LeftTabState) -> bool {
+    let is_active = state.active_section == Some(btn.section);
     let text_color = if is_active {
         Color32::from_rgb(79, 57, 246)
     } else {
         Color32::from_rgb(82, 82, 82)
     };
-    ui.vertical_centered(|ui| {
-        ui.label(RichText::new(btn.icon).size(15.0));
-        ui.label(RichText::new(btn.label).size(10.0).color(text_color));
-    });
+    let response = ui
+        .vertical_centered(|ui| {
+            ui.label(RichText::new(btn.icon).size(15.0));
+            ui.label(RichText::new(btn.label).size(10.0).color(text_color));
+        })
+        .response
+        .interact(egui::Sense::click())
+        .on_hover_cursor(egui::CursorIcon::PointingHand);
+    response.clicked()
 }
@@ -1,5 +1,57 @@
 pub mod ai_button;
+pub mod command_palette;
+pub mod context_menu;
+pub mod dashboard;
+pub mod error_dialog;
+pub mod file_dialog;
 pub mod inspector;
 pub mod left_tab;
+pub mod project_browser;
+pub mod recovery_dialog;
+pub mod settings_panel;
 pub mod toolbar;
 pub mod top_bar;
+
+/// 지금으로부터 지난 시간 ("3분 전")
+pub fn describe_age(time: std::time::SystemTime) -> String {
+    let secs = time.elapsed().map_or(0, |d| d.as_secs());
+    match secs {
+        0..=59 => "방금 전".to_string(),
+        60..=3599 => format!("{}분 전", secs / 60),
+        3600..=86399 => format!("{}시간 전", secs / 3600),
+        _ => format!("{}일 전", secs / 86400),
+    }
+}
+
+/// 패널 안 구역 제목 (작은 회색 글씨, 위아래 여백)
+pub fn heading(ui: &mut egui::Ui, text: &str) {
+    ui.add_space(10.0);
+    ui.label(
+        egui::RichText::new(text)
+            .size(11.0)
+            .color(egui::Color32::from_gray(110)),
+    );
+    ui.add_space(4.0);
+}
+
+/// 0~1 RGB → egui 색상
+pub fn rgb_color(color: [f32; 3]) -> egui::Color32 {
+    let c = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    egui::Color32::from_rgb(c(color[0]), c(color[1]), c(color[2]))
+}
+
+/// 클릭할 수 있는 색 견본. 선택된 색이면 테두리 표시
+pub fn color_swatch(ui: &mut egui::Ui, color: [f32; 3], selected: bool) -> bool {
+    let (rect, response) = ui.allocate_exact_size(egui::Vec2::splat(18.0), egui::Sense::click());
+    ui.painter()
+        .rect_filled(rect, egui::CornerRadius::same(4), rgb_color(color));
+    if selected {
+        ui.painter().rect_stroke(
+            rect.expand(2.0),
+            egui::CornerRadius::same(5),
+            egui::Stroke::new(2.0, egui::Color32::from_rgb(79, 57, 246)),
+            egui::StrokeKind::Outside,
+        );
+    }
+    response.clicked()
+}
@@ -0,0 +1,253 @@
+use std::path::PathBuf;
+
+use egui::{self, Color32, CornerRadius, RichText, Vec2};
+
+use super::{describe_age, rgb_color};
+use crate::workspace::{self, Preview, ProjectEntry};
+
+pub struct ProjectBrowserState {
+    pub workspace: String,
+    pub query: String,
+    pub entries: Vec<ProjectEntry>,
+    // 패널을 처음 열 때 한 번 읽음
+    pub scanned: bool,
+    // 삭제 확인 중인 파일
+    pub confirm_delete: Option<PathBuf>,
+    // 현재 보드를 저장하려는데 이미 있는 파일 (덮어쓸지 확인 중)
+    pub confirm_overwrite: Option<PathBuf>,
+    pub error: Option<String>,
+}
+
+impl Default for ProjectBrowserState {
+    fn default() -> Self {
+        Self {
+            workspace: workspace::default_dir().display().to_string(),
+            query: String::new(),
+            entries: Vec::new(),
+            scanned: false,
+            confirm_delete: None,
+            confirm_overwrite: None,
+            error: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ProjectBrowserAction {
+    pub refresh: bool,
+    pub save_current: bool,
+    // 확인을 받고 덮어쓸 파일
+    pub overwrite: Option<PathBuf>,
+    // 이미 있는 파일과 겹치지 않는 새 이름으로 저장
+    pub save_as_new: bool,
+    pub open: Option<PathBuf>,
+    pub duplicate: Option<PathBuf>,
+    pub delete: Option<PathBuf>,
+}
+
+const THUMBNAIL_SIZE: Vec2 = Vec2::new(72.0, 52.0);
+
+pub fn show(ui: &mut egui::Ui, state: &mut ProjectBrowserState) -> ProjectBrowserAction {
+    let mut action = ProjectBrowserAction {
+        refresh: !state.scanned,
+        ..Default::default()
+    };
+
+    ui.add_space(8.0);
+    ui.label(RichText::new("📁 프로젝트").size(15.0).strong());
+    ui.add_space(8.0);
+
+    ui.label(
+        RichText::new("작업 공간")
+            .size(11.0)
+            .color(Color32::from_gray(110)),
+    );
+    ui.horizontal(|ui| {
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut state.workspace)
+                .desired_width(ui.available_width() - 30.0),
+        );
+        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+            action.refresh = true;
+        }
+        if ui.small_button("⟳").on_hover_text("새로고침").clicked() {
+            action.refresh = true;
+        }
+    });
+
+    ui.add_space(4.0);
+    ui.add(
+        egui::TextEdit::singleline(&mut state.query)
+            .hint_text("🔍 이름 검색")
+            .desired_width(f32::INFINITY),
+    );
+    ui.add_space(4.0);
+    if ui.button("💾 현재 보드를 작업 공간에 저장").clicked() {
+        action.save_current = true;
+    }
+    if let Some(path) = state.confirm_overwrite.clone() {
+        let name = path
+            .file_name()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        ui.label(RichText::new(format!("'{}' 파일이 이미 있습니다.", name)).size(11.0));
+        ui.horizontal(|ui| {
+            if ui.small_button("덮어쓰기").clicked() {
+                action.overwrite = Some(path.clone());
+            }
+            if ui.small_button("새 이름으로 저장").clicked() {
+                action.save_as_new = true;
+            }
+            if ui.small_button("취소").clicked() {
+                state.confirm_overwrite = None;
+            }
+        });
+    }
+
+    if let Some(err) = &state.error {
+        ui.label(
+            RichText::new(err)
+                .size(11.0)
+                .color(Color32::from_rgb(220, 38, 38)),
+        );
+    }
+    ui.add_space(6.0);
+    ui.separator();
+
+    let query = state.query.trim().to_lowercase();
+    let mut shown = 0;
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for entry in &state.entries {
+                if !query.is_empty() && !entry.name.to_lowercase().contains(&query) {
+                    continue;
+                }
+                shown += 1;
+                show_entry(ui, entry, &mut state.confirm_delete, &mut action);
+                ui.add_space(6.0);
+            }
+            if shown == 0 {
+                let message = if state.entries.is_empty() {
+                    "작업 공간에 보드 파일이 없습니다"
+                } else {
+                    "검색 결과가 없습니다"
+                };
+                ui.label(
+                    RichText::new(message)
+                        .size(11.0)
+                        .color(Color32::from_gray(140)),
+                );
+            }
+        });
+
+    action
+}
+
+fn show_entry(
+    ui: &mut egui::Ui,
+    entry: &ProjectEntry,
+    confirm_delete: &mut Option<PathBuf>,
+    action: &mut ProjectBrowserAction,
+) {
+    ui.horizontal(|ui| {
+        let (rect, response) = ui.allocate_exact_size(THUMBNAIL_SIZE, egui::Sense::click());
+        paint_thumbnail(ui, rect, &entry.preview);
+        if response.double_clicked() {
+            action.open = Some(entry.path.clone());
+        }
+
+        ui.vertical(|ui| {
+            ui.label(RichText::new(&entry.name).size(12.0).strong());
+            let age = entry.modified.map_or("-".to_string(), describe_age);
+            let detail = match &entry.preview {
+                Preview::Ready(thumbnail) => format!(
+                    "{} · {} · 카드 {}",
+                    entry.format.label(),
+                    age,
+                    thumbnail.card_count
+                ),
+                Preview::Loading => format!("{} · {} · 읽는 중…", entry.format.label(), age),
+                Preview::Failed(_) => format!("{} · {}", entry.format.label(), age),
+            };
+            ui.label(
+                RichText::new(detail)
+                    .size(10.0)
+                    .color(Color32::from_gray(120)),
+            );
+            if let Preview::Failed(err) = &entry.preview {
+                ui.label(
+                    RichText::new(err)
+                        .size(10.0)
+                        .color(Color32::from_rgb(220, 38, 38)),
+                );
+            }
+
+            ui.horizontal(|ui| {
+                if confirm_delete.as_ref() == Some(&entry.path) {
+                    ui.label(RichText::new("삭제할까요?").size(10.0));
+                    if ui.small_button("삭제").clicked() {
+                        action.delete = Some(entry.path.clone());
+                        *confirm_delete = None;
+                    }
+                    if ui.small_button("취소").clicked() {
+                        *confirm_delete = None;
+                    }
+                    return;
+                }
+                if ui
+                    .add_enabled(
+                        !matches!(entry.preview, Preview::Failed(_)),
+                        egui::Button::new("열기").small(),
+                    )
+                    .clicked()
+                {
+                    action.open = Some(entry.path.clone());
+                }
+                if ui.small_button("복제").clicked() {
+                    action.duplicate = Some(entry.path.clone());
+                }
+                if ui.small_button("삭제").clicked() {
+                    *confirm_delete = Some(entry.path.clone());
+                }
+            });
+        });
+    });
+}
+
+fn paint_thumbnail(ui: &egui::Ui, rect: egui::Rect, preview: &Preview) {
+    let painter = ui.painter();
+    painter.rect_filled(
+        rect,
+        CornerRadius::same(4),
+        Color32::from_rgb(240, 240, 243),
+    );
+    let Preview::Ready(thumbnail) = preview else {
+        let mark = if matches!(preview, Preview::Loading) {
+            "…"
+        } else {
+            "⚠"
+        };
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            mark,
+            egui::FontId::proportional(14.0),
+            Color32::from_gray(150),
+        );
+        return;
+    };
+
+    let inner = rect.shrink(6.0);
+    let side = inner.width().min(inner.height());
+    let area = egui::Rect::from_center_size(inner.center(), Vec2::splat(side));
+    let dot = Vec2::new(4.0, 2.5);
+    for &([x, y], color) in &thumbnail.points {
+        let center = area.min + Vec2::new(x * side, y * side);
+        painter.rect_filled(
+            egui::Rect::from_center_size(center, dot),
+            0.0,
+            rgb_color(color),
+        );
+    }
+}
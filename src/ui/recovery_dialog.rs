@@ -0,0 +1,69 @@
+use egui::{self, Color32, RichText};
+
+use super::describe_age;
+use crate::journal::Recovery;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RecoveryChoice {
+    Restore,
+    Discard,
+}
+
+// 시작 시 남아 있던 자동 저장 파일(보드별)에 대한 복구 안내
+pub struct RecoveryPrompt {
+    pub recoveries: Vec<Recovery>,
+    pub error: Option<String>,
+}
+
+pub fn show(ctx: &egui::Context, prompt: &RecoveryPrompt) -> Option<RecoveryChoice> {
+    let mut choice = None;
+
+    egui::Window::new("⚠ 작업 복구")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label("이전 세션이 정상적으로 종료되지 않았습니다.");
+            ui.label(format!(
+                "자동 저장된 보드 {}개를 복구할까요?",
+                prompt.recoveries.len()
+            ));
+            ui.add_space(4.0);
+
+            for recovery in &prompt.recoveries {
+                let snapshot = recovery
+                    .snapshot_time
+                    .map_or("없음".to_string(), describe_age);
+                ui.label(
+                    RichText::new(format!(
+                        "{} — 마지막 스냅샷: {} · 이후 작업 기록 {:.1}KB",
+                        recovery.name.as_deref().unwrap_or("이름 없는 보드"),
+                        snapshot,
+                        recovery.journal_bytes as f64 / 1024.0
+                    ))
+                    .size(11.0)
+                    .color(Color32::from_gray(110)),
+                );
+            }
+
+            if let Some(err) = &prompt.error {
+                ui.label(
+                    RichText::new(err)
+                        .size(11.0)
+                        .color(Color32::from_rgb(220, 38, 38)),
+                );
+            }
+
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                if ui.button("복구").clicked() {
+                    choice = Some(RecoveryChoice::Restore);
+                }
+                if ui.button("버리고 새로 시작").clicked() {
+                    choice = Some(RecoveryChoice::Discard);
+                }
+            });
+        });
+
+    choice
+}
@@ -0,0 +1,160 @@
+use egui::{self, Color32, RichText};
+
+use super::{color_swatch, heading};
+use crate::formats::{parse_hex_color, to_hex_color};
+use crate::settings::{
+    Settings, Theme, WheelAction, GRID_SIZE_RANGE, PAN_FRICTION_RANGE, ZOOM_STEP_RANGE,
+};
+use crate::types::CARD_COLORS;
+
+// 편집 중인 설정 (바뀌면 바로 적용되고 저장됨)
+#[derive(Default)]
+pub struct SettingsPanelState {
+    pub draft: Settings,
+    // 불러오기/검증 경고
+    pub warnings: Vec<String>,
+    // 설정 파일 경로 (표시용, 없으면 저장 안 됨)
+    pub path: Option<String>,
+    pub keymap_warnings: Vec<String>,
+    pub keymap_path: Option<String>,
+}
+
+#[derive(Default)]
+pub struct SettingsAction {
+    pub changed: bool,
+    pub reset: bool,
+    pub reload_keymap: bool,
+}
+
+pub fn show(ui: &mut egui::Ui, state: &mut SettingsPanelState) -> SettingsAction {
+    let before = state.draft.clone();
+    let mut action = SettingsAction::default();
+    let draft = &mut state.draft;
+
+    ui.add_space(8.0);
+    ui.label(RichText::new("⚙ 설정").size(15.0).strong());
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            heading(ui, "테마");
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut draft.theme, Theme::Light, "라이트");
+                ui.radio_value(&mut draft.theme, Theme::Dark, "다크");
+            });
+
+            heading(ui, "마우스 휠");
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut draft.wheel_action, WheelAction::Zoom, "확대/축소");
+                ui.radio_value(&mut draft.wheel_action, WheelAction::Pan, "이동");
+            });
+            ui.label(
+                RichText::new("Ctrl/Cmd+휠과 핀치는 항상 확대, 트랙패드 스크롤은 이동")
+                    .size(10.0)
+                    .color(Color32::from_gray(140)),
+            );
+
+            heading(ui, "확대 감도 (휠 한 칸 배율)");
+            ui.add(egui::Slider::new(&mut draft.zoom_step, ZOOM_STEP_RANGE).fixed_decimals(2));
+
+            heading(ui, "관성 이동");
+            ui.checkbox(&mut draft.pan_inertia, "끌어 이동을 놓으면 미끄러지듯 이어가기");
+            ui.add_enabled(
+                draft.pan_inertia,
+                egui::Slider::new(&mut draft.pan_friction, PAN_FRICTION_RANGE)
+                    .fixed_decimals(1)
+                    .text("마찰"),
+            );
+
+            heading(ui, "새 카드 색");
+            ui.horizontal_wrapped(|ui| {
+                if ui
+                    .selectable_label(draft.default_card_color.is_none(), "순환")
+                    .clicked()
+                {
+                    draft.default_card_color = None;
+                }
+                let current = draft
+                    .default_card_color
+                    .as_deref()
+                    .and_then(parse_hex_color);
+                for color in CARD_COLORS {
+                    let selected = current.is_some_and(|c| to_hex_color(c) == to_hex_color(color));
+                    if color_swatch(ui, color, selected) {
+                        draft.default_card_color = Some(to_hex_color(color));
+                    }
+                }
+                let mut custom = current.unwrap_or(CARD_COLORS[0]);
+                if ui.color_edit_button_rgb(&mut custom).changed() {
+                    draft.default_card_color = Some(to_hex_color(custom));
+                }
+            });
+
+            heading(ui, "격자와 정렬");
+            ui.checkbox(&mut draft.snap_to_grid, "카드를 격자에 맞추기");
+            ui.add_enabled(
+                draft.snap_to_grid,
+                egui::Slider::new(&mut draft.grid_size, GRID_SIZE_RANGE)
+                    .fixed_decimals(2)
+                    .text("간격"),
+            );
+            ui.checkbox(&mut draft.alignment_guides, "주변 카드에 맞추고 안내선 표시");
+            ui.label(
+                RichText::new("옮기는 중 Alt를 누르고 있으면 맞추지 않음")
+                    .size(10.0)
+                    .color(Color32::from_gray(140)),
+            );
+
+            heading(ui, "패널");
+            ui.checkbox(&mut draft.show_inspector, "인스펙터");
+            ui.checkbox(&mut draft.show_toolbar, "하단 툴바");
+            ui.checkbox(&mut draft.show_ai_button, "AI 버튼");
+
+            heading(ui, "단축키");
+            let keymap_location = state
+                .keymap_path
+                .as_deref()
+                .unwrap_or("키맵 위치를 알 수 없어 기본 단축키 사용");
+            ui.label(
+                RichText::new(keymap_location)
+                    .size(10.0)
+                    .color(Color32::from_gray(140)),
+            );
+            if ui.button("키맵 파일 다시 읽기").clicked() {
+                action.reload_keymap = true;
+            }
+            for warning in &state.keymap_warnings {
+                ui.label(
+                    RichText::new(format!("⚠ {}", warning))
+                        .size(10.0)
+                        .color(Color32::from_rgb(180, 83, 9)),
+                );
+            }
+
+            ui.add_space(12.0);
+            if ui.button("기본값으로 되돌리기").clicked() {
+                action.reset = true;
+            }
+
+            for warning in &state.warnings {
+                ui.label(
+                    RichText::new(format!("⚠ {}", warning))
+                        .size(10.0)
+                        .color(Color32::from_rgb(180, 83, 9)),
+                );
+            }
+            let location = state
+                .path
+                .as_deref()
+                .unwrap_or("설정 위치를 알 수 없어 저장하지 않음");
+            ui.add_space(6.0);
+            ui.label(
+                RichText::new(location)
+                    .size(10.0)
+                    .color(Color32::from_gray(140)),
+            );
+        });
+
+    action.changed = state.draft != before;
+    action
+}
@@ -1,25 +1,17 @@
 use egui::{self, Color32, CornerRadius, RichText, Vec2};
 
+use crate::commands::{Command, BATCH_SIZES};
+use crate::keymap::Keymap;
 use crate::types::{TOOLBAR_BOTTOM_OFFSET, TOOLBAR_HALF_WIDTH};
 
-pub struct ToolbarAction {
-    pub add_node: bool,
-    pub add_batch: usize, // 0이면 미사용, 100/1000/10000
-    pub reset: bool,
-}
-
-impl Default for ToolbarAction {
-    fn default() -> Self {
-        Self {
-            add_node: false,
-            add_batch: 0,
-            reset: false,
-        }
-    }
-}
-
-pub fn show(ctx: &egui::Context, canvas_rect: egui::Rect) -> ToolbarAction {
-    let mut action = ToolbarAction::default();
+/// 누른 버튼의 명령
+pub fn show(ctx: &egui::Context, canvas_rect: egui::Rect, keymap: &Keymap) -> Option<Command> {
+    let mut command = None;
+    // 마우스를 올리면 명령 이름과 현재 단축키
+    let hint = |command: Command| match keymap.label(command) {
+        shortcut if shortcut.is_empty() => command.title(),
+        shortcut => format!("{} ({})", command.title(), shortcut),
+    };
 
     let toolbar_y = canvas_rect.max.y - TOOLBAR_BOTTOM_OFFSET;
     let toolbar_x = canvas_rect.center().x;
@@ -51,14 +43,14 @@ pub fn show(ctx: &egui::Context, canvas_rect: egui::Rect) -> ToolbarAction {
                     .fill(Color32::from_rgb(79, 57, 246))
                     .corner_radius(CornerRadius::same(14))
                     .min_size(Vec2::new(96.0, 48.0));
-                    if ui.add(add_btn).clicked() {
-                        action.add_node = true;
+                    if ui.add(add_btn).on_hover_text(hint(Command::AddCard)).clicked() {
+                        command = Some(Command::AddCard);
                     }
 
                     // 대량 생성 버튼들
-                    for count in [100, 1000, 10000] {
+                    for count in BATCH_SIZES {
                         if batch_button(ui, count).clicked() {
-                            action.add_batch = count;
+                            command = Some(Command::AddCards(count));
                         }
                     }
 
@@ -68,17 +60,51 @@ pub fn show(ctx: &egui::Context, canvas_rect: egui::Rect) -> ToolbarAction {
 
                     // 초기화
                     if toolbar_button(ui, "↺", "초기화").clicked() {
-                        action.reset = true;
+                        command = Some(Command::ResetBoard);
+                    }
+
+                    ui.add_space(4.0);
+                    separator(ui);
+                    ui.add_space(4.0);
+
+                    // 화면 맞춤
+                    if toolbar_button(ui, "⛶", "전체")
+                        .on_hover_text(hint(Command::ZoomToFit))
+                        .clicked()
+                    {
+                        command = Some(Command::ZoomToFit);
+                    }
+                    if toolbar_button(ui, "⊡", "선택")
+                        .on_hover_text(hint(Command::ZoomToSelection))
+                        .clicked()
+                    {
+                        command = Some(Command::ZoomToSelection);
+                    }
+                    if toolbar_button(ui, "1:1", "100%")
+                        .on_hover_text(hint(Command::ZoomToActualSize))
+                        .clicked()
+                    {
+                        command = Some(Command::ZoomToActualSize);
                     }
 
                     ui.add_space(4.0);
                     separator(ui);
                     ui.add_space(4.0);
 
-                    // 저장 / 내보내기 / 가져오기 (미구현)
+                    // 저장 (미구현) / 내보내기 / 가져오기
                     toolbar_button(ui, "💾", "저장");
-                    toolbar_button(ui, "📤", "내보내기");
-                    toolbar_button(ui, "📥", "가져오기");
+                    if toolbar_button(ui, "📤", "내보내기")
+                        .on_hover_text(hint(Command::Export))
+                        .clicked()
+                    {
+                        command = Some(Command::Export);
+                    }
+                    if toolbar_button(ui, "📥", "가져오기")
+                        .on_hover_text(hint(Command::Import))
+                        .clicked()
+                    {
+                        command = Some(Command::Import);
+                    }
 
                     ui.add_space(4.0);
                     separator(ui);
@@ -92,7 +118,7 @@ pub fn show(ctx: &egui::Context, canvas_rect: egui::Rect) -> ToolbarAction {
             });
         });
 
-    action
+    command
 }
 
 fn toolbar_button(ui: &mut egui::Ui, icon: &str, label: &str) -> egui::Response {
@@ -1,8 +1,31 @@
 use egui::{self, Color32, RichText, Vec2};
 
+use crate::commands::Command;
+
 pub struct TopBarState {
     pub active_tab: usize,
     pub tabs: Vec<String>,
+    // 이름 변경 중인 탭과 편집 중인 이름
+    pub renaming: Option<(usize, String)>,
+    // 저장하지 않은 변경이 있어 닫기 확인 중인 탭
+    pub confirm_close: Option<usize>,
+    // 창을 닫으려는데 저장하지 않은 변경이 있는 탭들 (종료 확인 중)
+    pub confirm_quit: Option<Vec<usize>>,
+}
+
+// 탭 조작 요청 (보드 전환/생성/삭제는 AppState가 처리)
+#[derive(Default)]
+pub struct TopBarAction {
+    pub select: Option<usize>,
+    // 새 탭/탭 닫기
+    pub command: Option<Command>,
+    // 확인을 받고 변경을 버린 채 닫을 탭
+    pub discard_close: Option<usize>,
+    // 확인을 받고 변경을 버린 채 앱 종료
+    pub quit: bool,
+    pub rename: Option<(usize, String)>,
+    // (옮길 탭, 새 위치)
+    pub move_tab: Option<(usize, usize)>,
 }
 
 impl Default for TopBarState {
@@ -14,6 +37,9 @@ impl Default for TopBarState {
                 "기술스택 선택".to_string(),
                 "신규서비스 런칭 전략".to_string(),
             ],
+            renaming: None,
+            confirm_close: None,
+            confirm_quit: None,
         }
     }
 }
@@ -28,7 +54,10 @@ pub fn frame() -> egui::Frame {
         .inner_margin(egui::Margin::ZERO)
 }
 
-pub fn show(ui: &mut egui::Ui, state: &mut TopBarState) {
+pub fn show(ui: &mut egui::Ui, state: &mut TopBarState) -> TopBarAction {
+    let mut action = TopBarAction::default();
+    let tab_count = state.tabs.len();
+
     ui.horizontal_centered(|ui| {
         ui.add_space(100.0); // macOS traffic lights 영역
 
@@ -82,6 +111,23 @@ pub fn show(ui: &mut egui::Ui, state: &mut TopBarState) {
                         let icon = if is_active { "📄" } else { "📋" };
                         ui.label(RichText::new(icon).size(12.0));
                         ui.add_space(4.0);
+
+                        // 이름 변경 중: Enter/포커스 이탈로 확정, Esc로 취소
+                        if let Some((_, name)) = state.renaming.as_mut().filter(|(r, _)| *r == i) {
+                            let edit = ui.add(
+                                egui::TextEdit::singleline(name)
+                                    .font(font_id.clone())
+                                    .desired_width(140.0),
+                            );
+                            edit.request_focus();
+                            if ui.input(|inp| inp.key_pressed(egui::Key::Escape)) {
+                                action.rename = Some((i, tab_name.clone()));
+                            } else if edit.lost_focus() {
+                                action.rename = Some((i, name.clone()));
+                            }
+                            return;
+                        }
+
                         let label = egui::Label::new(
                             RichText::new(tab_name.as_str())
                                 .font(font_id)
@@ -89,13 +135,125 @@ pub fn show(ui: &mut egui::Ui, state: &mut TopBarState) {
                         )
                         .selectable(false);
                         ui.add(label);
+
+                        if is_active && tab_count > 1 {
+                            let close = egui::Button::new(
+                                RichText::new("×").size(13.0).color(Color32::from_gray(140)),
+                            )
+                            .frame(false);
+                            if ui.add(close).on_hover_text("탭 닫기").clicked() {
+                                action.command = Some(Command::CloseTab);
+                            }
+                        }
                     });
                 },
             );
 
-            if response.response.clicked() {
-                state.active_tab = i;
+            let response = response.response.interact(egui::Sense::click());
+            if response.double_clicked() {
+                state.renaming = Some((i, tab_name.clone()));
+            } else if response.clicked() {
+                action.select = Some(i);
             }
+            response.context_menu(|ui| {
+                if ui.button("✏ 이름 바꾸기").clicked() {
+                    state.renaming = Some((i, tab_name.clone()));
+                    ui.close_menu();
+                }
+                if ui.add_enabled(i > 0, egui::Button::new("◀ 왼쪽으로 이동")).clicked() {
+                    action.move_tab = Some((i, i - 1));
+                    ui.close_menu();
+                }
+                let can_move_right = i + 1 < tab_count;
+                if ui.add_enabled(can_move_right, egui::Button::new("▶ 오른쪽으로 이동")).clicked() {
+                    action.move_tab = Some((i, i + 1));
+                    ui.close_menu();
+                }
+                if ui.add_enabled(tab_count > 1, egui::Button::new("× 닫기")).clicked() {
+                    action.command = Some(Command::CloseTabAt(i));
+                    ui.close_menu();
+                }
+            });
+        }
+
+        // 새 탭
+        ui.add_space(6.0);
+        let new_tab = egui::Button::new(RichText::new("+").size(16.0).color(Color32::from_gray(110)))
+            .frame(false);
+        if ui.add(new_tab).on_hover_text("새 보드").clicked() {
+            action.command = Some(Command::NewTab);
         }
     });
+
+    if let Some(idx) = state.confirm_close {
+        show_close_confirm(ui.ctx(), state, idx, &mut action);
+    }
+    if let Some(unsaved) = state.confirm_quit.clone() {
+        show_quit_confirm(ui.ctx(), state, &unsaved, &mut action);
+    }
+
+    if action.rename.is_some() {
+        state.renaming = None;
+    }
+    action
+}
+
+fn show_close_confirm(
+    ctx: &egui::Context,
+    state: &mut TopBarState,
+    idx: usize,
+    action: &mut TopBarAction,
+) {
+    let Some(name) = state.tabs.get(idx) else {
+        state.confirm_close = None;
+        return;
+    };
+    egui::Window::new("탭 닫기")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label(format!("'{}'에 저장하지 않은 변경이 있습니다.", name));
+            ui.label("닫으면 변경 내용이 사라집니다.");
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                if ui.button("닫기").clicked() {
+                    action.discard_close = Some(idx);
+                }
+                let escape = ui.input(|i| i.key_pressed(egui::Key::Escape));
+                if ui.button("취소").clicked() || escape {
+                    state.confirm_close = None;
+                }
+            });
+        });
+}
+
+fn show_quit_confirm(
+    ctx: &egui::Context,
+    state: &mut TopBarState,
+    unsaved: &[usize],
+    action: &mut TopBarAction,
+) {
+    egui::Window::new("종료")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, Vec2::ZERO)
+        .show(ctx, |ui| {
+            ui.label("저장하지 않은 변경이 있는 탭이 있습니다.");
+            for name in unsaved.iter().filter_map(|&i| state.tabs.get(i)) {
+                ui.label(format!("• {}", name));
+            }
+            ui.label("종료하면 변경 내용이 사라집니다.");
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                if ui.button("종료").clicked() {
+                    action.quit = true;
+                    state.confirm_quit = None;
+                }
+                let escape = ui.input(|i| i.key_pressed(egui::Key::Escape));
+                if ui.button("취소").clicked() || escape {
+                    state.confirm_quit = None;
+                }
+            });
+        });
 }
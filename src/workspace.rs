@@ -0,0 +1,368 @@
+// 작업 공간 디렉터리의 보드 파일 목록 (프로젝트 탐색기)
+//
+// 목록은 바로 만들고, 썸네일은 파일을 모두 읽어야 하므로 작업 스레드에서 채운다.
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver};
+use std::time::SystemTime;
+
+use crate::document::Document;
+use crate::formats::{self, Format};
+use crate::paths;
+
+// 썸네일에 그리는 최대 카드 수 (넘으면 고르게 건너뜀)
+const THUMBNAIL_MAX_POINTS: usize = 400;
+
+/// 작업 공간 기본 위치. 데이터 디렉터리를 모르면 현재 디렉터리 아래
+pub fn default_dir() -> PathBuf {
+    paths::data_dir().unwrap_or_default().join("projects")
+}
+
+/// 카드 배치 미리보기: 0~1로 정규화한 위치(y 아래 방향)와 색상
+pub struct Thumbnail {
+    pub points: Vec<([f32; 2], [f32; 3])>,
+    pub card_count: usize,
+}
+
+impl Thumbnail {
+    pub fn of(doc: &Document) -> Self {
+        let Some((min, max)) = doc.bounds() else {
+            return Thumbnail {
+                points: Vec::new(),
+                card_count: 0,
+            };
+        };
+        // 긴 변 기준으로 맞추고 짧은 변은 가운데 정렬
+        let extent = (max[0] - min[0]).max(max[1] - min[1]).max(f32::EPSILON);
+        let offset = [
+            (extent - (max[0] - min[0])) * 0.5,
+            (extent - (max[1] - min[1])) * 0.5,
+        ];
+        let step = doc.len().div_ceil(THUMBNAIL_MAX_POINTS);
+        let points = doc
+            .block_positions
+            .iter()
+            .step_by(step)
+            .map(|inst| {
+                let x = (inst.position[0] - min[0] + offset[0]) / extent;
+                let y = 1.0 - (inst.position[1] - min[1] + offset[1]) / extent;
+                ([x, y], inst.color)
+            })
+            .collect();
+        Thumbnail {
+            points,
+            card_count: doc.len(),
+        }
+    }
+}
+
+pub enum Preview {
+    // 작업 스레드에서 읽는 중
+    Loading,
+    Ready(Thumbnail),
+    // 읽지 못한 파일은 썸네일 대신 오류 메시지
+    Failed(String),
+}
+
+pub struct ProjectEntry {
+    pub path: PathBuf,
+    pub name: String,
+    pub format: Format,
+    pub modified: Option<SystemTime>,
+    pub preview: Preview,
+}
+
+pub struct Listing {
+    pub entries: Vec<ProjectEntry>,
+    // 읽지 못해 건너뛴 항목 (경로 또는 오류)
+    pub skipped: Vec<String>,
+}
+
+impl Listing {
+    /// 썸네일을 아직 읽지 않은 파일
+    pub fn pending(&self) -> Vec<PathBuf> {
+        self.entries
+            .iter()
+            .filter(|e| matches!(e.preview, Preview::Loading))
+            .map(|e| e.path.clone())
+            .collect()
+    }
+}
+
+/// 디렉터리의 보드 파일을 최근 수정 순으로 나열. 파일 내용은 읽지 않음.
+/// 디렉터리가 없으면 빈 목록 (처음 저장할 때 만듦).
+/// 수정 시각이 같은 파일은 이전 목록의 썸네일을 재사용하고, 나머지는 Loading으로 둠
+pub fn scan(dir: &Path, previous: Vec<ProjectEntry>) -> io::Result<Listing> {
+    let mut previous = previous;
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    let items = match fs::read_dir(dir) {
+        Ok(items) => items,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Ok(Listing { entries, skipped });
+        }
+        Err(err) => return Err(err),
+    };
+    for item in items {
+        let item = match item {
+            Ok(item) => item,
+            Err(err) => {
+                skipped.push(err.to_string());
+                continue;
+            }
+        };
+        let path = item.path();
+        let Some(format) = Format::from_path(&path) else {
+            continue;
+        };
+        let metadata = match item.metadata() {
+            Ok(metadata) => metadata,
+            Err(err) => {
+                skipped.push(format!("{}: {}", path.display(), err));
+                continue;
+            }
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let modified = metadata.modified().ok();
+
+        let cached = previous.iter().position(|e| {
+            e.path == path
+                && e.modified == modified
+                && modified.is_some()
+                && !matches!(e.preview, Preview::Loading)
+        });
+        let preview = match cached {
+            Some(i) => previous.swap_remove(i).preview,
+            None => Preview::Loading,
+        };
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        entries.push(ProjectEntry {
+            path,
+            name,
+            format,
+            modified,
+            preview,
+        });
+    }
+    entries.sort_by(|a, b| b.modified.cmp(&a.modified).then(a.name.cmp(&b.name)));
+    Ok(Listing { entries, skipped })
+}
+
+/// 작업 스레드에서 파일을 하나씩 읽어 썸네일을 보냄. 하나 보낼 때마다 notify 호출.
+/// 받는 쪽이 사라지면(새로 목록을 읽으면) 남은 파일은 읽지 않고 끝냄
+pub fn load_previews(
+    paths: Vec<PathBuf>,
+    notify: impl Fn() + Send + 'static,
+) -> io::Result<Receiver<(PathBuf, Preview)>> {
+    let (sender, receiver) = mpsc::channel();
+    std::thread::Builder::new()
+        .name("project-previews".into())
+        .spawn(move || {
+            for path in paths {
+                let preview = match formats::import_file(&path) {
+                    Ok(doc) => Preview::Ready(Thumbnail::of(&doc)),
+                    Err(err) => Preview::Failed(err.to_string()),
+                };
+                if sender.send((path, preview)).is_err() {
+                    return;
+                }
+                notify();
+            }
+        })?;
+    Ok(receiver)
+}
+
+/// 이미 있으면 "이름 2.ext", "이름 3.ext" … 중 없는 경로
+pub fn unique_path(path: &Path) -> PathBuf {
+    if !path.exists() {
+        return path.to_path_buf();
+    }
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    (2..)
+        .map(|n| dir.join(format!("{} {}.{}", stem, n, ext)))
+        .find(|candidate| !candidate.exists())
+        .unwrap_or_default()
+}
+
+/// "이름 사본.ext", 이미 있으면 "이름 사본 2.ext" … 로 복제
+pub fn duplicate(path: &Path) -> io::Result<PathBuf> {
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let ext = path
+        .extension()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let target = (1..)
+        .map(|n| {
+            let name = match n {
+                1 => format!("{} 사본.{}", stem, ext),
+                n => format!("{} 사본 {}.{}", stem, n, ext),
+            };
+            dir.join(name)
+        })
+        .find(|candidate| !candidate.exists())
+        .unwrap_or_default();
+    fs::copy(path, &target)?;
+    Ok(target)
+}
+
+/// 같은 파일을 가리키는지 (경로 표기가 달라도 실제 파일이 같으면 true)
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    a == b
+        || matches!(
+            (fs::canonicalize(a), fs::canonicalize(b)),
+            (Ok(a), Ok(b)) if a == b
+        )
+}
+
+/// 파일을 지우고, 그 파일에서 연 보드는 출처를 지워 저장하지 않은 보드로 표시
+/// (다시 저장하면 새 파일로). boards: 보드마다 (출처 파일, 저장하지 않은 변경)
+pub fn delete<'a>(
+    path: &Path,
+    boards: impl Iterator<Item = (&'a mut Option<PathBuf>, &'a mut bool)>,
+) -> io::Result<()> {
+    // 지운 뒤에는 실제 경로를 확인할 수 없으므로 먼저 찾음
+    let opened: Vec<_> = boards
+        .filter(|(source, _)| source.as_deref().is_some_and(|s| is_same_file(s, path)))
+        .collect();
+    fs::remove_file(path)?;
+    for (source, unsaved) in opened {
+        *source = None;
+        *unsaved = true;
+    }
+    Ok(())
+}
+
+/// 탭 이름을 파일 이름으로 쓸 수 있게 정리
+pub fn file_name_for(title: &str, format: Format) -> String {
+    let stem: String = title
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let stem = if stem.is_empty() { "보드" } else { &stem };
+    format!("{}.{}", stem, format.extension())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "workspace-test-{:016x}",
+            crate::document::random_id()
+        ));
+        fs::create_dir_all(&dir).expect("creates temp dir");
+        dir
+    }
+
+    #[test]
+    fn scan_lists_without_parsing_and_previews_load_in_background() {
+        let dir = temp_dir();
+        fs::write(dir.join("good.canvas"), r#"{"nodes": [], "edges": []}"#).expect("writes");
+        fs::write(dir.join("broken.canvas"), "{ not json").expect("writes");
+        fs::write(dir.join("notes.txt"), "무시").expect("writes");
+
+        let listing = scan(&dir, Vec::new()).expect("scans");
+        assert!(listing.skipped.is_empty());
+        let mut names: Vec<&str> = listing.entries.iter().map(|e| e.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, ["broken", "good"]);
+        assert_eq!(listing.pending().len(), 2);
+
+        let receiver = load_previews(listing.pending(), || {}).expect("spawns");
+        for (path, preview) in receiver {
+            let broken = path.ends_with("broken.canvas");
+            match preview {
+                Preview::Ready(thumbnail) => assert!(!broken && thumbnail.card_count == 0),
+                Preview::Failed(_) => assert!(broken),
+                Preview::Loading => panic!("작업 스레드가 Loading을 보냄"),
+            }
+        }
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn scan_of_a_missing_directory_is_empty_and_creates_nothing() {
+        let dir = temp_dir().join("오타");
+        let listing = scan(&dir, Vec::new()).expect("scans");
+        assert!(listing.entries.is_empty() && listing.skipped.is_empty());
+        assert!(!dir.exists());
+        let _ = fs::remove_dir_all(dir.parent().expect("parent"));
+    }
+
+    #[test]
+    fn unique_path_skips_existing_files() {
+        let dir = temp_dir();
+        let path = dir.join("보드.canvas");
+        assert_eq!(unique_path(&path), path);
+        fs::write(&path, "").expect("writes");
+        fs::write(dir.join("보드 2.canvas"), "").expect("writes");
+        assert_eq!(unique_path(&path), dir.join("보드 3.canvas"));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn delete_detaches_every_board_opened_from_the_file() {
+        let dir = temp_dir();
+        let (deleted, kept) = (dir.join("회의.canvas"), dir.join("기획.canvas"));
+        fs::write(&deleted, "").expect("writes");
+        fs::write(&kept, "").expect("writes");
+
+        // 활성 탭은 같은 파일을 다른 표기로, 두 번째 탭은 다른 파일을 엶
+        let mut boards = [
+            (Some(dir.join(".").join("회의.canvas")), false),
+            (Some(kept.clone()), false),
+            (Some(deleted.clone()), false),
+            (None, false),
+        ];
+        delete(
+            &deleted,
+            boards.iter_mut().map(|(source, unsaved)| (source, unsaved)),
+        )
+        .expect("deletes");
+        assert!(!deleted.exists());
+        assert_eq!(
+            boards,
+            [
+                (None, true),
+                (Some(kept), false),
+                (None, true),
+                (None, false)
+            ]
+        );
+
+        // 지우지 못하면 보드는 그대로
+        let mut boards = [(Some(deleted.clone()), false)];
+        let result = delete(
+            &deleted,
+            boards.iter_mut().map(|(source, unsaved)| (source, unsaved)),
+        );
+        assert!(result.is_err());
+        assert_eq!(boards, [(Some(deleted), false)]);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}